@@ -1,5 +1,8 @@
 use crate::player::Player;
 use crate::server::Server;
+use minecraft_protocol::packets::play::client::commands::{
+    CommandTreeNode, DeclareCommands, UnknownRedirect,
+};
 use std::error::Error;
 
 pub struct CommandError(Box<dyn Error>);
@@ -13,6 +16,16 @@ pub trait Command<S: Server> {
         command: &str,
         arguments: Vec<String>,
     ) -> Result<(), Self::Error>;
+
+    /// The literal node of the command. Its children are the arguments the client can complete
+    fn command_node(&self) -> CommandTreeNode;
+}
+
+/// Builds the command graph sent to the client from the registered commands
+pub fn declare_commands<'a, S: Server, C: Command<S> + 'a>(
+    commands: impl IntoIterator<Item = &'a C>,
+) -> Result<DeclareCommands, UnknownRedirect> {
+    DeclareCommands::from_tree(commands.into_iter().map(|command| command.command_node()))
 }
 
 pub trait CommandSuggestions<S: Server> {
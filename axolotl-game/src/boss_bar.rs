@@ -0,0 +1,171 @@
+use std::collections::HashSet;
+
+use minecraft_protocol::chat::Component;
+use minecraft_protocol::packets::play::client::hud::{
+    BossBar as BossBarPacket, BossBarAction, BossBarColor, BossBarDivision, BossBarFlags,
+};
+use uuid::Uuid;
+
+/// A boss bar shown to a set of players
+///
+/// Setters only record what changed. [BossBar::flush] turns the changes into update packets
+#[derive(Debug, Clone)]
+pub struct BossBar {
+    uuid: Uuid,
+    title: Component,
+    health: f32,
+    color: BossBarColor,
+    division: BossBarDivision,
+    flags: BossBarFlags,
+    viewers: HashSet<Uuid>,
+    title_changed: bool,
+    health_changed: bool,
+    style_changed: bool,
+    flags_changed: bool,
+}
+
+impl BossBar {
+    pub fn new(
+        title: impl Into<Component>,
+        color: BossBarColor,
+        division: BossBarDivision,
+    ) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            title: title.into(),
+            health: 1.0,
+            color,
+            division,
+            flags: BossBarFlags::empty(),
+            viewers: HashSet::new(),
+            title_changed: false,
+            health_changed: false,
+            style_changed: false,
+            flags_changed: false,
+        }
+    }
+
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    pub fn title(&self) -> &Component {
+        &self.title
+    }
+
+    pub fn health(&self) -> f32 {
+        self.health
+    }
+
+    pub fn color(&self) -> BossBarColor {
+        self.color
+    }
+
+    pub fn division(&self) -> BossBarDivision {
+        self.division
+    }
+
+    pub fn flags(&self) -> BossBarFlags {
+        self.flags
+    }
+
+    pub fn set_title(&mut self, title: impl Into<Component>) {
+        let title = title.into();
+        if self.title != title {
+            self.title = title;
+            self.title_changed = true;
+        }
+    }
+
+    /// Clamped between 0 and 1
+    pub fn set_health(&mut self, health: f32) {
+        let health = health.clamp(0.0, 1.0);
+        if self.health != health {
+            self.health = health;
+            self.health_changed = true;
+        }
+    }
+
+    pub fn set_style(&mut self, color: BossBarColor, division: BossBarDivision) {
+        if self.color != color || self.division != division {
+            self.color = color;
+            self.division = division;
+            self.style_changed = true;
+        }
+    }
+
+    pub fn set_flags(&mut self, flags: BossBarFlags) {
+        if self.flags != flags {
+            self.flags = flags;
+            self.flags_changed = true;
+        }
+    }
+
+    pub fn viewers(&self) -> impl Iterator<Item = &Uuid> {
+        self.viewers.iter()
+    }
+
+    pub fn is_viewer(&self, player: &Uuid) -> bool {
+        self.viewers.contains(player)
+    }
+
+    /// The packet to send to the player. None if they already see the bar
+    pub fn add_viewer(&mut self, player: Uuid) -> Option<BossBarPacket> {
+        self.viewers.insert(player).then(|| self.add_packet())
+    }
+
+    /// The packet to send to the player. None if they did not see the bar
+    pub fn remove_viewer(&mut self, player: &Uuid) -> Option<BossBarPacket> {
+        self.viewers
+            .remove(player)
+            .then(|| self.packet(BossBarAction::Remove))
+    }
+
+    /// Shows the bar with its current state
+    pub fn add_packet(&self) -> BossBarPacket {
+        self.packet(BossBarAction::Add {
+            title: self.title.clone(),
+            health: self.health,
+            color: self.color,
+            division: self.division,
+            flags: self.flags,
+        })
+    }
+
+    /// The updates since the last flush. Every viewer needs all of them
+    pub fn take_updates(&mut self) -> Vec<BossBarPacket> {
+        let mut updates = Vec::new();
+        if std::mem::take(&mut self.title_changed) {
+            updates.push(self.packet(BossBarAction::UpdateTitle(self.title.clone())));
+        }
+        if std::mem::take(&mut self.health_changed) {
+            updates.push(self.packet(BossBarAction::UpdateHealth(self.health)));
+        }
+        if std::mem::take(&mut self.style_changed) {
+            updates.push(self.packet(BossBarAction::UpdateStyle {
+                color: self.color,
+                division: self.division,
+            }));
+        }
+        if std::mem::take(&mut self.flags_changed) {
+            updates.push(self.packet(BossBarAction::UpdateFlags(self.flags)));
+        }
+        updates
+    }
+
+    /// Passes every update to every viewer
+    pub fn flush(&mut self, mut send: impl FnMut(&Uuid, BossBarPacket)) {
+        for update in self.take_updates() {
+            for viewer in &self.viewers {
+                send(viewer, update.clone());
+            }
+        }
+    }
+
+    fn packet(&self, action: BossBarAction) -> BossBarPacket {
+        BossBarPacket {
+            uuid: self.uuid,
+            action,
+        }
+    }
+}
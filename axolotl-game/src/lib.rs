@@ -13,7 +13,7 @@ use axolotl_nbt::serde_impl;
 pub use flume::{bounded, unbounded, Receiver, Sender};
 //pub use crossbeam::channel::{bounded, unbounded, Receiver, Sender};
 use flume::Drain;
-use log::{debug, info};
+use log::{debug, info, warn};
 use thiserror::Error;
 
 use axolotl_api::game::{AxolotlVersion, DataRegistries, Game, Registries, Registry};
@@ -25,6 +25,8 @@ use axolotl_api::{NamespacedId, NamespacedKey};
 use axolotl_items::blocks::MinecraftBlock;
 use axolotl_items::items::MinecraftItem;
 use axolotl_world::level::MinecraftVersion;
+use minecraft_protocol::packets::play::client::particle::{Particle, ParticleData};
+use minecraft_protocol::packets::play::client::sound::SoundEvent;
 use registry::SimpleRegistry;
 
 use crate::chat::AxolotlChatType;
@@ -32,9 +34,11 @@ use crate::item_stack::AxolotlItemStack;
 use crate::world::generator::AxolotlDensityLoader;
 use crate::world::perlin::GameNoise;
 
+pub mod boss_bar;
 pub mod chat;
 pub mod item_stack;
 pub mod registry;
+pub mod scoreboard;
 pub mod world;
 
 pub struct ChunkPosSplit(i32, i32);
@@ -179,6 +183,9 @@ pub struct AxolotlRegistries<W: World> {
     pub biomes: SimpleRegistry<DataPackBiome>,
     pub blocks: SimpleRegistry<MinecraftBlock<AxolotlGame<W>>>,
     pub chat_types: SimpleRegistry<AxolotlChatType>,
+    /// From the data dump reports. The values are the keys
+    pub sound_events: SimpleRegistry<String>,
+    pub particle_types: SimpleRegistry<String>,
 }
 impl<W: World> Debug for AxolotlRegistries<W> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -187,6 +194,8 @@ impl<W: World> Debug for AxolotlRegistries<W> {
             .field("biomes", &self.biomes.values.len())
             .field("blocks", &self.blocks.values.len())
             .field("chat_types", &self.chat_types.values.len())
+            .field("sound_events", &self.sound_events.values.len())
+            .field("particle_types", &self.particle_types.values.len())
             .field("World Type", &name)
             .finish()
     }
@@ -209,6 +218,16 @@ impl<W: World> AxolotlRegistries<W> {
             &mut block_registry,
         )
         .unwrap();
+        let mut reports = registry::load_registry_reports(data_dump.as_ref())?;
+        let mut protocol_registry = |name: &str| match reports.remove(name) {
+            Some(report) => SimpleRegistry::from_report(name, report),
+            None => {
+                warn!("Registry {} is missing from the data dump reports", name);
+                SimpleRegistry::new()
+            }
+        };
+        let sound_events = protocol_registry("minecraft:sound_event");
+        let particle_types = protocol_registry("minecraft:particle_type");
 
         Ok(AxolotlRegistries {
             biomes: SimpleRegistry::load_from_path(
@@ -221,8 +240,29 @@ impl<W: World> AxolotlRegistries<W> {
             )?,
             blocks: block_registry,
             chat_types,
+            sound_events,
+            particle_types,
         })
     }
+
+    /// Unknown keys are sent by name. The client plays them if a resource pack adds them
+    pub fn sound_event(&self, key: impl AsRef<str>) -> SoundEvent {
+        let key = key.as_ref();
+        match self.sound_events.get_id(key) {
+            Some(id) => SoundEvent::Registered(id as i32),
+            None => SoundEvent::named(key),
+        }
+    }
+
+    /// None if the particle type is unknown
+    pub fn particle(&self, key: impl AsRef<str>, data: ParticleData) -> Option<Particle> {
+        self.particle_types
+            .get_id(key)
+            .map(|particle_type| Particle {
+                particle_type: particle_type as i32,
+                data,
+            })
+    }
 }
 impl<W: World> Registries<AxolotlGame<W>> for AxolotlRegistries<W> {
     type BiomeRegistry = SimpleRegistry<DataPackBiome>;
@@ -1,10 +1,11 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use ahash::AHashMap;
 use log::warn;
 use serde::de::DeserializeOwned;
 use serde::ser::SerializeMap;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use axolotl_api::data::{ForPacket, PacketVersion};
 use axolotl_api::game::Registry;
@@ -150,6 +151,50 @@ impl<T: DeserializeOwned> SimpleRegistry<T> {
     }
 }
 
+/// A registry in `reports/registries.json` of the data dump
+#[derive(Debug, Deserialize)]
+pub struct RegistryReport {
+    pub entries: HashMap<String, RegistryReportEntry>,
+}
+#[derive(Debug, Deserialize)]
+pub struct RegistryReportEntry {
+    pub protocol_id: usize,
+}
+
+/// Every registry the client knows the ids of. By registry name
+pub fn load_registry_reports(
+    data_dump: impl AsRef<Path>,
+) -> Result<HashMap<String, RegistryReport>, Error> {
+    let path = data_dump.as_ref().join("reports").join("registries.json");
+    let file = std::fs::File::open(path)?;
+    Ok(serde_json::from_reader(file)?)
+}
+
+impl SimpleRegistry<String> {
+    /// The values are the keys. Registered in protocol id order so the ids match the client
+    pub fn from_report(name: impl Into<String>, report: RegistryReport) -> Self {
+        let mut registry = SimpleRegistry::new();
+        let name = name.into();
+        let mut entries: Vec<(String, usize)> = report
+            .entries
+            .into_iter()
+            .map(|(key, entry)| (key, entry.protocol_id))
+            .collect();
+        entries.sort_by_key(|(_, protocol_id)| *protocol_id);
+        for (key, protocol_id) in entries {
+            let id = registry.register(key.clone(), key);
+            if id != protocol_id {
+                warn!(
+                    "Registry {} has a gap. Expected protocol id {} got {}",
+                    name, id, protocol_id
+                );
+            }
+        }
+        registry.name = Some(name);
+        registry
+    }
+}
+
 impl<T> Registry<T> for SimpleRegistry<T> {
     fn register(&mut self, namespace: impl Into<String>, item: T) -> usize {
         let namespace = namespace.into();
@@ -0,0 +1,398 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use minecraft_protocol::chat::Component;
+use minecraft_protocol::packets::play::client::scoreboard::{
+    DisplayObjective, ObjectiveAction, ObjectiveRenderType, ScoreAction, TeamAction, TeamInfo,
+    UpdateObjectives, UpdateScore, UpdateTeams, MAX_NAME_LENGTH,
+};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum ScoreboardError {
+    #[error("Name {0} is longer than {} characters", MAX_NAME_LENGTH)]
+    NameTooLong(String),
+    #[error("Objective {0} already exists")]
+    DuplicateObjective(String),
+    #[error("Team {0} already exists")]
+    DuplicateTeam(String),
+    #[error("Unknown objective {0}")]
+    UnknownObjective(String),
+    #[error("Unknown team {0}")]
+    UnknownTeam(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Objective {
+    pub display_name: Component,
+    pub render_type: ObjectiveRenderType,
+    /// Entity name to score
+    pub scores: BTreeMap<String, i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Team {
+    pub info: TeamInfo,
+    pub entities: BTreeSet<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ScoreboardState {
+    objectives: BTreeMap<String, Objective>,
+    /// Display slot to objective name
+    display: BTreeMap<i8, String>,
+    teams: BTreeMap<String, Team>,
+}
+
+/// Objectives, scores and teams shown to a set of players
+///
+/// Every viewer keeps a copy of what it was sent. [Scoreboard::sync] sends the difference
+#[derive(Debug, Clone, Default)]
+pub struct Scoreboard {
+    state: ScoreboardState,
+    viewers: HashMap<Uuid, ScoreboardState>,
+}
+
+fn check_name(name: &str) -> Result<(), ScoreboardError> {
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(ScoreboardError::NameTooLong(name.to_string()));
+    }
+    Ok(())
+}
+
+impl Scoreboard {
+    pub fn add_objective(
+        &mut self,
+        name: impl Into<String>,
+        display_name: impl Into<Component>,
+        render_type: ObjectiveRenderType,
+    ) -> Result<(), ScoreboardError> {
+        let name = name.into();
+        check_name(&name)?;
+        if self.state.objectives.contains_key(&name) {
+            return Err(ScoreboardError::DuplicateObjective(name));
+        }
+        self.state.objectives.insert(
+            name,
+            Objective {
+                display_name: display_name.into(),
+                render_type,
+                scores: BTreeMap::new(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Also clears the display slots showing it
+    pub fn remove_objective(&mut self, name: &str) -> Option<Objective> {
+        self.state.display.retain(|_, objective| objective != name);
+        self.state.objectives.remove(name)
+    }
+
+    pub fn objective(&self, name: &str) -> Option<&Objective> {
+        self.state.objectives.get(name)
+    }
+
+    pub fn objective_mut(&mut self, name: &str) -> Option<&mut Objective> {
+        self.state.objectives.get_mut(name)
+    }
+
+    /// Shows the objective in the slot. None clears the slot
+    pub fn set_display(
+        &mut self,
+        slot: i8,
+        objective: Option<&str>,
+    ) -> Result<(), ScoreboardError> {
+        match objective {
+            Some(objective) => {
+                if !self.state.objectives.contains_key(objective) {
+                    return Err(ScoreboardError::UnknownObjective(objective.to_string()));
+                }
+                self.state.display.insert(slot, objective.to_string());
+            }
+            None => {
+                self.state.display.remove(&slot);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn set_score(
+        &mut self,
+        objective: &str,
+        entity: impl Into<String>,
+        value: i32,
+    ) -> Result<(), ScoreboardError> {
+        self.objective_mut(objective)
+            .ok_or_else(|| ScoreboardError::UnknownObjective(objective.to_string()))?
+            .scores
+            .insert(entity.into(), value);
+        Ok(())
+    }
+
+    pub fn reset_score(&mut self, objective: &str, entity: &str) -> Option<i32> {
+        self.objective_mut(objective)?.scores.remove(entity)
+    }
+
+    pub fn add_team(
+        &mut self,
+        name: impl Into<String>,
+        info: TeamInfo,
+    ) -> Result<(), ScoreboardError> {
+        let name = name.into();
+        check_name(&name)?;
+        if self.state.teams.contains_key(&name) {
+            return Err(ScoreboardError::DuplicateTeam(name));
+        }
+        self.state.teams.insert(
+            name,
+            Team {
+                info,
+                entities: BTreeSet::new(),
+            },
+        );
+        Ok(())
+    }
+
+    pub fn remove_team(&mut self, name: &str) -> Option<Team> {
+        self.state.teams.remove(name)
+    }
+
+    pub fn team(&self, name: &str) -> Option<&Team> {
+        self.state.teams.get(name)
+    }
+
+    pub fn team_mut(&mut self, name: &str) -> Option<&mut Team> {
+        self.state.teams.get_mut(name)
+    }
+
+    /// An entity is in at most one team. It leaves its old team
+    pub fn join_team(
+        &mut self,
+        team: &str,
+        entity: impl Into<String>,
+    ) -> Result<(), ScoreboardError> {
+        if !self.state.teams.contains_key(team) {
+            return Err(ScoreboardError::UnknownTeam(team.to_string()));
+        }
+        let entity = entity.into();
+        self.leave_team(&entity);
+        if let Some(team) = self.state.teams.get_mut(team) {
+            team.entities.insert(entity);
+        }
+        Ok(())
+    }
+
+    /// The team the entity left
+    pub fn leave_team(&mut self, entity: &str) -> Option<String> {
+        self.state
+            .teams
+            .iter_mut()
+            .find_map(|(name, team)| team.entities.remove(entity).then(|| name.clone()))
+    }
+
+    /// The viewer gets everything on the next sync
+    pub fn add_viewer(&mut self, viewer: Uuid) {
+        self.viewers.entry(viewer).or_default();
+    }
+
+    /// The packets that remove the scoreboard from the viewer
+    pub fn remove_viewer(&mut self, viewer: &Uuid) -> Vec<ClientBoundPlay> {
+        match self.viewers.remove(viewer) {
+            Some(sent) => diff(&sent, &ScoreboardState::default()),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn viewers(&self) -> impl Iterator<Item = &Uuid> {
+        self.viewers.keys()
+    }
+
+    /// The packets that bring the viewer up to date
+    pub fn sync(&mut self, viewer: &Uuid) -> Vec<ClientBoundPlay> {
+        let Some(sent) = self.viewers.get_mut(viewer) else {
+            return Vec::new();
+        };
+        let packets = diff(sent, &self.state);
+        if !packets.is_empty() {
+            *sent = self.state.clone();
+        }
+        packets
+    }
+
+    /// Syncs every viewer
+    pub fn flush(&mut self, mut send: impl FnMut(&Uuid, ClientBoundPlay)) {
+        let viewers: Vec<Uuid> = self.viewers.keys().copied().collect();
+        for viewer in viewers {
+            for packet in self.sync(&viewer) {
+                send(&viewer, packet);
+            }
+        }
+    }
+}
+
+/// The packets that turn what a client has into what it should have
+fn diff(old: &ScoreboardState, new: &ScoreboardState) -> Vec<ClientBoundPlay> {
+    let mut packets = Vec::new();
+    for name in old.objectives.keys() {
+        if !new.objectives.contains_key(name) {
+            packets.push(
+                UpdateObjectives {
+                    name: name.clone(),
+                    action: ObjectiveAction::Remove,
+                }
+                .into(),
+            );
+        }
+    }
+    for (name, objective) in &new.objectives {
+        let old_objective = old.objectives.get(name);
+        match old_objective {
+            None => packets.push(
+                UpdateObjectives {
+                    name: name.clone(),
+                    action: ObjectiveAction::Create {
+                        display_name: objective.display_name.clone(),
+                        render_type: objective.render_type,
+                    },
+                }
+                .into(),
+            ),
+            Some(old_objective)
+                if old_objective.display_name != objective.display_name
+                    || old_objective.render_type != objective.render_type =>
+            {
+                packets.push(
+                    UpdateObjectives {
+                        name: name.clone(),
+                        action: ObjectiveAction::Update {
+                            display_name: objective.display_name.clone(),
+                            render_type: objective.render_type,
+                        },
+                    }
+                    .into(),
+                )
+            }
+            Some(_) => {}
+        }
+        let old_scores = old_objective.map(|objective| &objective.scores);
+        if let Some(old_scores) = old_scores {
+            for entity in old_scores.keys() {
+                if !objective.scores.contains_key(entity) {
+                    packets.push(
+                        UpdateScore {
+                            entity_name: entity.clone(),
+                            objective: name.clone(),
+                            action: ScoreAction::Remove,
+                        }
+                        .into(),
+                    );
+                }
+            }
+        }
+        for (entity, value) in &objective.scores {
+            if old_scores.and_then(|scores| scores.get(entity)) != Some(value) {
+                packets.push(
+                    UpdateScore {
+                        entity_name: entity.clone(),
+                        objective: name.clone(),
+                        action: ScoreAction::Update(*value),
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    for (slot, name) in &old.display {
+        // The client clears the slots of removed objectives itself
+        if !new.display.contains_key(slot) && new.objectives.contains_key(name) {
+            packets.push(
+                DisplayObjective {
+                    position: *slot,
+                    name: String::new(),
+                }
+                .into(),
+            );
+        }
+    }
+    for (slot, name) in &new.display {
+        if old.display.get(slot) != Some(name) {
+            packets.push(
+                DisplayObjective {
+                    position: *slot,
+                    name: name.clone(),
+                }
+                .into(),
+            );
+        }
+    }
+
+    // Removals first. The client refuses to remove an entity that already joined another team
+    for (name, old_team) in &old.teams {
+        let Some(team) = new.teams.get(name) else {
+            packets.push(
+                UpdateTeams {
+                    name: name.clone(),
+                    action: TeamAction::Remove,
+                }
+                .into(),
+            );
+            continue;
+        };
+        let removed: Vec<String> = old_team
+            .entities
+            .difference(&team.entities)
+            .cloned()
+            .collect();
+        if !removed.is_empty() {
+            packets.push(
+                UpdateTeams {
+                    name: name.clone(),
+                    action: TeamAction::RemoveEntities(removed),
+                }
+                .into(),
+            );
+        }
+    }
+    for (name, team) in &new.teams {
+        let Some(old_team) = old.teams.get(name) else {
+            packets.push(
+                UpdateTeams {
+                    name: name.clone(),
+                    action: TeamAction::Create {
+                        info: team.info.clone(),
+                        entities: team.entities.iter().cloned().collect(),
+                    },
+                }
+                .into(),
+            );
+            continue;
+        };
+        if old_team.info != team.info {
+            packets.push(
+                UpdateTeams {
+                    name: name.clone(),
+                    action: TeamAction::UpdateInfo(team.info.clone()),
+                }
+                .into(),
+            );
+        }
+        let added: Vec<String> = team
+            .entities
+            .difference(&old_team.entities)
+            .cloned()
+            .collect();
+        if !added.is_empty() {
+            packets.push(
+                UpdateTeams {
+                    name: name.clone(),
+                    action: TeamAction::AddEntities(added),
+                }
+                .into(),
+            );
+        }
+    }
+    packets
+}
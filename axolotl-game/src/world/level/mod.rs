@@ -4,3 +4,4 @@ pub mod configs;
 pub mod flat;
 pub mod level_gen;
 pub mod noise;
+pub mod packets;
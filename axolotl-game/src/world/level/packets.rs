@@ -0,0 +1,121 @@
+//! Packets built from the values stored in `level.dat`
+use axolotl_world::level::LevelDat;
+use minecraft_protocol::packets::play::client::login::GameMode;
+use minecraft_protocol::packets::play::client::world::{
+    GameEvent, InitializeWorldBorder, SetBorderCenter, SetBorderLerpSize, SetBorderSize,
+    SetBorderWarningDelay, SetBorderWarningDistance, UpdateTime, PORTAL_TELEPORT_BOUNDARY,
+};
+use serde_json::Value;
+
+pub trait LevelDatPackets {
+    fn initialize_world_border(&self) -> InitializeWorldBorder;
+
+    fn set_border_center(&self) -> SetBorderCenter;
+
+    fn set_border_lerp_size(&self) -> SetBorderLerpSize;
+
+    fn set_border_size(&self) -> SetBorderSize;
+
+    fn set_border_warning_delay(&self) -> SetBorderWarningDelay;
+
+    fn set_border_warning_distance(&self) -> SetBorderWarningDistance;
+
+    /// Stopped if the `doDaylightCycle` game rule is false
+    fn update_time(&self) -> UpdateTime;
+
+    /// What a joining player needs to see the weather. Empty if it is clear
+    fn weather_events(&self) -> Vec<GameEvent>;
+
+    /// None if the game type is unknown
+    fn change_game_mode(&self) -> Option<GameEvent>;
+
+    /// From the `doImmediateRespawn` game rule
+    fn enable_respawn_screen(&self) -> GameEvent;
+}
+
+/// Game rules are stored as strings by vanilla
+fn game_rule(level: &LevelDat, name: &str, default: bool) -> bool {
+    match level.game_rules.get(name) {
+        Some(Value::Bool(value)) => *value,
+        Some(Value::String(value)) => value == "true",
+        _ => default,
+    }
+}
+
+impl LevelDatPackets for LevelDat {
+    fn initialize_world_border(&self) -> InitializeWorldBorder {
+        InitializeWorldBorder {
+            x: self.border_center_x,
+            z: self.border_center_z,
+            old_diameter: self.border_size,
+            new_diameter: self.border_size_lerp_target,
+            speed: self.border_size_lerp_time,
+            portal_teleport_boundary: PORTAL_TELEPORT_BOUNDARY,
+            warning_blocks: self.border_warning_blocks as i32,
+            warning_time: self.border_warning_time as i32,
+        }
+    }
+
+    fn set_border_center(&self) -> SetBorderCenter {
+        SetBorderCenter {
+            x: self.border_center_x,
+            z: self.border_center_z,
+        }
+    }
+
+    fn set_border_lerp_size(&self) -> SetBorderLerpSize {
+        SetBorderLerpSize {
+            old_diameter: self.border_size,
+            new_diameter: self.border_size_lerp_target,
+            speed: self.border_size_lerp_time,
+        }
+    }
+
+    fn set_border_size(&self) -> SetBorderSize {
+        SetBorderSize {
+            diameter: self.border_size,
+        }
+    }
+
+    fn set_border_warning_delay(&self) -> SetBorderWarningDelay {
+        SetBorderWarningDelay {
+            warning_time: self.border_warning_time as i32,
+        }
+    }
+
+    fn set_border_warning_distance(&self) -> SetBorderWarningDistance {
+        SetBorderWarningDistance {
+            warning_blocks: self.border_warning_blocks as i32,
+        }
+    }
+
+    fn update_time(&self) -> UpdateTime {
+        UpdateTime::new(
+            self.time,
+            self.day_time,
+            game_rule(self, "doDaylightCycle", true),
+        )
+    }
+
+    fn weather_events(&self) -> Vec<GameEvent> {
+        if !self.raining && !self.thundering {
+            return Vec::new();
+        }
+        vec![
+            GameEvent::StartRaining,
+            GameEvent::RainLevelChange(1.0),
+            GameEvent::ThunderLevelChange(if self.thundering { 1.0 } else { 0.0 }),
+        ]
+    }
+
+    fn change_game_mode(&self) -> Option<GameEvent> {
+        u8::try_from(self.game_type)
+            .ok()
+            .and_then(|game_type| GameMode::try_from(game_type).ok())
+            .map(GameEvent::ChangeGameMode)
+    }
+
+    fn enable_respawn_screen(&self) -> GameEvent {
+        GameEvent::EnableRespawnScreen(!game_rule(self, "doImmediateRespawn", false))
+    }
+}
@@ -67,6 +67,18 @@ pub struct LevelDat {
     pub border_safe_zone: f64,
     pub border_size: f64,
     pub border_size_lerp_target: f64,
+    /// Milliseconds until the border reaches the lerp target
+    #[serde(default)]
+    pub border_size_lerp_time: i64,
+    #[serde(default = "default_border_warning_blocks")]
+    pub border_warning_blocks: f64,
+    /// In seconds
+    #[serde(default = "default_border_warning_time")]
+    pub border_warning_time: f64,
+    #[serde(rename = "raining", default)]
+    pub raining: bool,
+    #[serde(rename = "rainTime", default)]
+    pub rain_time: i32,
     #[serde(rename = "thundering")]
     pub thundering: bool,
     #[serde(rename = "thunderTime")]
@@ -105,6 +117,11 @@ impl Default for LevelDat {
             border_safe_zone: 0.0,
             border_size: 59999984.0,
             border_size_lerp_target: 59999984.0,
+            border_size_lerp_time: 0,
+            border_warning_blocks: default_border_warning_blocks(),
+            border_warning_time: default_border_warning_time(),
+            raining: false,
+            rain_time: 0,
             thundering: false,
             thunder_time: 0,
             other: Default::default(),
@@ -112,6 +129,14 @@ impl Default for LevelDat {
     }
 }
 
+fn default_border_warning_blocks() -> f64 {
+    5.0
+}
+
+fn default_border_warning_time() -> f64 {
+    15.0
+}
+
 pub fn default_game_rules() -> HashMap<String, Value> {
     let mut game_rules = HashMap::new();
     game_rules.insert("commandBlockOutput".to_string(), Value::Bool(false));
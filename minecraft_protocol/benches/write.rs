@@ -1,6 +1,7 @@
-use cfb_mode::cipher::KeyIvInit;
+use cfb8::cipher::KeyIvInit;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+use minecraft_protocol::chat::Component;
 use minecraft_protocol::java::v_761::play::ClientIO;
 use minecraft_protocol::packets::play::client::ClientBoundPlay::Disconnect;
 use minecraft_protocol::packets::play::client::{ClientBoundPlay, DisconnectPacket};
@@ -8,7 +9,7 @@ use minecraft_protocol::simple_handlers::{EncryptedPacketWriter, NonEncryptedPac
 use minecraft_protocol::{CompressionSettings, Encryptor, PacketHandler, PacketWriter};
 
 fn write_packet(mut target: Vec<u8>, mut writer: impl PacketWriter<PacketOut = ClientBoundPlay>) {
-    let packet = Disconnect(DisconnectPacket("Hello world!".to_string()));
+    let packet = Disconnect(DisconnectPacket(Component::text("Hello world!")));
 
     writer.send_packet(packet, &mut target).unwrap();
 }
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use minecraft_protocol::data::var_int;
+use minecraft_protocol::java::v_761::play::ServerIO;
+use minecraft_protocol::PacketIO;
+
+// The packet id followed by the packet. Errors are fine, panics and allocations past the limits are not
+fuzz_target!(|data: &[u8]| {
+    let mut data = data;
+    if let Ok(id) = var_int::inline::read(&mut data) {
+        let _ = ServerIO::handle_read(id.0, data.len(), &mut data);
+    }
+});
@@ -11,3 +11,21 @@ impl Parse for VarInt {
         Ok(VarInt { value })
     }
 }
+
+/// The bytes of the value encoded as a var int
+pub fn var_int_bytes(value: i32) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(5);
+    let mut value = value as u32;
+    loop {
+        let mut byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        bytes.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+    bytes
+}
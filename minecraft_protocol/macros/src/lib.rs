@@ -7,6 +7,7 @@ use crate::define_var_int::VarInt;
 use crate::packet_io::PacketIO;
 
 pub(crate) mod define_var_int;
+pub(crate) mod packet;
 pub(crate) mod packet_enum;
 pub(crate) mod packet_io;
 
@@ -26,20 +27,7 @@ pub(crate) mod packet_io;
 pub fn define_var_int(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as VarInt);
     let x: i32 = input.value.base10_parse().unwrap();
-    let mut bytes = Vec::with_capacity(4);
-    let mut value = x;
-
-    loop {
-        let mut byte = (value & 0x7F) as u8;
-        value >>= 7;
-        if value != 0 {
-            byte |= 0x80;
-        }
-        bytes.push(byte);
-        if value == 0 {
-            break;
-        }
-    }
+    let bytes = define_var_int::var_int_bytes(x);
     let v = quote! {
         [ #(#bytes),* ]
     };
@@ -110,3 +98,46 @@ pub fn packet_enum(item: TokenStream) -> TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// Implements `Packet` with the struct as the content. The fields are read and written in order.
+///
+/// With `content = Type` the struct only describes the layout and `Type` is the content.
+/// `Type` must have fields with the same names and types. Used for the `*Impl` packets of a version.
+///
+/// `Packet`, `PacketDataType`, `PacketReadError`, `PacketWriteError`, `Bound`, `Stage` and `Protocol` must be in scope.
+/// `VarInt` must be in scope if `#[varint]` or `#[prefixed_array]` is used. The `limits` module must be in scope if `#[prefixed_array]` is used.
+///
+/// Field encodings
+/// - None: `PacketDataType`
+/// - `#[varint]`: An i32 written as a VarInt
+/// - `#[prefixed_array]`: A Vec prefixed with its length as a VarInt. The length is checked against the `max_array_length` decode limit. The other encodings apply to each item
+/// - `#[optional]`: An Option prefixed with a bool
+/// - `#[nbt]`: Any serde type written as NBT
+/// - `#[rest]`: The remaining bytes of the packet. Only valid on the last field
+/// - `#[skip]`: Not sent. Read as [Default]
+/// # Example
+/// ```ignore
+/// use minecraft_protocol_macros::Packet;
+/// #[derive(Debug, Clone, PartialEq, Packet)]
+/// #[packet(id = 0x09, bound = ClientBound, stage = Play, protocol = 761)]
+/// pub struct BlockUpdate {
+///     pub location: PackedPosition,
+///     #[varint]
+///     pub block_id: i32,
+/// }
+///
+/// #[derive(PacketImplDebug, Packet)]
+/// #[packet(id = 0x1F, bound = ClientBound, stage = Play, protocol = 761, content = KeepAlive)]
+/// pub struct ClientBoundKeepAliveImpl(i64);
+/// ```
+#[proc_macro_derive(
+    Packet,
+    attributes(packet, varint, prefixed_array, optional, nbt, rest, skip)
+)]
+pub fn derive_packet(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    packet::derive_packet(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,349 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Index, LitInt, Member, Path, Token};
+
+use crate::define_var_int::var_int_bytes;
+
+/// One `key = value` of the packet attribute
+struct PacketArg {
+    key: Ident,
+    value: PacketArgValue,
+}
+
+enum PacketArgValue {
+    Int(LitInt),
+    Path(Path),
+}
+
+impl Parse for PacketArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let value = if input.peek(LitInt) {
+            PacketArgValue::Int(input.parse()?)
+        } else {
+            PacketArgValue::Path(input.parse()?)
+        };
+        Ok(PacketArg { key, value })
+    }
+}
+
+/// Will parse the packet attribute
+/// ```no_compile
+/// #[packet(id = 0x1F, bound = ClientBound, stage = Play, protocol = 761, content = KeepAlive)]
+/// ```
+struct PacketAttribute {
+    id: i32,
+    bound: Ident,
+    stage: Ident,
+    protocol: LitInt,
+    /// The struct itself if not set
+    content: Option<Path>,
+}
+
+impl PacketAttribute {
+    fn parse(attr: &Attribute) -> syn::Result<Self> {
+        let args = attr.parse_args_with(Punctuated::<PacketArg, Token![,]>::parse_terminated)?;
+        let (mut id, mut bound, mut stage, mut protocol, mut content) =
+            (None, None, None, None, None);
+        for arg in args {
+            match (arg.key.to_string().as_str(), arg.value) {
+                ("id", PacketArgValue::Int(value)) => id = Some(value.base10_parse::<i32>()?),
+                ("bound", PacketArgValue::Path(value)) if value.get_ident().is_some() => {
+                    bound = value.get_ident().cloned()
+                }
+                ("stage", PacketArgValue::Path(value)) if value.get_ident().is_some() => {
+                    stage = value.get_ident().cloned()
+                }
+                ("protocol", PacketArgValue::Int(value)) => protocol = Some(value),
+                ("content", PacketArgValue::Path(value)) => content = Some(value),
+                _ => {
+                    return Err(Error::new(
+                        arg.key.span(),
+                        "Expected id = <int>, bound = <Bound>, stage = <Stage>, protocol = <int> or content = <type>",
+                    ))
+                }
+            }
+        }
+        let missing =
+            |name: &str| Error::new(attr.span(), format!("Missing {} in #[packet]", name));
+        Ok(PacketAttribute {
+            id: id.ok_or_else(|| missing("id"))?,
+            bound: bound.ok_or_else(|| missing("bound"))?,
+            stage: stage.ok_or_else(|| missing("stage"))?,
+            protocol: protocol.ok_or_else(|| missing("protocol"))?,
+            content,
+        })
+    }
+}
+
+/// How a field is encoded. Set with attributes on the field
+#[derive(Default)]
+struct Encoding {
+    varint: bool,
+    prefixed_array: bool,
+    optional: bool,
+    nbt: bool,
+    rest: bool,
+    skip: bool,
+}
+
+impl Encoding {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut encoding = Encoding::default();
+        for attr in attrs {
+            let flag = if attr.path.is_ident("varint") {
+                &mut encoding.varint
+            } else if attr.path.is_ident("prefixed_array") {
+                &mut encoding.prefixed_array
+            } else if attr.path.is_ident("optional") {
+                &mut encoding.optional
+            } else if attr.path.is_ident("nbt") {
+                &mut encoding.nbt
+            } else if attr.path.is_ident("rest") {
+                &mut encoding.rest
+            } else if attr.path.is_ident("skip") {
+                &mut encoding.skip
+            } else {
+                continue;
+            };
+            if !attr.tokens.is_empty() {
+                return Err(Error::new(
+                    attr.span(),
+                    "Field encodings do not take arguments",
+                ));
+            }
+            *flag = true;
+        }
+        if encoding.varint && encoding.nbt {
+            return Err(Error::new(
+                attrs[0].span(),
+                "#[varint] and #[nbt] can not be combined",
+            ));
+        }
+        if (encoding.rest || encoding.skip)
+            && (encoding.varint || encoding.nbt || encoding.optional || encoding.prefixed_array)
+        {
+            return Err(Error::new(
+                attrs[0].span(),
+                "#[rest] and #[skip] can not be combined with other encodings",
+            ));
+        }
+        Ok(encoding)
+    }
+
+    /// Writes a single value. Without the optional and prefixed array wrappers
+    fn write_value(&self, value: TokenStream) -> TokenStream {
+        if self.varint {
+            quote! { PacketDataType::write(VarInt(#value), w)?; }
+        } else if self.nbt {
+            quote! { nbt::to_writer(w, &#value, None)?; }
+        } else {
+            quote! { PacketDataType::write(#value, w)?; }
+        }
+    }
+
+    fn read_value(&self) -> TokenStream {
+        if self.varint {
+            quote! { <VarInt as PacketDataType>::read(r)?.0 }
+        } else if self.nbt {
+            quote! {
+                nbt::from_reader(&mut *r)
+                    .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?
+            }
+        } else {
+            quote! { PacketDataType::read(r)? }
+        }
+    }
+
+    fn write(&self, value: TokenStream) -> TokenStream {
+        if self.skip {
+            return quote! {};
+        }
+        if self.rest {
+            return quote! { w.write_all(&#value)?; };
+        }
+        let write = if self.prefixed_array {
+            let write_item = self.write_value(quote! { item });
+            quote! {
+                PacketDataType::write(VarInt(value.len() as i32), w)?;
+                for item in value {
+                    #write_item
+                }
+            }
+        } else {
+            self.write_value(quote! { value })
+        };
+        if self.optional {
+            quote! {
+                match #value {
+                    Some(value) => {
+                        PacketDataType::write(true, w)?;
+                        #write
+                    }
+                    None => PacketDataType::write(false, w)?,
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let value = #value;
+                    #write
+                }
+            }
+        }
+    }
+
+    fn read(&self) -> TokenStream {
+        if self.skip {
+            return quote! { Default::default() };
+        }
+        if self.rest {
+            return quote! {
+                {
+                    let mut rest = Vec::new();
+                    r.read_to_end(&mut rest)?;
+                    rest
+                }
+            };
+        }
+        let read = if self.prefixed_array {
+            let read_value = self.read_value();
+            quote! {
+                {
+                    let length = limits::read_array_length(r)?;
+                    let mut array = limits::with_capacity(length);
+                    for _ in 0..length {
+                        array.push(#read_value);
+                    }
+                    array
+                }
+            }
+        } else {
+            self.read_value()
+        };
+        if self.optional {
+            quote! {
+                if <bool as PacketDataType>::read(r)? {
+                    Some(#read)
+                } else {
+                    None
+                }
+            }
+        } else {
+            read
+        }
+    }
+}
+
+pub fn derive_packet(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(ref data) = input.data else {
+        return Err(Error::new(input.span(), "Expected struct"));
+    };
+    let name = &input.ident;
+    let attribute =
+        match input.attrs.iter().find(|attr| attr.path.is_ident("packet")) {
+            Some(attr) => PacketAttribute::parse(attr)?,
+            None => return Err(Error::new(
+                input.span(),
+                "Expected #[packet(id = 0x00, bound = ClientBound, stage = Play, protocol = 761)]",
+            )),
+        };
+
+    let mut writes = Vec::new();
+    let mut reads = Vec::new();
+    let mut members = Vec::new();
+    let mut moves = Vec::new();
+    let field_count = data.fields.len();
+    for (index, field) in data.fields.iter().enumerate() {
+        let encoding = Encoding::parse(&field.attrs)?;
+        if encoding.rest && index + 1 != field_count {
+            return Err(Error::new(
+                field.span(),
+                "#[rest] can only be used on the last field",
+            ));
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let variable = format_ident!("field_{}", index);
+        writes.push(encoding.write(quote! { content.#member }));
+        let read = encoding.read();
+        reads.push(quote! { let #variable = #read; });
+        members.push(quote! { #member: #variable });
+        moves.push(quote! { #member: packet.#member });
+    }
+    let construct = match data.fields {
+        Fields::Unit => quote! { #name },
+        _ => quote! { #name { #(#members),* } },
+    };
+
+    let PacketAttribute {
+        id,
+        bound,
+        stage,
+        protocol,
+        content,
+    } = attribute;
+    // With a content type the fields of the struct are only the layout. They are moved to and from the content by name
+    let (content_type, to_layout, from_layout) = match content {
+        Some(content) => (
+            quote! { #content },
+            quote! {
+                let packet = content;
+                let content = #name { #(#moves),* };
+            },
+            quote! {
+                let packet = #construct;
+                Ok(#content { #(#moves),* })
+            },
+        ),
+        None => (quote! { Self }, quote! {}, quote! { Ok(#construct) }),
+    };
+    let id_bytes = var_int_bytes(id);
+    let id_len = id_bytes.len();
+    let result = quote! {
+        #[automatically_derived]
+        impl Packet for #name {
+            type Content = #content_type;
+
+            #[inline]
+            fn write_packet_id<W: std::io::Write>(w: &mut W) -> Result<usize, PacketWriteError> {
+                w.write_all(&[#(#id_bytes),*])?;
+                Ok(#id_len)
+            }
+            #[inline(always)]
+            fn packet_id() -> i32 {
+                #id
+            }
+            fn bound() -> Bound {
+                Bound::#bound
+            }
+            fn stage() -> Stage {
+                Stage::#stage
+            }
+            fn protocol() -> Protocol {
+                Protocol::Java(#protocol)
+            }
+
+            #[allow(unused_variables)]
+            fn write<W: std::io::Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+                Self::write_packet_id(w)?;
+                #to_layout
+                #(#writes)*
+                Ok(())
+            }
+
+            #[allow(unused_variables)]
+            fn read<R: std::io::Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+                #(#reads)*
+                #from_layout
+            }
+        }
+    };
+    Ok(result)
+}
@@ -0,0 +1,215 @@
+//! Pretty-prints and replays packet captures made with
+//! [Recorder](minecraft_protocol::simple_handlers::recording::Recorder).
+//!
+//! ```text
+//! packet_capture print <capture>
+//! packet_capture replay <capture> [port]
+//! ```
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::net::{Shutdown, TcpStream};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use minecraft_protocol::data::var_int::VarInt;
+use minecraft_protocol::data::PacketDataType;
+use minecraft_protocol::java::handshake::HandShakeIO;
+use minecraft_protocol::java::status::{ClientBoundStatusIO, ServerBoundStatusIO};
+use minecraft_protocol::java::versions::{JavaVersion, SupportedVersion, VersionVisitor};
+use minecraft_protocol::simple_handlers::recording::{
+    CaptureReader, CaptureRecord, RawIO, RawPacket,
+};
+use minecraft_protocol::simple_handlers::NonEncryptedPacketWriter;
+use minecraft_protocol::{
+    Bound, CompressionSettings, PacketHandler, PacketIO, PacketWriter, Stage,
+};
+
+const USAGE: &str = "Usage:
+    packet_capture print <capture>
+    packet_capture replay <capture> [port]";
+
+/// The Set Compression packet of the login stage
+const SET_COMPRESSION_ID: i32 = 0x03;
+/// The Encryption Response packet of the login stage
+const ENCRYPTION_RESPONSE_ID: i32 = 0x01;
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["print", capture] => print(capture),
+        ["replay", capture] => replay(capture, 25565),
+        ["replay", capture, port] => replay(capture, port.parse()?),
+        _ => {
+            eprintln!("{}", USAGE);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Decodes a record with the [PacketIO] of its stage and bound
+struct Describe<'a>(&'a CaptureRecord);
+
+impl VersionVisitor for Describe<'_> {
+    type Output = String;
+
+    fn visit<V: JavaVersion>(self) -> Self::Output {
+        let record = self.0;
+        match (record.stage, record.bound) {
+            (Stage::Handshake, _) => decode::<HandShakeIO>(record),
+            (Stage::Status, Bound::ServerBound) => decode::<ServerBoundStatusIO>(record),
+            (Stage::Status, Bound::ClientBound) => decode::<ClientBoundStatusIO>(record),
+            (Stage::Login, Bound::ServerBound) => decode::<V::LoginServerIO>(record),
+            (Stage::Login, Bound::ClientBound) => decode::<V::LoginClientIO>(record),
+            (Stage::Play, Bound::ServerBound) => decode::<V::PlayServerIO>(record),
+            (Stage::Play, Bound::ClientBound) => decode::<V::PlayClientIO>(record),
+        }
+    }
+}
+
+fn decode<IO: PacketIO>(record: &CaptureRecord) -> String {
+    match IO::handle_read(
+        record.packet_id,
+        record.data.len(),
+        &mut record.data.as_slice(),
+    ) {
+        Ok(packet) => format!("{:#?}", packet),
+        Err(err) => format!("Failed to decode ({}): {}", err, hex(&record.data)),
+    }
+}
+
+fn hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn print(capture: &str) -> anyhow::Result<()> {
+    // Until a handshake is found the packets are decoded as the newest version
+    let mut version = SupportedVersion::NEWEST;
+    let mut start = None;
+    for record in CaptureReader::open(capture)? {
+        let record = record?;
+        let start = *start.get_or_insert(record.timestamp);
+        if record.stage == Stage::Handshake {
+            let protocol = VarInt::read(&mut record.data.as_slice())?.0;
+            match SupportedVersion::from_protocol(protocol) {
+                Ok(supported) => version = supported,
+                Err(err) => eprintln!("{}. Decoding as {}", err, version.name()),
+            }
+        }
+        let direction = match record.bound {
+            Bound::ServerBound => "C -> S",
+            Bound::ClientBound => "S -> C",
+        };
+        println!(
+            "[{:>10.3}s] #{} {} {:?} 0x{:02X} ({} bytes)",
+            record.timestamp.saturating_sub(start) as f64 / 1000.0,
+            record.connection,
+            direction,
+            record.stage,
+            record.packet_id,
+            record.data.len()
+        );
+        println!("{}", version.visit(Describe(&record)));
+    }
+    Ok(())
+}
+
+/// A connection to the server for each connection of the capture
+struct Connection {
+    stream: TcpStream,
+    writer: NonEncryptedPacketWriter<RawIO>,
+    drain: JoinHandle<()>,
+}
+
+impl Connection {
+    fn open(port: u16) -> anyhow::Result<Self> {
+        let stream = TcpStream::connect(("127.0.0.1", port))?;
+        // Anything the server sends is discarded. But it has to be read so the server does not block
+        let mut incoming = stream.try_clone()?;
+        let drain = thread::spawn(move || {
+            let mut buffer = [0u8; 4096];
+            while matches!(incoming.read(&mut buffer), Ok(read) if read > 0) {}
+        });
+        Ok(Self {
+            stream,
+            writer: NonEncryptedPacketWriter::default(),
+            drain,
+        })
+    }
+
+    fn close(self) -> anyhow::Result<()> {
+        self.stream.shutdown(Shutdown::Write)?;
+        self.drain
+            .join()
+            .map_err(|_| anyhow::anyhow!("Failed to read from the server"))
+    }
+}
+
+/// Sends the server bound packets of the capture to a server on localhost. Keeping the original timing.
+///
+/// Every connection of the capture is replayed over its own connection to the server.
+/// Compression is enabled when the capture contains the Set Compression packet.
+/// Encrypted logins can not be replayed. Use a server in offline mode
+fn replay(capture: &str, port: u16) -> anyhow::Result<()> {
+    let mut connections: HashMap<u64, Connection> = HashMap::new();
+    let mut last_timestamp = None;
+    let mut sent = 0;
+    for record in CaptureReader::open(capture)? {
+        let record = record?;
+        if record.stage == Stage::Login && record.bound == Bound::ClientBound {
+            if record.packet_id == SET_COMPRESSION_ID {
+                let threshold = VarInt::read(&mut record.data.as_slice())?.0;
+                if let Some(connection) = connections.get_mut(&record.connection) {
+                    connection
+                        .writer
+                        .set_compression(CompressionSettings::Zlib {
+                            threshold,
+                            compression_level: 6,
+                        });
+                }
+            }
+            continue;
+        }
+        if record.bound == Bound::ClientBound {
+            continue;
+        }
+        if record.stage == Stage::Login && record.packet_id == ENCRYPTION_RESPONSE_ID {
+            eprintln!(
+                "Connection #{} contains an encrypted login. The server will not accept the replay",
+                record.connection
+            );
+        }
+        if let Some(last) = last_timestamp {
+            thread::sleep(Duration::from_millis(record.timestamp.saturating_sub(last)));
+        }
+        last_timestamp = Some(record.timestamp);
+        let connection = match connections.entry(record.connection) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(Connection::open(port)?),
+        };
+        connection.writer.send_packet(
+            RawPacket {
+                packet_id: record.packet_id,
+                data: record.data,
+            },
+            &mut connection.stream,
+        )?;
+        connection.stream.flush()?;
+        sent += 1;
+    }
+    println!(
+        "Replayed {} packets over {} connections",
+        sent,
+        connections.len()
+    );
+    for connection in connections.into_values() {
+        connection.close()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! Legacy formatting codes. `§` followed by a color or formatting character.
+//!
+//! Still used by the legacy server list ping and by plugins that store messages as strings.
+//! RGB colors are rounded to the closest named color and click and hover events are lost
+use crate::chat::{Color, Component, NamedColor, Style};
+
+/// The section sign that starts every code
+pub const SECTION_SIGN: char = '§';
+
+/// The formatting that can be expressed with legacy codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct LegacyState {
+    color: Option<NamedColor>,
+    obfuscated: bool,
+    bold: bool,
+    strikethrough: bool,
+    underlined: bool,
+    italic: bool,
+}
+
+impl LegacyState {
+    fn of(style: &Style) -> Self {
+        LegacyState {
+            color: style.color.as_ref().map(Color::to_named),
+            obfuscated: style.obfuscated.unwrap_or_default(),
+            bold: style.bold.unwrap_or_default(),
+            strikethrough: style.strikethrough.unwrap_or_default(),
+            underlined: style.underlined.unwrap_or_default(),
+            italic: style.italic.unwrap_or_default(),
+        }
+    }
+
+    /// In the order of the codes. `k` to `o`
+    fn flags(&self) -> [(bool, char); 5] {
+        [
+            (self.obfuscated, 'k'),
+            (self.bold, 'l'),
+            (self.strikethrough, 'm'),
+            (self.underlined, 'n'),
+            (self.italic, 'o'),
+        ]
+    }
+
+    /// Writes the codes that change `self` into `next`
+    fn transition(&self, next: &LegacyState, code: char, output: &mut String) {
+        let removes_flag = self
+            .flags()
+            .iter()
+            .zip(next.flags())
+            .any(|((current, _), (next, _))| *current && !next);
+        // Color codes and the reset code clear the formatting
+        let start = if self.color != next.color || removes_flag {
+            output.push(code);
+            output.push(next.color.map(|color| color.code()).unwrap_or('r'));
+            LegacyState {
+                color: next.color,
+                ..LegacyState::default()
+            }
+        } else {
+            *self
+        };
+        for ((current, _), (next, flag)) in start.flags().iter().zip(next.flags()) {
+            if next && !current {
+                output.push(code);
+                output.push(flag);
+            }
+        }
+    }
+}
+
+impl Component {
+    /// Parses a string with `§` codes
+    pub fn from_legacy(text: &str) -> Component {
+        Component::from_legacy_with(text, SECTION_SIGN)
+    }
+
+    /// Parses a string with a different code character. Usually `&` in configs
+    pub fn from_legacy_with(text: &str, code: char) -> Component {
+        let mut components = Vec::new();
+        let mut style = Style::default();
+        let mut current = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(char) = chars.next() {
+            if char != code {
+                current.push(char);
+                continue;
+            }
+            let Some(next) = chars.peek().map(char::to_ascii_lowercase) else {
+                current.push(char);
+                continue;
+            };
+            let new_style = match next {
+                'k' => Style {
+                    obfuscated: Some(true),
+                    ..style.clone()
+                },
+                'l' => Style {
+                    bold: Some(true),
+                    ..style.clone()
+                },
+                'm' => Style {
+                    strikethrough: Some(true),
+                    ..style.clone()
+                },
+                'n' => Style {
+                    underlined: Some(true),
+                    ..style.clone()
+                },
+                'o' => Style {
+                    italic: Some(true),
+                    ..style.clone()
+                },
+                'r' => Style::default(),
+                _ => match NamedColor::from_code(next) {
+                    Some(color) => Style {
+                        color: Some(Color::Named(color)),
+                        ..Style::default()
+                    },
+                    None => {
+                        current.push(char);
+                        continue;
+                    }
+                },
+            };
+            chars.next();
+            if !current.is_empty() {
+                components.push(Component::text(std::mem::take(&mut current)).style(style));
+            }
+            style = new_style;
+        }
+        if !current.is_empty() {
+            components.push(Component::text(current).style(style));
+        }
+
+        match components.len() {
+            0 => Component::text(""),
+            1 if components[0].style.is_empty() => components.remove(0),
+            _ => Component {
+                children: components,
+                ..Component::text("")
+            },
+        }
+    }
+
+    /// Writes the component with `§` codes
+    pub fn to_legacy(&self) -> String {
+        self.to_legacy_with(SECTION_SIGN)
+    }
+
+    pub fn to_legacy_with(&self, code: char) -> String {
+        let mut output = String::new();
+        let mut state = LegacyState::default();
+        self.push_legacy(&Style::default(), code, &mut state, &mut output);
+        output
+    }
+
+    fn push_legacy(
+        &self,
+        parent: &Style,
+        code: char,
+        state: &mut LegacyState,
+        output: &mut String,
+    ) {
+        let style = self.style.inherit(parent);
+        // Translation arguments are written without their formatting
+        let mut text = String::new();
+        self.content.push_plain_text(&mut text);
+        if !text.is_empty() {
+            let next = LegacyState::of(&style);
+            state.transition(&next, code, output);
+            *state = next;
+            output.push_str(&text);
+        }
+        for child in &self.children {
+            child.push_legacy(&style, code, state, output);
+        }
+    }
+}
@@ -0,0 +1,784 @@
+//! Text components. The JSON chat format used by chat, disconnect messages and the MOTD.
+//!
+//! ```
+//! use minecraft_protocol::chat::{ClickEvent, Component, NamedColor};
+//!
+//! let message = Component::text("Welcome ")
+//!     .color(NamedColor::Gold)
+//!     .append(Component::text("Notch").bold(true))
+//!     .click_event(ClickEvent::SuggestCommand("/msg Notch ".to_string()));
+//! assert_eq!(message.to_legacy(), "§6Welcome §lNotch");
+//! ```
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::data::PacketDataType;
+use crate::limits;
+
+pub mod legacy;
+
+/// The longest JSON text a client accepts
+pub const MAX_JSON_LENGTH: usize = 262144;
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ChatError {
+    #[error("Invalid color {0}")]
+    InvalidColor(String),
+    #[error("Unknown click event action {0}")]
+    UnknownClickAction(String),
+    #[error("Invalid page number {0}")]
+    InvalidPage(String),
+    #[error("Component has no content")]
+    MissingContent,
+    #[error("Empty component array")]
+    EmptyArray,
+}
+
+/// A text component. Content with a style and the components appended to it
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Component {
+    pub content: Content,
+    pub style: Style,
+    /// Inherit the style of this component. Sent as `extra`
+    pub children: Vec<Component>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Content {
+    Text(String),
+    /// Translated by the client. `%s` in the translation is replaced with the arguments
+    Translatable {
+        key: String,
+        with: Vec<Component>,
+        /// Shown when the client does not know the key. Only used by 1.19.4 clients
+        fallback: Option<String>,
+    },
+    /// The key bound to the action. `key.jump` shows `Space` by default
+    Keybind(String),
+    Score(Score),
+    /// An entity selector. Resolved by the server before sending
+    Selector {
+        selector: String,
+        separator: Option<Box<Component>>,
+    },
+}
+
+impl Default for Content {
+    fn default() -> Self {
+        Content::Text(String::new())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Score {
+    /// A player name or a selector
+    pub name: String,
+    pub objective: String,
+    /// Replaces the score when set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// Formatting of a component. Unset values are inherited from the parent
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Style {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bold: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub italic: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub underlined: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strikethrough: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub obfuscated: Option<bool>,
+    /// A resource location. `minecraft:default` if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<String>,
+    /// Inserted into the chat input when shift clicked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insertion: Option<String>,
+    #[serde(
+        default,
+        rename = "clickEvent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub click_event: Option<ClickEvent>,
+    #[serde(
+        default,
+        rename = "hoverEvent",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hover_event: Option<HoverEvent>,
+}
+
+impl Style {
+    pub fn is_empty(&self) -> bool {
+        self == &Style::default()
+    }
+
+    /// Fills the unset values with the values of the parent
+    pub fn inherit(&self, parent: &Style) -> Style {
+        Style {
+            color: self.color.or(parent.color),
+            bold: self.bold.or(parent.bold),
+            italic: self.italic.or(parent.italic),
+            underlined: self.underlined.or(parent.underlined),
+            strikethrough: self.strikethrough.or(parent.strikethrough),
+            obfuscated: self.obfuscated.or(parent.obfuscated),
+            font: self.font.clone().or_else(|| parent.font.clone()),
+            insertion: self.insertion.clone().or_else(|| parent.insertion.clone()),
+            click_event: self
+                .click_event
+                .clone()
+                .or_else(|| parent.click_event.clone()),
+            hover_event: self
+                .hover_event
+                .clone()
+                .or_else(|| parent.hover_event.clone()),
+        }
+    }
+}
+
+/// The 16 colors with a legacy formatting code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NamedColor {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+}
+
+impl NamedColor {
+    /// Ordered by the legacy code
+    pub const ALL: [NamedColor; 16] = [
+        NamedColor::Black,
+        NamedColor::DarkBlue,
+        NamedColor::DarkGreen,
+        NamedColor::DarkAqua,
+        NamedColor::DarkRed,
+        NamedColor::DarkPurple,
+        NamedColor::Gold,
+        NamedColor::Gray,
+        NamedColor::DarkGray,
+        NamedColor::Blue,
+        NamedColor::Green,
+        NamedColor::Aqua,
+        NamedColor::Red,
+        NamedColor::LightPurple,
+        NamedColor::Yellow,
+        NamedColor::White,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedColor::Black => "black",
+            NamedColor::DarkBlue => "dark_blue",
+            NamedColor::DarkGreen => "dark_green",
+            NamedColor::DarkAqua => "dark_aqua",
+            NamedColor::DarkRed => "dark_red",
+            NamedColor::DarkPurple => "dark_purple",
+            NamedColor::Gold => "gold",
+            NamedColor::Gray => "gray",
+            NamedColor::DarkGray => "dark_gray",
+            NamedColor::Blue => "blue",
+            NamedColor::Green => "green",
+            NamedColor::Aqua => "aqua",
+            NamedColor::Red => "red",
+            NamedColor::LightPurple => "light_purple",
+            NamedColor::Yellow => "yellow",
+            NamedColor::White => "white",
+        }
+    }
+
+    /// The character following `§`
+    pub fn code(&self) -> char {
+        std::char::from_digit(*self as u32, 16).unwrap()
+    }
+
+    pub fn from_code(code: char) -> Option<NamedColor> {
+        code.to_digit(16)
+            .map(|index| NamedColor::ALL[index as usize])
+    }
+
+    pub fn rgb(&self) -> u32 {
+        match self {
+            NamedColor::Black => 0x000000,
+            NamedColor::DarkBlue => 0x0000AA,
+            NamedColor::DarkGreen => 0x00AA00,
+            NamedColor::DarkAqua => 0x00AAAA,
+            NamedColor::DarkRed => 0xAA0000,
+            NamedColor::DarkPurple => 0xAA00AA,
+            NamedColor::Gold => 0xFFAA00,
+            NamedColor::Gray => 0xAAAAAA,
+            NamedColor::DarkGray => 0x555555,
+            NamedColor::Blue => 0x5555FF,
+            NamedColor::Green => 0x55FF55,
+            NamedColor::Aqua => 0x55FFFF,
+            NamedColor::Red => 0xFF5555,
+            NamedColor::LightPurple => 0xFF55FF,
+            NamedColor::Yellow => 0xFFFF55,
+            NamedColor::White => 0xFFFFFF,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Named(NamedColor),
+    /// `0xRRGGBB`. Sent as `#RRGGBB`
+    Rgb(u32),
+}
+
+impl Color {
+    pub fn rgb(&self) -> u32 {
+        match self {
+            Color::Named(named) => named.rgb(),
+            Color::Rgb(rgb) => *rgb,
+        }
+    }
+
+    /// The closest named color. Used where only legacy codes are supported
+    pub fn to_named(&self) -> NamedColor {
+        let rgb = match self {
+            Color::Named(named) => return *named,
+            Color::Rgb(rgb) => rgb,
+        };
+        let channels = |rgb: u32| [(rgb >> 16) & 0xFF, (rgb >> 8) & 0xFF, rgb & 0xFF];
+        let target = channels(*rgb);
+        *NamedColor::ALL
+            .iter()
+            .min_by_key(|named| {
+                channels(named.rgb())
+                    .iter()
+                    .zip(target)
+                    .map(|(a, b)| (*a as i32 - b as i32).pow(2))
+                    .sum::<i32>()
+            })
+            .unwrap()
+    }
+}
+
+impl From<NamedColor> for Color {
+    fn from(color: NamedColor) -> Self {
+        Color::Named(color)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Color::Named(named) => f.write_str(named.name()),
+            Color::Rgb(rgb) => write!(f, "#{:06X}", rgb),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = ChatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = s.strip_prefix('#') {
+            return u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|rgb| hex.len() == 6 && *rgb <= 0xFFFFFF)
+                .map(Color::Rgb)
+                .ok_or_else(|| ChatError::InvalidColor(s.to_string()));
+        }
+        NamedColor::ALL
+            .iter()
+            .find(|named| named.name() == s)
+            .map(|named| Color::Named(*named))
+            .ok_or_else(|| ChatError::InvalidColor(s.to_string()))
+    }
+}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let color = String::deserialize(deserializer)?;
+        color.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "RawClickEvent", into = "RawClickEvent")]
+pub enum ClickEvent {
+    OpenUrl(String),
+    /// Sent as if the player typed it. Including the `/`
+    RunCommand(String),
+    /// Replaces the chat input
+    SuggestCommand(String),
+    /// Only in books
+    ChangePage(u32),
+    CopyToClipboard(String),
+}
+
+/// Every click event value is a string. Even the page number
+#[derive(Serialize, Deserialize)]
+struct RawClickEvent {
+    action: String,
+    value: String,
+}
+
+impl From<ClickEvent> for RawClickEvent {
+    fn from(event: ClickEvent) -> Self {
+        let (action, value) = match event {
+            ClickEvent::OpenUrl(url) => ("open_url", url),
+            ClickEvent::RunCommand(command) => ("run_command", command),
+            ClickEvent::SuggestCommand(command) => ("suggest_command", command),
+            ClickEvent::ChangePage(page) => ("change_page", page.to_string()),
+            ClickEvent::CopyToClipboard(text) => ("copy_to_clipboard", text),
+        };
+        RawClickEvent {
+            action: action.to_string(),
+            value,
+        }
+    }
+}
+
+impl TryFrom<RawClickEvent> for ClickEvent {
+    type Error = ChatError;
+
+    fn try_from(raw: RawClickEvent) -> Result<Self, Self::Error> {
+        let RawClickEvent { action, value } = raw;
+        match action.as_str() {
+            "open_url" => Ok(ClickEvent::OpenUrl(value)),
+            "run_command" => Ok(ClickEvent::RunCommand(value)),
+            "suggest_command" => Ok(ClickEvent::SuggestCommand(value)),
+            "change_page" => value
+                .parse()
+                .map(ClickEvent::ChangePage)
+                .map_err(|_| ChatError::InvalidPage(value)),
+            "copy_to_clipboard" => Ok(ClickEvent::CopyToClipboard(value)),
+            _ => Err(ChatError::UnknownClickAction(action)),
+        }
+    }
+}
+
+/// Only the `contents` format is supported. The `value` format was removed in 1.16
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "action", content = "contents", rename_all = "snake_case")]
+pub enum HoverEvent {
+    ShowText(Box<Component>),
+    ShowItem(HoverItem),
+    ShowEntity(HoverEntity),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HoverItem {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<i32>,
+    /// SNBT of the item tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HoverEntity {
+    #[serde(rename = "type")]
+    pub entity_type: String,
+    pub id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Box<Component>>,
+}
+
+impl Component {
+    pub fn text(text: impl Into<String>) -> Self {
+        Content::Text(text.into()).into()
+    }
+
+    pub fn translatable(key: impl Into<String>, with: Vec<Component>) -> Self {
+        Content::Translatable {
+            key: key.into(),
+            with,
+            fallback: None,
+        }
+        .into()
+    }
+
+    pub fn keybind(key: impl Into<String>) -> Self {
+        Content::Keybind(key.into()).into()
+    }
+
+    pub fn score(name: impl Into<String>, objective: impl Into<String>) -> Self {
+        Content::Score(Score {
+            name: name.into(),
+            objective: objective.into(),
+            value: None,
+        })
+        .into()
+    }
+
+    pub fn selector(selector: impl Into<String>) -> Self {
+        Content::Selector {
+            selector: selector.into(),
+            separator: None,
+        }
+        .into()
+    }
+
+    /// Sets the fallback of a translatable component. Does nothing for any other content
+    pub fn fallback(mut self, fallback: impl Into<String>) -> Self {
+        if let Content::Translatable {
+            fallback: ref mut value,
+            ..
+        } = self.content
+        {
+            *value = Some(fallback.into());
+        }
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.style.color = Some(color.into());
+        self
+    }
+
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.style.bold = Some(bold);
+        self
+    }
+
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.style.italic = Some(italic);
+        self
+    }
+
+    pub fn underlined(mut self, underlined: bool) -> Self {
+        self.style.underlined = Some(underlined);
+        self
+    }
+
+    pub fn strikethrough(mut self, strikethrough: bool) -> Self {
+        self.style.strikethrough = Some(strikethrough);
+        self
+    }
+
+    pub fn obfuscated(mut self, obfuscated: bool) -> Self {
+        self.style.obfuscated = Some(obfuscated);
+        self
+    }
+
+    pub fn font(mut self, font: impl Into<String>) -> Self {
+        self.style.font = Some(font.into());
+        self
+    }
+
+    pub fn insertion(mut self, insertion: impl Into<String>) -> Self {
+        self.style.insertion = Some(insertion.into());
+        self
+    }
+
+    pub fn click_event(mut self, event: ClickEvent) -> Self {
+        self.style.click_event = Some(event);
+        self
+    }
+
+    pub fn hover_event(mut self, event: HoverEvent) -> Self {
+        self.style.hover_event = Some(event);
+        self
+    }
+
+    /// Shows the component as a tooltip
+    pub fn hover_text(self, text: impl Into<Component>) -> Self {
+        self.hover_event(HoverEvent::ShowText(Box::new(text.into())))
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Adds a child. It inherits the style of this component
+    pub fn append(mut self, child: impl Into<Component>) -> Self {
+        self.children.push(child.into());
+        self
+    }
+
+    /// The text without formatting. Translatable components show the fallback or the key
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+        self.push_plain_text(&mut text);
+        text
+    }
+
+    fn push_plain_text(&self, output: &mut String) {
+        self.content.push_plain_text(output);
+        for child in &self.children {
+            child.push_plain_text(output);
+        }
+    }
+}
+
+impl Content {
+    /// Only the text of this content. Without the children of the component
+    pub(crate) fn push_plain_text(&self, output: &mut String) {
+        match self {
+            Content::Text(text) => output.push_str(text),
+            Content::Translatable {
+                key,
+                with,
+                fallback,
+            } => format_translation(fallback.as_ref().unwrap_or(key), with, output),
+            Content::Keybind(key) => output.push_str(key),
+            Content::Score(score) => output.push_str(score.value.as_deref().unwrap_or_default()),
+            Content::Selector { selector, .. } => output.push_str(selector),
+        }
+    }
+}
+
+/// Replaces `%s`, `%1$s` and `%%` like the client does
+fn format_translation(template: &str, with: &[Component], output: &mut String) {
+    let mut next_argument = 0;
+    let mut rest = template;
+    while let Some(index) = rest.find('%') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index + 1..];
+        if let Some(after) = rest.strip_prefix('%') {
+            output.push('%');
+            rest = after;
+            continue;
+        }
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        let (argument, after) = if digits > 0 && rest[digits..].starts_with("$s") {
+            (
+                rest[..digits]
+                    .parse::<usize>()
+                    .ok()
+                    .map(|i| i.wrapping_sub(1)),
+                &rest[digits + 2..],
+            )
+        } else if let Some(after) = rest.strip_prefix('s') {
+            next_argument += 1;
+            (Some(next_argument - 1), after)
+        } else {
+            output.push('%');
+            continue;
+        };
+        if let Some(argument) = argument.and_then(|argument| with.get(argument)) {
+            argument.push_plain_text(output);
+        }
+        rest = after;
+    }
+    output.push_str(rest);
+}
+
+impl From<Content> for Component {
+    fn from(content: Content) -> Self {
+        Component {
+            content,
+            style: Style::default(),
+            children: vec![],
+        }
+    }
+}
+
+impl From<&str> for Component {
+    fn from(text: &str) -> Self {
+        Component::text(text)
+    }
+}
+
+impl From<String> for Component {
+    fn from(text: String) -> Self {
+        Component::text(text)
+    }
+}
+
+/// The keys written for a component. Content keys that do not apply are None
+#[derive(Serialize)]
+struct ComponentFields<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translate: Option<&'a str>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    with: &'a [Component],
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fallback: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keybind: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<&'a Score>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selector: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    separator: Option<&'a Component>,
+    #[serde(flatten)]
+    style: &'a Style,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    extra: &'a [Component],
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut fields = ComponentFields {
+            text: None,
+            translate: None,
+            with: &[],
+            fallback: None,
+            keybind: None,
+            score: None,
+            selector: None,
+            separator: None,
+            style: &self.style,
+            extra: &self.children,
+        };
+        match &self.content {
+            Content::Text(text) => fields.text = Some(text),
+            Content::Translatable {
+                key,
+                with,
+                fallback,
+            } => {
+                fields.translate = Some(key);
+                fields.with = with;
+                fields.fallback = fallback.as_deref();
+            }
+            Content::Keybind(key) => fields.keybind = Some(key),
+            Content::Score(score) => fields.score = Some(score),
+            Content::Selector {
+                selector,
+                separator,
+            } => {
+                fields.selector = Some(selector);
+                fields.separator = separator.as_deref();
+            }
+        }
+        fields.serialize(serializer)
+    }
+}
+
+/// A component can also be a string, a number, a boolean or an array.
+///
+/// The first component of an array is the parent of the others
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawComponent {
+    String(String),
+    Bool(bool),
+    Number(serde_json::Number),
+    Array(Vec<Component>),
+    Object(Box<ComponentObject>),
+}
+
+#[derive(Deserialize)]
+struct ComponentObject {
+    text: Option<String>,
+    translate: Option<String>,
+    #[serde(default)]
+    with: Vec<Component>,
+    fallback: Option<String>,
+    keybind: Option<String>,
+    score: Option<Score>,
+    selector: Option<String>,
+    separator: Option<Box<Component>>,
+    #[serde(flatten)]
+    style: Style,
+    #[serde(default)]
+    extra: Vec<Component>,
+}
+
+impl TryFrom<RawComponent> for Component {
+    type Error = ChatError;
+
+    fn try_from(raw: RawComponent) -> Result<Self, Self::Error> {
+        let object = match raw {
+            RawComponent::String(text) => return Ok(Component::text(text)),
+            RawComponent::Bool(value) => return Ok(Component::text(value.to_string())),
+            RawComponent::Number(value) => return Ok(Component::text(value.to_string())),
+            RawComponent::Array(components) => {
+                let mut components = components.into_iter();
+                let mut parent = components.next().ok_or(ChatError::EmptyArray)?;
+                parent.children.extend(components);
+                return Ok(parent);
+            }
+            RawComponent::Object(object) => *object,
+        };
+        // The same order the client checks the keys in
+        let content = if let Some(text) = object.text {
+            Content::Text(text)
+        } else if let Some(key) = object.translate {
+            Content::Translatable {
+                key,
+                with: object.with,
+                fallback: object.fallback,
+            }
+        } else if let Some(score) = object.score {
+            Content::Score(score)
+        } else if let Some(selector) = object.selector {
+            Content::Selector {
+                selector,
+                separator: object.separator,
+            }
+        } else if let Some(key) = object.keybind {
+            Content::Keybind(key)
+        } else {
+            return Err(ChatError::MissingContent);
+        };
+        Ok(Component {
+            content,
+            style: object.style,
+            children: object.extra,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        RawComponent::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Sent as a JSON string
+impl PacketDataType for Component {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        // Components are longer than other strings. Same as vanilla
+        let json = limits::read_string(reader, MAX_JSON_LENGTH)?;
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        let json = serde_json::to_string(&self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if json.len() > MAX_JSON_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Text component is longer than {} bytes", MAX_JSON_LENGTH),
+            ));
+        }
+        json.write(writer)
+    }
+}
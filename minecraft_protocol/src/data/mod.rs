@@ -8,11 +8,14 @@ use nbt::Blob;
 use uuid::Uuid;
 
 use crate::data::var_int::VarInt;
+use crate::limits;
 
 pub mod fpoints;
 pub mod sints;
+pub mod slot;
 pub mod uints;
 pub mod var_int;
+pub mod var_long;
 
 pub trait Position {
     fn into_single_long(self) -> i64;
@@ -29,9 +32,10 @@ macro_rules! define_into_position {
             }
 
             fn from_single_long(long: i64) -> Self {
-                let x = (long >> 38) & 0x3FFFFFF;
-                let z = (long >> 12) & 0x3FFFFFF;
-                let y = long & 0xFFF;
+                // Shifting back down keeps the sign of negative coordinates
+                let x = long >> 38;
+                let z = (long << 26) >> 38;
+                let y = (long << 52) >> 52;
                 (x as $t, y as $t, z as $t)
             }
         }
@@ -53,6 +57,27 @@ pub trait PacketDataType {
         Self: Sized;
     fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()>;
 }
+/// An optional NBT compound. Sent as an empty tag if None
+impl PacketDataType for Option<Blob> {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let tag = u8::read(reader)?;
+        if tag == 0 {
+            return Ok(None);
+        }
+        Blob::read(&mut (&[tag][..]).chain(reader)).map(Some)
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        match self {
+            Some(blob) => blob.write(writer),
+            None => 0u8.write(writer),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum NBTOrByteArray {
     NBT(Blob),
@@ -82,9 +107,20 @@ impl PacketDataType for NBTOrByteArray {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A block position packed into a long. See [Position]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct PackedPosition(pub u64);
 
+impl PackedPosition {
+    pub fn new(position: impl Position) -> Self {
+        PackedPosition(position.into_single_long() as u64)
+    }
+
+    pub fn position<P: Position>(self) -> P {
+        P::from_single_long(self.0 as i64)
+    }
+}
+
 impl<T: ?Sized> PacketDataType for Cow<'_, T>
 where
     T: PacketDataType + Clone,
@@ -150,10 +186,7 @@ impl PacketDataType for String {
     where
         Self: Sized,
     {
-        let len = VarInt::read(reader)?;
-        let mut buf = Vec::with_capacity(len.0 as usize);
-        reader.take(len.0 as u64).read_to_end(&mut buf)?;
-        Ok(String::from_utf8_lossy(buf.as_ref()).into_owned())
+        limits::read_string(reader, limits::DecodeLimits::current().max_string_length)
     }
 
     fn write<Writer: Write>(self, writer: &mut Writer) -> std::io::Result<()>
@@ -172,9 +205,9 @@ impl<T: PacketDataType> PacketDataType for Vec<T> {
     where
         Self: Sized,
     {
-        let len = VarInt::read(reader)?;
-        let mut vec = Vec::with_capacity(len.0 as usize);
-        for _ in 0..len.0 {
+        let len = limits::read_array_length(reader)?;
+        let mut vec = limits::with_capacity(len);
+        for _ in 0..len {
             vec.push(T::read(reader)?);
         }
         Ok(vec)
@@ -0,0 +1,73 @@
+use std::io;
+use std::io::{Read, Write};
+
+use nbt::Blob;
+
+use crate::data::var_int::VarInt;
+use crate::data::{NBTOrByteArray, PacketDataType};
+
+/// An item with its count and NBT
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemStack {
+    /// The id in the `minecraft:item` registry
+    pub item_id: i32,
+    pub count: i8,
+    /// None is sent as an empty tag
+    pub nbt: Option<NBTOrByteArray>,
+}
+
+/// An inventory slot. None if the slot is empty
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Slot(pub Option<ItemStack>);
+
+impl Slot {
+    pub const EMPTY: Slot = Slot(None);
+
+    pub fn new(item_id: i32, count: i8) -> Self {
+        Slot(Some(ItemStack {
+            item_id,
+            count,
+            nbt: None,
+        }))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl From<ItemStack> for Slot {
+    fn from(item: ItemStack) -> Self {
+        Slot(Some(item))
+    }
+}
+
+/// A present flag followed by the item id, count and NBT
+impl PacketDataType for Slot {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        if !bool::read(reader)? {
+            return Ok(Slot::EMPTY);
+        }
+        Ok(Slot(Some(ItemStack {
+            item_id: VarInt::read(reader)?.0,
+            count: i8::read(reader)?,
+            nbt: Option::<Blob>::read(reader)?.map(NBTOrByteArray::NBT),
+        })))
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        let Some(item) = self.0 else {
+            return false.write(writer);
+        };
+        true.write(writer)?;
+        VarInt(item.item_id).write(writer)?;
+        item.count.write(writer)?;
+        match item.nbt {
+            Some(nbt) => nbt.write(writer),
+            None => 0u8.write(writer),
+        }
+    }
+}
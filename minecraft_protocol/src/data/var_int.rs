@@ -106,7 +106,9 @@ pub mod inline {
 
     #[inline(always)]
     #[allow(unused_variables, unused_assignments)]
-    pub fn get_size(mut number: i32) -> u8 {
+    pub fn get_size(number: i32) -> u8 {
+        // Negative numbers are written as their unsigned value. So they always take 5 bytes
+        let mut number = number as u32;
         let mut iterations = 0;
         loop {
             let mut temp = (number & 0x7F) as u8;
@@ -154,7 +156,7 @@ pub mod inline {
         var_int: VI,
         write: &mut W,
     ) -> std::io::Result<usize> {
-        let mut x = var_int.into();
+        let mut x = var_int.into() as u32;
         let mut iterations = 0;
         loop {
             let mut temp = (x & 0x7F) as u8;
@@ -0,0 +1,73 @@
+use std::fmt::Display;
+use std::io;
+use std::io::{ErrorKind, Read, Write};
+
+use crate::data::PacketDataType;
+
+/// A variable length i64. Up to 10 bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct VarLong(pub i64);
+
+impl PacketDataType for VarLong {
+    fn read<R: Read>(buf: &mut R) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut number_of_reads = 0;
+        let mut result = 0;
+        let mut byte = [0u8];
+        loop {
+            buf.read_exact(&mut byte)?;
+            let read = byte[0];
+
+            let value = i64::from(read & 0x7F);
+            result |= value.overflowing_shl(7 * number_of_reads).0;
+
+            number_of_reads += 1;
+            if number_of_reads > 10 {
+                return Err(io::Error::new(ErrorKind::InvalidData, "VarLong too long"));
+            }
+            if read & 0x80 == 0 {
+                break;
+            }
+        }
+        Ok(VarLong(result))
+    }
+
+    fn write<W: Write>(self, write: &mut W) -> io::Result<()> {
+        let mut x = self.0 as u64;
+        loop {
+            let mut temp = (x & 0x7F) as u8;
+            x >>= 7;
+            if x != 0 {
+                temp |= 0x80;
+            }
+
+            write.write_all(&[temp])?;
+
+            if x == 0 {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<i64> for VarLong {
+    fn from(value: i64) -> Self {
+        VarLong(value)
+    }
+}
+
+impl From<VarLong> for i64 {
+    fn from(value: VarLong) -> Self {
+        value.0
+    }
+}
+
+impl Display for VarLong {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,6 +1,17 @@
+use std::fmt::{Debug, Formatter};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+use crate::data::var_int;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
 pub mod handshake;
 pub mod status;
+pub mod status_responder;
+pub mod v_760;
 pub mod v_761;
+pub mod v_762;
+pub mod versions;
 
 macro_rules! define_packet {
     ($content:ty, $id:literal, $bound:expr, $stage:expr, $protcol:expr) => {
@@ -28,3 +39,93 @@ macro_rules! call_write {
 }
 pub(crate) use call_write;
 pub(crate) use define_packet;
+
+/// A packet with the same layout as `P` but a different packet id.
+///
+/// Lets a version reuse the implementation of another version when only the packet id changed.
+pub struct RemappedPacket<P, const ID: i32, const VERSION: i32>(PhantomData<P>);
+
+impl<P, const ID: i32, const VERSION: i32> Debug for RemappedPacket<P, ID, VERSION> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RemappedPacket<{}> Protocol: {:?} ID: {:?}",
+            std::any::type_name::<P>(),
+            Protocol::Java(VERSION),
+            ID
+        )
+    }
+}
+
+impl<P: Packet, const ID: i32, const VERSION: i32> Packet for RemappedPacket<P, ID, VERSION> {
+    type Content = P::Content;
+
+    fn write_packet_id<W: Write>(w: &mut W) -> Result<usize, PacketWriteError> {
+        Ok(var_int::inline::write(ID, w)?)
+    }
+
+    fn packet_id() -> i32 {
+        ID
+    }
+
+    fn bound() -> Bound {
+        P::bound()
+    }
+
+    fn stage() -> Stage {
+        P::stage()
+    }
+
+    fn protocol() -> Protocol {
+        Protocol::Java(VERSION)
+    }
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        // P writes its own packet id first. That is dropped
+        let mut writer = SkipWriter {
+            skip: var_int::inline::get_size(P::packet_id()) as usize,
+            inner: w,
+        };
+        P::write(content, &mut writer)
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        P::read(r)
+    }
+
+    fn read_with_length<R: Read>(
+        r: &mut R,
+        length: usize,
+    ) -> Result<Self::Content, PacketReadError> {
+        P::read_with_length(r, length)
+    }
+
+    fn minimum_size() -> usize {
+        P::minimum_size()
+    }
+}
+
+/// Drops the first `skip` bytes written
+struct SkipWriter<'a, W> {
+    skip: usize,
+    inner: &'a mut W,
+}
+
+impl<W: Write> Write for SkipWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.skip == 0 {
+            return self.inner.write(buf);
+        }
+        let skipped = self.skip.min(buf.len());
+        self.skip -= skipped;
+        if skipped == buf.len() {
+            return Ok(skipped);
+        }
+        Ok(skipped + self.inner.write(&buf[skipped..])?)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
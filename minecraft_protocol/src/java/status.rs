@@ -116,6 +116,7 @@ pub struct Version {
 pub struct Players {
     pub max: i32,
     pub online: i32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub sample: Vec<Player>,
 }
 
@@ -130,7 +131,8 @@ pub struct Status<Desc> {
     pub version: Version,
     pub players: Players,
     pub description: Desc,
-    pub favicon: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub favicon: Option<String>,
     #[serde(default, rename = "previewsChat")]
     pub previews_chat: bool,
     #[serde(default, rename = "enforcesSecureChat")]
@@ -0,0 +1,161 @@
+//! Answers the server list ping.
+//!
+//! Both the modern status stage and the pre-Netty legacy `0xFE` ping.
+use std::path::Path;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use thiserror::Error;
+
+use crate::chat::Component;
+use crate::java::status::{
+    ClientBoundStatusPacket, Player, Players, ServerBoundStatusPacket, Status, StatusOrString,
+    Version,
+};
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+/// The protocol version vanilla sends in the legacy response. Older clients show the server as incompatible
+pub const LEGACY_PROTOCOL_VERSION: i32 = 127;
+
+#[derive(Debug, Error)]
+pub enum FaviconError {
+    #[error("Failed to read favicon: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Favicon is not a PNG")]
+    NotPng,
+    #[error("Favicon must be 64x64 but is {0}x{1}")]
+    InvalidSize(u32, u32),
+}
+
+/// The information shown in the server list
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusResponder {
+    pub version: Version,
+    pub max_players: i32,
+    pub online_players: i32,
+    /// The players shown when hovering over the player count
+    pub sample: Vec<Player>,
+    pub motd: Component,
+    /// `data:image/png;base64,<data>`
+    pub favicon: Option<String>,
+    pub enforces_secure_chat: bool,
+}
+
+impl StatusResponder {
+    pub fn new(version: Version, max_players: i32) -> Self {
+        Self {
+            version,
+            max_players,
+            online_players: 0,
+            sample: vec![],
+            motd: Component::text("A Minecraft Server"),
+            favicon: None,
+            enforces_secure_chat: false,
+        }
+    }
+
+    /// Sets the MOTD to a single text component without any formatting
+    pub fn set_motd_text(&mut self, motd: impl Into<String>) {
+        self.motd = Component::text(motd);
+    }
+
+    /// Sets the favicon from the contents of a 64x64 PNG
+    pub fn set_favicon_png(&mut self, png: &[u8]) -> Result<(), FaviconError> {
+        // Signature, IHDR length and type, then the width and height
+        if png.len() < 24 || png[..8] != PNG_SIGNATURE || &png[12..16] != b"IHDR" {
+            return Err(FaviconError::NotPng);
+        }
+        let width = u32::from_be_bytes([png[16], png[17], png[18], png[19]]);
+        let height = u32::from_be_bytes([png[20], png[21], png[22], png[23]]);
+        if width != 64 || height != 64 {
+            return Err(FaviconError::InvalidSize(width, height));
+        }
+        self.favicon = Some(format!("data:image/png;base64,{}", STANDARD.encode(png)));
+        Ok(())
+    }
+
+    pub fn load_favicon(&mut self, path: impl AsRef<Path>) -> Result<(), FaviconError> {
+        let png = std::fs::read(path)?;
+        self.set_favicon_png(&png)
+    }
+
+    pub fn status(&self) -> Status<Component> {
+        Status {
+            version: self.version.clone(),
+            players: Players {
+                max: self.max_players,
+                online: self.online_players,
+                sample: self.sample.clone(),
+            },
+            description: self.motd.clone(),
+            favicon: self.favicon.clone(),
+            previews_chat: false,
+            enforced_secure_chat: self.enforces_secure_chat,
+        }
+    }
+
+    /// The response to a packet in the status stage
+    pub fn handle(&self, packet: ServerBoundStatusPacket) -> ClientBoundStatusPacket<Component> {
+        match packet {
+            ServerBoundStatusPacket::Request => {
+                ClientBoundStatusPacket::Response(StatusOrString::Status(self.status()))
+            }
+            ServerBoundStatusPacket::Ping(payload) => ClientBoundStatusPacket::Ping(payload),
+        }
+    }
+
+    /// The MOTD without any formatting. Beta clients do not support formatting codes
+    pub fn plain_motd(&self) -> String {
+        self.motd.to_plain_text()
+    }
+
+    /// The kick packet that answers a legacy ping. The connection should be closed after sending it.
+    pub fn legacy_response(&self, ping: LegacyPing) -> Vec<u8> {
+        let response = match ping {
+            LegacyPing::Beta => format!(
+                "{}§{}§{}",
+                self.plain_motd().replace('§', ""),
+                self.online_players,
+                self.max_players
+            ),
+            LegacyPing::V1_4 => format!(
+                "§1\0{}\0{}\0{}\0{}\0{}",
+                LEGACY_PROTOCOL_VERSION,
+                self.version.name,
+                self.motd.to_legacy(),
+                self.online_players,
+                self.max_players
+            ),
+        };
+        let response: Vec<u16> = response.encode_utf16().collect();
+        let mut packet = Vec::with_capacity(3 + response.len() * 2);
+        packet.push(0xFF);
+        packet.extend_from_slice(&(response.len() as u16).to_be_bytes());
+        for unit in response {
+            packet.extend_from_slice(&unit.to_be_bytes());
+        }
+        packet
+    }
+}
+
+/// A ping sent by a client from before the Netty rewrite (1.6 and older)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyPing {
+    /// Beta 1.8 to 1.3. Only `0xFE` is sent
+    Beta,
+    /// 1.4 to 1.6. `0xFE 0x01` followed by a plugin message in 1.6
+    V1_4,
+}
+
+impl LegacyPing {
+    /// Checks the first bytes received on a connection before they are read as a packet.
+    ///
+    /// Returns None for a modern handshake
+    pub fn detect(buffer: &[u8]) -> Option<LegacyPing> {
+        match buffer {
+            [0xFE] => Some(LegacyPing::Beta),
+            [0xFE, 0x01, ..] => Some(LegacyPing::V1_4),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::io::{Read, Write};
+
+use log::warn;
+use uuid::Uuid;
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+/// The client bound login packets did not change in 1.19.3
+pub use crate::java::v_761::login::ClientIO;
+pub use server_bound::PacketIOImpl as ServerIO;
+
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::java::v_761::login::plugin::ServerBoundPluginResponseImpl;
+use crate::packets::login::server_bound::{ServerBoundEncryptionResponse, ServerBoundLoginStart};
+use crate::packets::login::ServerBoundLogin;
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// Login Start with the chat signing key of the player.
+///
+/// Based on [wiki.vg](https://wiki.vg/index.php?title=Protocol&oldid=17753#Login_Start)
+#[derive(PacketImplDebug)]
+pub struct ServerBoundLoginStartImpl;
+
+impl Packet for ServerBoundLoginStartImpl {
+    define_packet!(
+        ServerBoundLoginStart,
+        0x00,
+        Bound::ServerBound,
+        Stage::Login,
+        Java(760)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.name.write(w)?;
+        // No signature data
+        false.write(w)?;
+        if let Some(uuid) = content.uuid {
+            true.write(w)?;
+            uuid.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let name = String::read(r)?;
+        if bool::read(r)? {
+            // Chat signing is not supported. The key is skipped
+            let _timestamp = i64::read(r)?;
+            let _public_key = Vec::<u8>::read(r)?;
+            let _signature = Vec::<u8>::read(r)?;
+        }
+        let uuid = if bool::read(r)? {
+            Some(Uuid::read(r)?)
+        } else {
+            None
+        };
+        Ok(ServerBoundLoginStart { name, uuid })
+    }
+}
+
+/// Based on [wiki.vg](https://wiki.vg/index.php?title=Protocol&oldid=17753#Encryption_Response)
+#[derive(PacketImplDebug)]
+pub struct ServerBoundEncryptionResponseImpl;
+
+impl Packet for ServerBoundEncryptionResponseImpl {
+    define_packet!(
+        ServerBoundEncryptionResponse,
+        0x01,
+        Bound::ServerBound,
+        Stage::Login,
+        Java(760)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.shared_secret.write(w)?;
+        true.write(w)?;
+        content.verify_token.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let shared_secret = Vec::<u8>::read(r)?;
+        if !bool::read(r)? {
+            // The client signed the verify token with its chat signing key
+            warn!("Received a salt signature instead of a verify token");
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Verifying the encryption response with a salt signature is not supported"
+            )));
+        }
+        let verify_token = Vec::<u8>::read(r)?;
+        Ok(ServerBoundEncryptionResponse {
+            shared_secret,
+            verify_token,
+        })
+    }
+}
+
+mod server_bound {
+    use minecraft_protocol_macros::define_io;
+
+    use crate::PacketIO;
+    use crate::{PacketReadError, PacketWriteError};
+
+    use super::*;
+
+    define_io!(ServerBoundLogin {
+        0x00 => {
+            type_name: ServerBoundLoginStartImpl
+            g_var: LoginStart
+        },
+        0x01 => {
+            type_name: ServerBoundEncryptionResponseImpl
+            g_var: EncryptionResponse
+        },
+        0x02 => {
+            type_name: ServerBoundPluginResponseImpl
+            g_var: PluginResponse
+        }
+    });
+}
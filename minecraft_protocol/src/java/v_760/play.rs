@@ -0,0 +1,609 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+pub use client_bound::PacketIOImpl as ClientIO;
+pub use server_bound::PacketIOImpl as ServerIO;
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::java::v_761::play::server::{
+    write_move, SetPlayerPosition, SetPlayerPositionAndRotation, SetPlayerRotation,
+};
+use crate::packets::play::client::sound::{
+    EntitySoundEffect, SoundCategory, SoundEffect, SoundEvent,
+};
+use crate::packets::play::client::ServerData;
+use crate::packets::play::server::ServerBoundMove;
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+const MOVE_IDS: [i32; 3] = [0x14, 0x15, 0x16];
+
+/// 1.19.2 still sends if chat previews are enabled
+#[derive(PacketImplDebug)]
+pub struct ClientBoundServerDataImpl;
+
+impl Packet for ClientBoundServerDataImpl {
+    define_packet!(ServerData, 0x42, Bound::ClientBound, Stage::Play, Java(760));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        if let Some(motd) = content.motd {
+            true.write(w)?;
+            motd.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        if let Some(icon) = content.icon {
+            true.write(w)?;
+            icon.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        content.previews_chat.write(w)?;
+        content.enforced_secure_chat.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let motd = if bool::read(r)? {
+            Some(Component::read(r)?)
+        } else {
+            None
+        };
+        let icon = if bool::read(r)? {
+            Some(String::read(r)?)
+        } else {
+            None
+        };
+        Ok(ServerData {
+            motd,
+            icon,
+            previews_chat: bool::read(r)?,
+            enforced_secure_chat: bool::read(r)?,
+        })
+    }
+}
+
+/// 1.19.2 only sends registry ids. Named sounds have their own packet
+fn write_sound_event<W: Write>(sound: SoundEvent, w: &mut W) -> Result<(), PacketWriteError> {
+    match sound {
+        SoundEvent::Registered(id) => {
+            VarInt(id).write(w)?;
+            Ok(())
+        }
+        SoundEvent::Named { name, .. } => Err(PacketWriteError::Other(format!(
+            "Named sound {} is not supported by 1.19.2",
+            name
+        ))),
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSoundEffectImpl;
+
+impl Packet for ClientBoundSoundEffectImpl {
+    define_packet!(
+        SoundEffect,
+        0x60,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(760)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        write_sound_event(content.sound, w)?;
+        content.category.write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.volume.write(w)?;
+        content.pitch.write(w)?;
+        content.seed.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SoundEffect {
+            sound: SoundEvent::Registered(VarInt::read(r)?.0),
+            category: SoundCategory::read(r)?,
+            x: i32::read(r)?,
+            y: i32::read(r)?,
+            z: i32::read(r)?,
+            volume: f32::read(r)?,
+            pitch: f32::read(r)?,
+            seed: i64::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundEntitySoundEffectImpl;
+
+impl Packet for ClientBoundEntitySoundEffectImpl {
+    define_packet!(
+        EntitySoundEffect,
+        0x5F,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(760)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        write_sound_event(content.sound, w)?;
+        content.category.write(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.volume.write(w)?;
+        content.pitch.write(w)?;
+        content.seed.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(EntitySoundEffect {
+            sound: SoundEvent::Registered(VarInt::read(r)?.0),
+            category: SoundCategory::read(r)?,
+            entity_id: VarInt::read(r)?.0,
+            volume: f32::read(r)?,
+            pitch: f32::read(r)?,
+            seed: i64::read(r)?,
+        })
+    }
+}
+
+macro_rules! move_packet {
+    ($name:ident, $shared:ty, $id:literal) => {
+        /// The layout is shared with 1.19.3. Only the packet id changed
+        #[derive(PacketImplDebug)]
+        pub struct $name;
+
+        impl Packet for $name {
+            define_packet!(
+                ServerBoundMove,
+                $id,
+                Bound::ServerBound,
+                Stage::Play,
+                Java(760)
+            );
+
+            fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+                write_move(content, MOVE_IDS, w)
+            }
+
+            fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+                <$shared>::read(r)
+            }
+        }
+    };
+}
+move_packet!(SetPlayerPositionImpl, SetPlayerPosition, 0x14);
+move_packet!(
+    SetPlayerPositionAndRotationImpl,
+    SetPlayerPositionAndRotation,
+    0x15
+);
+move_packet!(SetPlayerRotationImpl, SetPlayerRotation, 0x16);
+
+mod client_bound {
+    use minecraft_protocol_macros::define_io;
+
+    use crate::java::v_761::play::client::block::{
+        ClientBoundAcknowledgeBlockChangeImpl, ClientBoundBlockActionImpl,
+        ClientBoundBlockEntityDataImpl, ClientBoundBlockUpdateImpl,
+        ClientBoundUpdateSectionBlocksImpl,
+    };
+    use crate::java::v_761::play::client::chat::ClientBoundSystemChatImpl;
+    use crate::java::v_761::play::client::chunk::{
+        ClientBoundChunkDataImpl, ClientBoundLightUpdateImpl, ClientBoundSetCenterChunkImpl,
+        ClientBoundUnloadChunkImpl,
+    };
+    use crate::java::v_761::play::client::commands::ClientBoundCommandSuggestionsImpl;
+    use crate::java::v_761::play::client::container::{
+        ClientBoundCloseContainerImpl, ClientBoundOpenScreenImpl, ClientBoundSelectedSlotImpl,
+        ClientBoundSetContainerContentImpl, ClientBoundSetContainerPropertyImpl,
+        ClientBoundSetContainerSlotImpl,
+    };
+    use crate::java::v_761::play::client::entity::{
+        ClientBoundRemoveEntitiesImpl, ClientBoundSetEntityVelocityImpl,
+        ClientBoundSetEquipmentImpl, ClientBoundSetHeadRotationImpl, ClientBoundSpawnEntityImpl,
+        ClientBoundSpawnExperienceOrbImpl, ClientBoundSpawnPlayerImpl,
+        ClientBoundTeleportEntityImpl, ClientBoundUpdateEntityPositionAndRotationImpl,
+        ClientBoundUpdateEntityPositionImpl, ClientBoundUpdateEntityRotationImpl,
+    };
+    use crate::java::v_761::play::client::hud::{
+        ClientBoundBossBarImpl, ClientBoundClearTitlesImpl, ClientBoundSetActionBarTextImpl,
+        ClientBoundSetSubtitleTextImpl, ClientBoundSetTabListHeaderAndFooterImpl,
+        ClientBoundSetTitleAnimationTimesImpl, ClientBoundSetTitleTextImpl,
+    };
+    use crate::java::v_761::play::client::particle::ClientBoundParticleImpl;
+    use crate::java::v_761::play::client::player_info::SyncPlayerPositionImpl;
+    use crate::java::v_761::play::client::respawn::{
+        ClientBoundRespawnImpl, ClientBoundSetDefaultSpawnPositionImpl,
+    };
+    use crate::java::v_761::play::client::scoreboard::{
+        ClientBoundDisplayObjectiveImpl, ClientBoundUpdateObjectivesImpl,
+        ClientBoundUpdateScoreImpl, ClientBoundUpdateTeamsImpl,
+    };
+    use crate::java::v_761::play::client::sound::ClientBoundStopSoundImpl;
+    use crate::java::v_761::play::client::world::{
+        ClientBoundGameEventImpl, ClientBoundInitializeWorldBorderImpl,
+        ClientBoundSetBorderCenterImpl, ClientBoundSetBorderLerpSizeImpl,
+        ClientBoundSetBorderSizeImpl, ClientBoundSetBorderWarningDelayImpl,
+        ClientBoundSetBorderWarningDistanceImpl, ClientBoundUpdateTimeImpl,
+    };
+    use crate::java::v_761::play::client::{
+        ClientBoundChangeDifficulty, ClientBoundDisconnectPacketImpl, ClientBoundKeepAliveImpl,
+        ClientBoundLoginPacketImpl, ClientBoundPingImpl, ClientBoundPluginMessageImpl,
+        ClientBoundResourcePackImpl, ClientBoundSetAbilities,
+    };
+    use crate::java::RemappedPacket;
+    use crate::packets::play::client::ClientBoundPlay;
+    use crate::PacketIO;
+    use crate::{PacketReadError, PacketWriteError};
+
+    use super::*;
+
+    define_io!(ClientBoundPlay {
+        0x25 => {
+            type_name: RemappedPacket<ClientBoundLoginPacketImpl, 0x25, 760>
+            g_var: Login
+        },
+        0x16 => {
+            type_name: RemappedPacket<ClientBoundPluginMessageImpl, 0x16, 760>
+            g_var: PluginMessage
+        },
+        0x31 => {
+            type_name: RemappedPacket<ClientBoundSetAbilities, 0x31, 760>
+            g_var: Abilities
+        },
+        0x0B => {
+            type_name: ClientBoundChangeDifficulty
+            g_var: ChangeDifficulty
+        },
+        0x19 => {
+            type_name: RemappedPacket<ClientBoundDisconnectPacketImpl, 0x19, 760>
+            g_var: Disconnect
+        },
+        0x20 => {
+            type_name: RemappedPacket<ClientBoundKeepAliveImpl, 0x20, 760>
+            g_var: KeepAlive
+        },
+        0x2F => {
+            type_name: RemappedPacket<ClientBoundPingImpl, 0x2F, 760>
+            g_var: Ping
+        },
+        0x39 => {
+            type_name: RemappedPacket<SyncPlayerPositionImpl, 0x39, 760>
+            g_var: SyncPlayerPosition
+        },
+        0x21 => {
+            type_name: RemappedPacket<ClientBoundChunkDataImpl, 0x21, 760>
+            g_var: ChunkData
+        },
+        0x24 => {
+            type_name: RemappedPacket<ClientBoundLightUpdateImpl, 0x24, 760>
+            g_var: UpdateLight
+        },
+        0x42 => {
+            type_name: ClientBoundServerDataImpl
+            g_var: ServerData
+        },
+        0x62 => {
+            type_name: RemappedPacket<ClientBoundSystemChatImpl, 0x62, 760>
+            g_var: SystemChat
+        },
+        0x09 => {
+            type_name: ClientBoundBlockUpdateImpl
+            g_var: BlockUpdate
+        },
+        0x40 => {
+            type_name: RemappedPacket<ClientBoundUpdateSectionBlocksImpl, 0x40, 760>
+            g_var: UpdateSectionBlocks
+        },
+        0x07 => {
+            type_name: ClientBoundBlockEntityDataImpl
+            g_var: BlockEntityData
+        },
+        0x08 => {
+            type_name: ClientBoundBlockActionImpl
+            g_var: BlockAction
+        },
+        0x05 => {
+            type_name: ClientBoundAcknowledgeBlockChangeImpl
+            g_var: AcknowledgeBlockChange
+        },
+        0x00 => {
+            type_name: ClientBoundSpawnEntityImpl
+            g_var: SpawnEntity
+        },
+        0x01 => {
+            type_name: ClientBoundSpawnExperienceOrbImpl
+            g_var: SpawnExperienceOrb
+        },
+        0x02 => {
+            type_name: ClientBoundSpawnPlayerImpl
+            g_var: SpawnPlayer
+        },
+        0x28 => {
+            type_name: RemappedPacket<ClientBoundUpdateEntityPositionImpl, 0x28, 760>
+            g_var: UpdateEntityPosition
+        },
+        0x29 => {
+            type_name: RemappedPacket<ClientBoundUpdateEntityPositionAndRotationImpl, 0x29, 760>
+            g_var: UpdateEntityPositionAndRotation
+        },
+        0x2A => {
+            type_name: RemappedPacket<ClientBoundUpdateEntityRotationImpl, 0x2A, 760>
+            g_var: UpdateEntityRotation
+        },
+        0x66 => {
+            type_name: RemappedPacket<ClientBoundTeleportEntityImpl, 0x66, 760>
+            g_var: TeleportEntity
+        },
+        0x3F => {
+            type_name: RemappedPacket<ClientBoundSetHeadRotationImpl, 0x3F, 760>
+            g_var: SetHeadRotation
+        },
+        0x52 => {
+            type_name: RemappedPacket<ClientBoundSetEntityVelocityImpl, 0x52, 760>
+            g_var: SetEntityVelocity
+        },
+        0x3B => {
+            type_name: RemappedPacket<ClientBoundRemoveEntitiesImpl, 0x3B, 760>
+            g_var: RemoveEntities
+        },
+        0x53 => {
+            type_name: RemappedPacket<ClientBoundSetEquipmentImpl, 0x53, 760>
+            g_var: SetEquipment
+        },
+        0x2D => {
+            type_name: RemappedPacket<ClientBoundOpenScreenImpl, 0x2D, 760>
+            g_var: OpenScreen
+        },
+        0x11 => {
+            type_name: RemappedPacket<ClientBoundSetContainerContentImpl, 0x11, 760>
+            g_var: SetContainerContent
+        },
+        0x13 => {
+            type_name: RemappedPacket<ClientBoundSetContainerSlotImpl, 0x13, 760>
+            g_var: SetContainerSlot
+        },
+        0x12 => {
+            type_name: RemappedPacket<ClientBoundSetContainerPropertyImpl, 0x12, 760>
+            g_var: SetContainerProperty
+        },
+        0x10 => {
+            type_name: RemappedPacket<ClientBoundCloseContainerImpl, 0x10, 760>
+            g_var: CloseContainer
+        },
+        0x4A => {
+            type_name: RemappedPacket<ClientBoundSelectedSlotImpl, 0x4A, 760>
+            g_var: SelectedSlot
+        },
+        0x0E => {
+            type_name: RemappedPacket<ClientBoundCommandSuggestionsImpl, 0x0E, 760>
+            g_var: CommandSuggestions
+        },
+        0x3D => {
+            type_name: RemappedPacket<ClientBoundResourcePackImpl, 0x3D, 760>
+            g_var: ResourcePack
+        },
+        0x0A => {
+            type_name: RemappedPacket<ClientBoundBossBarImpl, 0x0A, 760>
+            g_var: BossBar
+        },
+        0x0D => {
+            type_name: RemappedPacket<ClientBoundClearTitlesImpl, 0x0D, 760>
+            g_var: ClearTitles
+        },
+        0x43 => {
+            type_name: RemappedPacket<ClientBoundSetActionBarTextImpl, 0x43, 760>
+            g_var: SetActionBarText
+        },
+        0x5B => {
+            type_name: RemappedPacket<ClientBoundSetSubtitleTextImpl, 0x5B, 760>
+            g_var: SetSubtitleText
+        },
+        0x5D => {
+            type_name: RemappedPacket<ClientBoundSetTitleTextImpl, 0x5D, 760>
+            g_var: SetTitleText
+        },
+        0x5E => {
+            type_name: RemappedPacket<ClientBoundSetTitleAnimationTimesImpl, 0x5E, 760>
+            g_var: SetTitleAnimationTimes
+        },
+        0x63 => {
+            type_name: RemappedPacket<ClientBoundSetTabListHeaderAndFooterImpl, 0x63, 760>
+            g_var: SetTabListHeaderAndFooter
+        },
+        0x56 => {
+            type_name: RemappedPacket<ClientBoundUpdateObjectivesImpl, 0x56, 760>
+            g_var: UpdateObjectives
+        },
+        0x4F => {
+            type_name: RemappedPacket<ClientBoundDisplayObjectiveImpl, 0x4F, 760>
+            g_var: DisplayObjective
+        },
+        0x59 => {
+            type_name: RemappedPacket<ClientBoundUpdateScoreImpl, 0x59, 760>
+            g_var: UpdateScore
+        },
+        0x58 => {
+            type_name: RemappedPacket<ClientBoundUpdateTeamsImpl, 0x58, 760>
+            g_var: UpdateTeams
+        },
+        0x1F => {
+            type_name: RemappedPacket<ClientBoundInitializeWorldBorderImpl, 0x1F, 760>
+            g_var: InitializeWorldBorder
+        },
+        0x44 => {
+            type_name: RemappedPacket<ClientBoundSetBorderCenterImpl, 0x44, 760>
+            g_var: SetBorderCenter
+        },
+        0x45 => {
+            type_name: RemappedPacket<ClientBoundSetBorderLerpSizeImpl, 0x45, 760>
+            g_var: SetBorderLerpSize
+        },
+        0x46 => {
+            type_name: RemappedPacket<ClientBoundSetBorderSizeImpl, 0x46, 760>
+            g_var: SetBorderSize
+        },
+        0x47 => {
+            type_name: RemappedPacket<ClientBoundSetBorderWarningDelayImpl, 0x47, 760>
+            g_var: SetBorderWarningDelay
+        },
+        0x48 => {
+            type_name: RemappedPacket<ClientBoundSetBorderWarningDistanceImpl, 0x48, 760>
+            g_var: SetBorderWarningDistance
+        },
+        0x5C => {
+            type_name: RemappedPacket<ClientBoundUpdateTimeImpl, 0x5C, 760>
+            g_var: UpdateTime
+        },
+        0x1D => {
+            type_name: RemappedPacket<ClientBoundGameEventImpl, 0x1D, 760>
+            g_var: GameEvent
+        },
+        0x60 => {
+            type_name: ClientBoundSoundEffectImpl
+            g_var: SoundEffect
+        },
+        0x5F => {
+            type_name: ClientBoundEntitySoundEffectImpl
+            g_var: EntitySoundEffect
+        },
+        0x61 => {
+            type_name: RemappedPacket<ClientBoundStopSoundImpl, 0x61, 760>
+            g_var: StopSound
+        },
+        0x23 => {
+            type_name: RemappedPacket<ClientBoundParticleImpl, 0x23, 760>
+            g_var: Particle
+        },
+        0x3E => {
+            type_name: RemappedPacket<ClientBoundRespawnImpl, 0x3E, 760>
+            g_var: Respawn
+        },
+        0x4D => {
+            type_name: RemappedPacket<ClientBoundSetDefaultSpawnPositionImpl, 0x4D, 760>
+            g_var: SetDefaultSpawnPosition
+        },
+        0x4B => {
+            type_name: RemappedPacket<ClientBoundSetCenterChunkImpl, 0x4B, 760>
+            g_var: SetCenterChunk
+        },
+        0x1C => {
+            type_name: RemappedPacket<ClientBoundUnloadChunkImpl, 0x1C, 760>
+            g_var: UnloadChunk
+        }
+    });
+}
+
+mod server_bound {
+    use minecraft_protocol_macros::define_io;
+
+    use crate::java::v_761::play::server::container::{
+        ClickContainerImpl, ServerBoundCloseContainerImpl, SetCreativeModeSlotImpl, SetHeldItemImpl,
+    };
+    use crate::java::v_761::play::server::interaction::{
+        InteractImpl, PlayerActionImpl, PlayerCommandImpl, SwingArmImpl, UseItemImpl, UseItemOnImpl,
+    };
+    use crate::java::v_761::play::server::{
+        ClientInformationImpl, CommandSuggestionsRequestImpl, ConfirmTeleportImpl, KeepAlivePacket,
+        PongPacket, ResourcePackStatusImpl, ServerBoundPluginMessageImpl,
+    };
+    use crate::java::RemappedPacket;
+    use crate::packets::play::server::ServerBoundPlay;
+    use crate::PacketIO;
+    use crate::{PacketReadError, PacketWriteError};
+
+    use super::*;
+
+    define_io!(ServerBoundPlay {
+        0x14 => {
+            type_name: SetPlayerPositionImpl
+            g_var: PlayerMove
+        },
+        0x15 => {
+            type_name: SetPlayerPositionAndRotationImpl
+            g_var: PlayerMove
+        },
+        0x16 => {
+            type_name: SetPlayerRotationImpl
+            g_var: PlayerMove
+        },
+        0x20 => {
+            type_name: RemappedPacket<PongPacket, 0x20, 760>
+            g_var: Ping
+        },
+        0x12 => {
+            type_name: RemappedPacket<KeepAlivePacket, 0x12, 760>
+            g_var: KeepAlive
+        },
+        0x08 => {
+            type_name: RemappedPacket<ClientInformationImpl, 0x08, 760>
+            g_var: ClientInformation
+        },
+        0x00 => {
+            type_name: ConfirmTeleportImpl
+            g_var: ConfirmTeleport
+        },
+        0x0D => {
+            type_name: RemappedPacket<ServerBoundPluginMessageImpl, 0x0D, 760>
+            g_var: PluginMessage
+        },
+        0x0B => {
+            type_name: RemappedPacket<ClickContainerImpl, 0x0B, 760>
+            g_var: ClickContainer
+        },
+        0x0C => {
+            type_name: RemappedPacket<ServerBoundCloseContainerImpl, 0x0C, 760>
+            g_var: CloseContainer
+        },
+        0x2B => {
+            type_name: SetCreativeModeSlotImpl
+            g_var: SetCreativeModeSlot
+        },
+        0x28 => {
+            type_name: SetHeldItemImpl
+            g_var: SetHeldItem
+        },
+        0x09 => {
+            type_name: RemappedPacket<CommandSuggestionsRequestImpl, 0x09, 760>
+            g_var: CommandSuggestionsRequest
+        },
+        0x24 => {
+            type_name: ResourcePackStatusImpl
+            g_var: ResourcePackStatus
+        },
+        0x1D => {
+            type_name: RemappedPacket<PlayerActionImpl, 0x1D, 760>
+            g_var: PlayerAction
+        },
+        0x31 => {
+            type_name: UseItemOnImpl
+            g_var: UseItemOn
+        },
+        0x32 => {
+            type_name: UseItemImpl
+            g_var: UseItem
+        },
+        0x2F => {
+            type_name: SwingArmImpl
+            g_var: SwingArm
+        },
+        0x10 => {
+            type_name: RemappedPacket<InteractImpl, 0x10, 760>
+            g_var: Interact
+        },
+        0x1E => {
+            type_name: RemappedPacket<PlayerCommandImpl, 0x1E, 760>
+            g_var: PlayerCommand
+        }
+    });
+}
@@ -4,9 +4,11 @@ use uuid::Uuid;
 
 use minecraft_protocol_macros::PacketImplDebug;
 
-use crate::data::var_int::{inline, VarInt};
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
 use crate::data::{var_int, PacketDataType};
 use crate::java::define_packet;
+use crate::limits;
 use crate::packets::login::client_bound::{Disconnect, LoginSuccess, SetCompression};
 use crate::packets::login::Property;
 use crate::Protocol::Java;
@@ -32,14 +34,13 @@ impl Packet for ClientBoundDisconnectImpl {
     }
 
     fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
-        debug_assert!(content.reason.len() <= 262144);
         Self::write_packet_id(w)?;
         content.reason.write(w)?;
         Ok(())
     }
 
     fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
-        let reason = String::read(r)?;
+        let reason = Component::read(r)?;
         Ok(Disconnect { reason })
     }
 }
@@ -78,8 +79,8 @@ impl Packet for ClientBoundLoginSuccessImpl {
     fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
         let uuid = Uuid::read(r)?;
         let username = String::read(r)?;
-        let properties_len = inline::read(r)?.0;
-        let mut properties = Vec::with_capacity(properties_len as usize);
+        let properties_len = limits::read_array_length(r)?;
+        let mut properties = limits::with_capacity(properties_len);
         for _ in 0..properties_len {
             let name = String::read(r)?;
             let value = String::read(r)?;
@@ -48,7 +48,7 @@ impl Packet for ClientBoundPluginImpl {
         let message_id = VarInt::read(r)?;
         let channel = String::read(r)?;
         // This could possibly be slightly bigger than the actual length of the data, but it's not a big deal. At most 6 bytes.
-        let mut data = Vec::with_capacity(length - (channel.len()));
+        let mut data = Vec::with_capacity(length.saturating_sub(channel.len()));
 
         r.read_to_end(&mut data)?;
         Ok(ClientBoundPluginRequest {
@@ -104,7 +104,7 @@ impl Packet for ServerBoundPluginResponseImpl {
     ) -> Result<Self::Content, PacketReadError> {
         let message_id = VarInt::read(r)?;
         let successful = bool::read(r)?;
-        let mut data = Vec::with_capacity(length - 1);
+        let mut data = Vec::with_capacity(length.saturating_sub(1));
         r.read_to_end(&mut data)?;
         Ok(ServerBoundLoginPluginResponse {
             message_id,
@@ -0,0 +1,141 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::{Packet, PacketImplDebug};
+
+use crate::data::var_int::VarInt;
+use crate::data::{PackedPosition, PacketDataType};
+use crate::java::define_packet;
+use crate::limits;
+use crate::packets::play::client::block::{
+    AcknowledgeBlockChange, BlockAction, BlockEntityData, BlockUpdate, SectionBlock,
+    SectionPosition, UpdateSectionBlocks,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x05, bound = ClientBound, stage = Play, protocol = 761, content = AcknowledgeBlockChange)]
+pub struct ClientBoundAcknowledgeBlockChangeImpl(VarInt);
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundBlockEntityDataImpl;
+
+impl Packet for ClientBoundBlockEntityDataImpl {
+    define_packet!(
+        BlockEntityData,
+        0x07,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.location.write(w)?;
+        VarInt(content.block_entity_type).write(w)?;
+        content.data.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(BlockEntityData {
+            location: PackedPosition::read(r)?,
+            block_entity_type: VarInt::read(r)?.0,
+            data: PacketDataType::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundBlockActionImpl;
+
+impl Packet for ClientBoundBlockActionImpl {
+    define_packet!(
+        BlockAction,
+        0x08,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.location.write(w)?;
+        content.action_id.write(w)?;
+        content.action_parameter.write(w)?;
+        VarInt(content.block_type).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(BlockAction {
+            location: PackedPosition::read(r)?,
+            action_id: u8::read(r)?,
+            action_parameter: u8::read(r)?,
+            block_type: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundBlockUpdateImpl;
+
+impl Packet for ClientBoundBlockUpdateImpl {
+    define_packet!(
+        BlockUpdate,
+        0x09,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.location.write(w)?;
+        VarInt(content.block_state).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(BlockUpdate {
+            location: PackedPosition::read(r)?,
+            block_state: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateSectionBlocksImpl;
+
+impl Packet for ClientBoundUpdateSectionBlocksImpl {
+    define_packet!(
+        UpdateSectionBlocks,
+        0x3F,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.section.write(w)?;
+        content.suppress_light_updates.write(w)?;
+        content.blocks.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let section = SectionPosition::read(r)?;
+        let suppress_light_updates = bool::read(r)?;
+        let length = limits::read_array_length(r)?;
+        let mut blocks = limits::with_capacity(length);
+        for _ in 0..length {
+            blocks.push(SectionBlock::read(r)?);
+        }
+        Ok(UpdateSectionBlocks {
+            section,
+            suppress_light_updates,
+            blocks,
+        })
+    }
+}
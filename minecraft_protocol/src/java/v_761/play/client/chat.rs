@@ -0,0 +1,147 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::packets::play::client::chat::{
+    ChatTypeBinding, DisguisedChatMessage, FilterMask, PackedSignature, PlayerChatMessage,
+    SystemChatMessage,
+};
+use crate::packets::play::MessageSignature;
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// The most previous messages a player chat message can reference
+pub const MAX_PREVIOUS_MESSAGES: i32 = 20;
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSystemChatImpl;
+
+impl Packet for ClientBoundSystemChatImpl {
+    define_packet!(
+        SystemChatMessage,
+        0x60,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.content.write(w)?;
+        content.overlay.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SystemChatMessage {
+            content: Component::read(r)?,
+            overlay: bool::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundPlayerChatImpl;
+
+impl Packet for ClientBoundPlayerChatImpl {
+    define_packet!(
+        PlayerChatMessage,
+        0x31,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.sender.write(w)?;
+        VarInt(content.index).write(w)?;
+        if let Some(signature) = content.signature {
+            true.write(w)?;
+            signature.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        content.message.write(w)?;
+        content.timestamp.write(w)?;
+        content.salt.write(w)?;
+        content.previous_messages.write(w)?;
+        if let Some(unsigned_content) = content.unsigned_content {
+            true.write(w)?;
+            unsigned_content.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        content.filter.write(w)?;
+        content.chat_type.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let sender = PacketDataType::read(r)?;
+        let index = VarInt::read(r)?.0;
+        let signature = if bool::read(r)? {
+            Some(MessageSignature::read(r)?)
+        } else {
+            None
+        };
+        let message = String::read(r)?;
+        let timestamp = i64::read(r)?;
+        let salt = i64::read(r)?;
+        let previous_messages = Vec::<PackedSignature>::read(r)?;
+        if previous_messages.len() > MAX_PREVIOUS_MESSAGES as usize {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Too many previous messages {}",
+                previous_messages.len()
+            )));
+        }
+        let unsigned_content = if bool::read(r)? {
+            Some(Component::read(r)?)
+        } else {
+            None
+        };
+        Ok(PlayerChatMessage {
+            sender,
+            index,
+            signature,
+            message,
+            timestamp,
+            salt,
+            previous_messages,
+            unsigned_content,
+            filter: FilterMask::read(r)?,
+            chat_type: ChatTypeBinding::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundDisguisedChatImpl;
+
+impl Packet for ClientBoundDisguisedChatImpl {
+    define_packet!(
+        DisguisedChatMessage,
+        0x18,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.message.write(w)?;
+        content.chat_type.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(DisguisedChatMessage {
+            message: Component::read(r)?,
+            chat_type: ChatTypeBinding::read(r)?,
+        })
+    }
+}
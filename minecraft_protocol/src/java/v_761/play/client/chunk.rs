@@ -5,8 +5,10 @@ use minecraft_protocol_macros::PacketImplDebug;
 use crate::data::var_int::VarInt;
 use crate::data::PacketDataType;
 use crate::java::define_packet;
+use crate::limits;
 use crate::packets::play::client::chunk::{
-    BlockEntity, ChunkDataAndLight, ChunkPacket, LightPacket, UpdateLightPacket,
+    BlockEntity, ChunkDataAndLight, ChunkPacket, LightPacket, SetCenterChunk, UnloadChunk,
+    UpdateLightPacket,
 };
 use crate::Protocol;
 use crate::Protocol::Java;
@@ -50,11 +52,11 @@ impl Packet for ClientBoundChunkDataImpl {
         let chunk_z = PacketDataType::read(r)?;
         let chunk_data = {
             let height_map = PacketDataType::read(r)?;
-            let data_len = VarInt::read(r)?.0 as usize;
+            let data_len = limits::read_array_length(r)?;
             let mut data = vec![0; data_len];
             r.read_exact(&mut data)?;
-            let block_entities_len = VarInt::read(r)?.0 as usize;
-            let mut block_entities = Vec::with_capacity(block_entities_len);
+            let block_entities_len = limits::read_array_length(r)?;
+            let mut block_entities = limits::with_capacity(block_entities_len);
             for _ in 0..block_entities_len {
                 let xz: i8 = PacketDataType::read(r)?;
                 let y = PacketDataType::read(r)?;
@@ -139,3 +141,57 @@ fn read_light_content<R: Read>(reader: &mut R) -> Result<LightPacket, PacketRead
         block_light,
     })
 }
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetCenterChunkImpl;
+
+impl Packet for ClientBoundSetCenterChunkImpl {
+    define_packet!(
+        SetCenterChunk,
+        0x4A,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.chunk_x).write(w)?;
+        VarInt(content.chunk_z).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetCenterChunk {
+            chunk_x: VarInt::read(r)?.0,
+            chunk_z: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUnloadChunkImpl;
+
+impl Packet for ClientBoundUnloadChunkImpl {
+    define_packet!(
+        UnloadChunk,
+        0x1B,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.chunk_x.write(w)?;
+        content.chunk_z.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UnloadChunk {
+            chunk_x: i32::read(r)?,
+            chunk_z: i32::read(r)?,
+        })
+    }
+}
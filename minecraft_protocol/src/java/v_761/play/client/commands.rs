@@ -0,0 +1,109 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::limits;
+use crate::packets::play::client::commands::{
+    CommandNode, CommandSuggestion, CommandSuggestionsResponse, DeclareCommands,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// The parser ids are the 1.19.3 ids. So it is only registered for 1.19.3
+#[derive(PacketImplDebug)]
+pub struct ClientBoundDeclareCommandsImpl;
+
+impl Packet for ClientBoundDeclareCommandsImpl {
+    define_packet!(
+        DeclareCommands,
+        0x0E,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.nodes.len() as i32).write(w)?;
+        for node in content.nodes {
+            node.write(w)?;
+        }
+        VarInt(content.root_index).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let length = limits::read_array_length(r)?;
+        let mut nodes = limits::with_capacity(length);
+        for _ in 0..length {
+            nodes.push(CommandNode::read(r)?);
+        }
+        let root_index = VarInt::read(r)?.0;
+        if root_index < 0 || root_index as usize >= length {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Invalid root node {}",
+                root_index
+            )));
+        }
+        Ok(DeclareCommands { nodes, root_index })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundCommandSuggestionsImpl;
+
+impl Packet for ClientBoundCommandSuggestionsImpl {
+    define_packet!(
+        CommandSuggestionsResponse,
+        0x0D,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.transaction_id).write(w)?;
+        VarInt(content.start).write(w)?;
+        VarInt(content.length).write(w)?;
+        VarInt(content.matches.len() as i32).write(w)?;
+        for suggestion in content.matches {
+            suggestion.text.write(w)?;
+            if let Some(tooltip) = suggestion.tooltip {
+                true.write(w)?;
+                tooltip.write(w)?;
+            } else {
+                false.write(w)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let transaction_id = VarInt::read(r)?.0;
+        let start = VarInt::read(r)?.0;
+        let length = VarInt::read(r)?.0;
+        let count = limits::read_array_length(r)?;
+        let mut matches = limits::with_capacity(count);
+        for _ in 0..count {
+            matches.push(CommandSuggestion {
+                text: String::read(r)?,
+                tooltip: if bool::read(r)? {
+                    Some(Component::read(r)?)
+                } else {
+                    None
+                },
+            });
+        }
+        Ok(CommandSuggestionsResponse {
+            transaction_id,
+            start,
+            length,
+            matches,
+        })
+    }
+}
@@ -0,0 +1,191 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::chat::Component;
+use crate::data::slot::Slot;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::limits;
+use crate::packets::play::client::container::{
+    CloseContainer, OpenScreen, SetContainerContent, SetContainerProperty, SetContainerSlot,
+};
+use crate::packets::play::client::SelectedSlotPacket;
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundCloseContainerImpl;
+
+impl Packet for ClientBoundCloseContainerImpl {
+    define_packet!(
+        CloseContainer,
+        0x0F,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.window_id.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(CloseContainer {
+            window_id: u8::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetContainerContentImpl;
+
+impl Packet for ClientBoundSetContainerContentImpl {
+    define_packet!(
+        SetContainerContent,
+        0x10,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.window_id.write(w)?;
+        VarInt(content.state_id).write(w)?;
+        VarInt(content.slots.len() as i32).write(w)?;
+        for slot in content.slots {
+            slot.write(w)?;
+        }
+        content.carried_item.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let window_id = u8::read(r)?;
+        let state_id = VarInt::read(r)?.0;
+        let length = limits::read_array_length(r)?;
+        let mut slots = limits::with_capacity(length);
+        for _ in 0..length {
+            slots.push(Slot::read(r)?);
+        }
+        Ok(SetContainerContent {
+            window_id,
+            state_id,
+            slots,
+            carried_item: Slot::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetContainerPropertyImpl;
+
+impl Packet for ClientBoundSetContainerPropertyImpl {
+    define_packet!(
+        SetContainerProperty,
+        0x11,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.window_id.write(w)?;
+        content.property.write(w)?;
+        content.value.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetContainerProperty {
+            window_id: u8::read(r)?,
+            property: i16::read(r)?,
+            value: i16::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetContainerSlotImpl;
+
+impl Packet for ClientBoundSetContainerSlotImpl {
+    define_packet!(
+        SetContainerSlot,
+        0x12,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.window_id.write(w)?;
+        VarInt(content.state_id).write(w)?;
+        content.slot.write(w)?;
+        content.item.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetContainerSlot {
+            window_id: i8::read(r)?,
+            state_id: VarInt::read(r)?.0,
+            slot: i16::read(r)?,
+            item: Slot::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundOpenScreenImpl;
+
+impl Packet for ClientBoundOpenScreenImpl {
+    define_packet!(OpenScreen, 0x2C, Bound::ClientBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.window_id).write(w)?;
+        VarInt(content.window_type).write(w)?;
+        content.title.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(OpenScreen {
+            window_id: VarInt::read(r)?.0,
+            window_type: VarInt::read(r)?.0,
+            title: Component::read(r)?,
+        })
+    }
+}
+
+/// Set Held Item. The slot is sent as a byte
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSelectedSlotImpl;
+
+impl Packet for ClientBoundSelectedSlotImpl {
+    define_packet!(
+        SelectedSlotPacket,
+        0x49,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        let slot = i8::try_from(content.0)
+            .map_err(|_| PacketWriteError::Other(format!("Invalid hotbar slot {}", content.0)))?;
+        slot.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SelectedSlotPacket(i8::read(r)? as i32))
+    }
+}
@@ -0,0 +1,462 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::slot::Slot;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::limits;
+use crate::packets::play::client::entity::{
+    Angle, EquipmentSlot, RemoveEntities, SetEntityMetadata, SetEntityVelocity, SetEquipment,
+    SetHeadRotation, SpawnEntity, SpawnExperienceOrb, SpawnPlayer, TeleportEntity,
+    UpdateEntityPosition, UpdateEntityPositionAndRotation, UpdateEntityRotation, Velocity,
+};
+use crate::packets::play::client::metadata::{EntityMetadataValue, MetadataEntry, METADATA_END};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// More entries than any entity has
+pub const MAX_METADATA_ENTRIES: usize = 255;
+/// The number of equipment slots
+pub const MAX_EQUIPMENT: usize = 6;
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSpawnEntityImpl;
+
+impl Packet for ClientBoundSpawnEntityImpl {
+    define_packet!(
+        SpawnEntity,
+        0x00,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.uuid.write(w)?;
+        VarInt(content.entity_type).write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.pitch.write(w)?;
+        content.yaw.write(w)?;
+        content.head_yaw.write(w)?;
+        VarInt(content.data).write(w)?;
+        content.velocity.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SpawnEntity {
+            entity_id: VarInt::read(r)?.0,
+            uuid: PacketDataType::read(r)?,
+            entity_type: VarInt::read(r)?.0,
+            x: f64::read(r)?,
+            y: f64::read(r)?,
+            z: f64::read(r)?,
+            pitch: Angle::read(r)?,
+            yaw: Angle::read(r)?,
+            head_yaw: Angle::read(r)?,
+            data: VarInt::read(r)?.0,
+            velocity: Velocity::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSpawnExperienceOrbImpl;
+
+impl Packet for ClientBoundSpawnExperienceOrbImpl {
+    define_packet!(
+        SpawnExperienceOrb,
+        0x01,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.count.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SpawnExperienceOrb {
+            entity_id: VarInt::read(r)?.0,
+            x: f64::read(r)?,
+            y: f64::read(r)?,
+            z: f64::read(r)?,
+            count: i16::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSpawnPlayerImpl;
+
+impl Packet for ClientBoundSpawnPlayerImpl {
+    define_packet!(
+        SpawnPlayer,
+        0x02,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.uuid.write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.yaw.write(w)?;
+        content.pitch.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SpawnPlayer {
+            entity_id: VarInt::read(r)?.0,
+            uuid: PacketDataType::read(r)?,
+            x: f64::read(r)?,
+            y: f64::read(r)?,
+            z: f64::read(r)?,
+            yaw: Angle::read(r)?,
+            pitch: Angle::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateEntityPositionImpl;
+
+impl Packet for ClientBoundUpdateEntityPositionImpl {
+    define_packet!(
+        UpdateEntityPosition,
+        0x27,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.delta_x.write(w)?;
+        content.delta_y.write(w)?;
+        content.delta_z.write(w)?;
+        content.on_ground.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UpdateEntityPosition {
+            entity_id: VarInt::read(r)?.0,
+            delta_x: i16::read(r)?,
+            delta_y: i16::read(r)?,
+            delta_z: i16::read(r)?,
+            on_ground: bool::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateEntityPositionAndRotationImpl;
+
+impl Packet for ClientBoundUpdateEntityPositionAndRotationImpl {
+    define_packet!(
+        UpdateEntityPositionAndRotation,
+        0x28,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.delta_x.write(w)?;
+        content.delta_y.write(w)?;
+        content.delta_z.write(w)?;
+        content.yaw.write(w)?;
+        content.pitch.write(w)?;
+        content.on_ground.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UpdateEntityPositionAndRotation {
+            entity_id: VarInt::read(r)?.0,
+            delta_x: i16::read(r)?,
+            delta_y: i16::read(r)?,
+            delta_z: i16::read(r)?,
+            yaw: Angle::read(r)?,
+            pitch: Angle::read(r)?,
+            on_ground: bool::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateEntityRotationImpl;
+
+impl Packet for ClientBoundUpdateEntityRotationImpl {
+    define_packet!(
+        UpdateEntityRotation,
+        0x29,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.yaw.write(w)?;
+        content.pitch.write(w)?;
+        content.on_ground.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UpdateEntityRotation {
+            entity_id: VarInt::read(r)?.0,
+            yaw: Angle::read(r)?,
+            pitch: Angle::read(r)?,
+            on_ground: bool::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundRemoveEntitiesImpl;
+
+impl Packet for ClientBoundRemoveEntitiesImpl {
+    define_packet!(
+        RemoveEntities,
+        0x3A,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_ids.len() as i32).write(w)?;
+        for entity_id in content.entity_ids {
+            VarInt(entity_id).write(w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let length = limits::read_array_length(r)?;
+        let mut entity_ids = limits::with_capacity(length);
+        for _ in 0..length {
+            entity_ids.push(VarInt::read(r)?.0);
+        }
+        Ok(RemoveEntities { entity_ids })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetHeadRotationImpl;
+
+impl Packet for ClientBoundSetHeadRotationImpl {
+    define_packet!(
+        SetHeadRotation,
+        0x3E,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.head_yaw.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetHeadRotation {
+            entity_id: VarInt::read(r)?.0,
+            head_yaw: Angle::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetEntityMetadataImpl;
+
+impl Packet for ClientBoundSetEntityMetadataImpl {
+    define_packet!(
+        SetEntityMetadata,
+        0x4E,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        for entry in content.metadata {
+            if entry.index == METADATA_END {
+                return Err(PacketWriteError::Other(format!(
+                    "Metadata index {} is reserved",
+                    METADATA_END
+                )));
+            }
+            entry.index.write(w)?;
+            entry.value.write(w)?;
+        }
+        METADATA_END.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let entity_id = VarInt::read(r)?.0;
+        let mut metadata = Vec::new();
+        loop {
+            let index = u8::read(r)?;
+            if index == METADATA_END {
+                break;
+            }
+            if metadata.len() >= MAX_METADATA_ENTRIES {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Too many metadata entries"
+                )));
+            }
+            metadata.push(MetadataEntry {
+                index,
+                value: EntityMetadataValue::read(r)?,
+            });
+        }
+        Ok(SetEntityMetadata {
+            entity_id,
+            metadata,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetEntityVelocityImpl;
+
+impl Packet for ClientBoundSetEntityVelocityImpl {
+    define_packet!(
+        SetEntityVelocity,
+        0x50,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.velocity.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetEntityVelocity {
+            entity_id: VarInt::read(r)?.0,
+            velocity: Velocity::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetEquipmentImpl;
+
+impl Packet for ClientBoundSetEquipmentImpl {
+    define_packet!(
+        SetEquipment,
+        0x51,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        let length = content.equipment.len();
+        for (index, (slot, item)) in content.equipment.into_iter().enumerate() {
+            let more = if index + 1 < length { 0x80 } else { 0 };
+            (slot as u8 | more).write(w)?;
+            item.write(w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let entity_id = VarInt::read(r)?.0;
+        let mut equipment = Vec::new();
+        loop {
+            let slot = u8::read(r)?;
+            let equipment_slot = EquipmentSlot::try_from(slot & 0x7F)
+                .map_err(|e| PacketReadError::InvalidData(e.into()))?;
+            equipment.push((equipment_slot, Slot::read(r)?));
+            if slot & 0x80 == 0 {
+                break;
+            }
+            if equipment.len() >= MAX_EQUIPMENT {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Too many equipment entries"
+                )));
+            }
+        }
+        Ok(SetEquipment {
+            entity_id,
+            equipment,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundTeleportEntityImpl;
+
+impl Packet for ClientBoundTeleportEntityImpl {
+    define_packet!(
+        TeleportEntity,
+        0x64,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.yaw.write(w)?;
+        content.pitch.write(w)?;
+        content.on_ground.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(TeleportEntity {
+            entity_id: VarInt::read(r)?.0,
+            x: f64::read(r)?,
+            y: f64::read(r)?,
+            z: f64::read(r)?,
+            yaw: Angle::read(r)?,
+            pitch: Angle::read(r)?,
+            on_ground: bool::read(r)?,
+        })
+    }
+}
@@ -0,0 +1,180 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::{Packet, PacketImplDebug};
+use uuid::Uuid;
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::packets::play::client::hud::{
+    BossBar, BossBarAction, BossBarColor, BossBarDivision, BossBarFlags, ClearTitles,
+    SetActionBarText, SetSubtitleText, SetTabListHeaderAndFooter, SetTitleAnimationTimes,
+    SetTitleText,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x5B, bound = ClientBound, stage = Play, protocol = 761, content = SetTitleText)]
+pub struct ClientBoundSetTitleTextImpl(Component);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x59, bound = ClientBound, stage = Play, protocol = 761, content = SetSubtitleText)]
+pub struct ClientBoundSetSubtitleTextImpl(Component);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x42, bound = ClientBound, stage = Play, protocol = 761, content = SetActionBarText)]
+pub struct ClientBoundSetActionBarTextImpl(Component);
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetTitleAnimationTimesImpl;
+
+impl Packet for ClientBoundSetTitleAnimationTimesImpl {
+    define_packet!(
+        SetTitleAnimationTimes,
+        0x5C,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.fade_in.write(w)?;
+        content.stay.write(w)?;
+        content.fade_out.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetTitleAnimationTimes {
+            fade_in: i32::read(r)?,
+            stay: i32::read(r)?,
+            fade_out: i32::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundClearTitlesImpl;
+
+impl Packet for ClientBoundClearTitlesImpl {
+    define_packet!(
+        ClearTitles,
+        0x0C,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.reset.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(ClearTitles {
+            reset: bool::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetTabListHeaderAndFooterImpl;
+
+impl Packet for ClientBoundSetTabListHeaderAndFooterImpl {
+    define_packet!(
+        SetTabListHeaderAndFooter,
+        0x61,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.header.write(w)?;
+        content.footer.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetTabListHeaderAndFooter {
+            header: Component::read(r)?,
+            footer: Component::read(r)?,
+        })
+    }
+}
+
+fn read_flags<R: Read>(r: &mut R) -> Result<BossBarFlags, PacketReadError> {
+    let flags = u8::read(r)?;
+    BossBarFlags::from_bits(flags).ok_or(PacketReadError::InvalidData(anyhow::anyhow!(
+        "Invalid boss bar flags {}",
+        flags
+    )))
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundBossBarImpl;
+
+impl Packet for ClientBoundBossBarImpl {
+    define_packet!(BossBar, 0x0A, Bound::ClientBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.uuid.write(w)?;
+        VarInt(content.action.action_id()).write(w)?;
+        match content.action {
+            BossBarAction::Add {
+                title,
+                health,
+                color,
+                division,
+                flags,
+            } => {
+                title.write(w)?;
+                health.write(w)?;
+                color.write(w)?;
+                division.write(w)?;
+                flags.bits().write(w)?;
+            }
+            BossBarAction::Remove => {}
+            BossBarAction::UpdateHealth(health) => health.write(w)?,
+            BossBarAction::UpdateTitle(title) => title.write(w)?,
+            BossBarAction::UpdateStyle { color, division } => {
+                color.write(w)?;
+                division.write(w)?;
+            }
+            BossBarAction::UpdateFlags(flags) => flags.bits().write(w)?,
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let uuid = Uuid::read(r)?;
+        let action = match VarInt::read(r)?.0 {
+            0 => BossBarAction::Add {
+                title: Component::read(r)?,
+                health: f32::read(r)?,
+                color: BossBarColor::read(r)?,
+                division: BossBarDivision::read(r)?,
+                flags: read_flags(r)?,
+            },
+            1 => BossBarAction::Remove,
+            2 => BossBarAction::UpdateHealth(f32::read(r)?),
+            3 => BossBarAction::UpdateTitle(Component::read(r)?),
+            4 => BossBarAction::UpdateStyle {
+                color: BossBarColor::read(r)?,
+                division: BossBarDivision::read(r)?,
+            },
+            5 => BossBarAction::UpdateFlags(read_flags(r)?),
+            action => {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Invalid boss bar action {}",
+                    action
+                )))
+            }
+        };
+        Ok(BossBar { uuid, action })
+    }
+}
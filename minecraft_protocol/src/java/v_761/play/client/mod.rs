@@ -1,27 +1,86 @@
 use std::borrow::Cow;
 use std::io::{Read, Write};
 
-use minecraft_protocol_macros::{define_io, PacketImplDebug};
+use minecraft_protocol_macros::{define_io, Packet, PacketImplDebug};
 
-use crate::data::{var_int, PacketDataType};
+use crate::chat::Component;
+use crate::data::PacketDataType;
 use crate::java::define_packet;
-use crate::java::v_761::new_type_struct_define_packet;
+use crate::java::v_761::play::client::block::{
+    ClientBoundAcknowledgeBlockChangeImpl, ClientBoundBlockActionImpl,
+    ClientBoundBlockEntityDataImpl, ClientBoundBlockUpdateImpl, ClientBoundUpdateSectionBlocksImpl,
+};
+use crate::java::v_761::play::client::chat::{
+    ClientBoundDisguisedChatImpl, ClientBoundPlayerChatImpl, ClientBoundSystemChatImpl,
+};
 use crate::java::v_761::play::client::chunk::{
-    ClientBoundChunkDataImpl, ClientBoundLightUpdateImpl,
+    ClientBoundChunkDataImpl, ClientBoundLightUpdateImpl, ClientBoundSetCenterChunkImpl,
+    ClientBoundUnloadChunkImpl,
+};
+use crate::java::v_761::play::client::commands::{
+    ClientBoundCommandSuggestionsImpl, ClientBoundDeclareCommandsImpl,
+};
+use crate::java::v_761::play::client::container::{
+    ClientBoundCloseContainerImpl, ClientBoundOpenScreenImpl, ClientBoundSelectedSlotImpl,
+    ClientBoundSetContainerContentImpl, ClientBoundSetContainerPropertyImpl,
+    ClientBoundSetContainerSlotImpl,
+};
+use crate::java::v_761::play::client::entity::{
+    ClientBoundRemoveEntitiesImpl, ClientBoundSetEntityMetadataImpl,
+    ClientBoundSetEntityVelocityImpl, ClientBoundSetEquipmentImpl, ClientBoundSetHeadRotationImpl,
+    ClientBoundSpawnEntityImpl, ClientBoundSpawnExperienceOrbImpl, ClientBoundSpawnPlayerImpl,
+    ClientBoundTeleportEntityImpl, ClientBoundUpdateEntityPositionAndRotationImpl,
+    ClientBoundUpdateEntityPositionImpl, ClientBoundUpdateEntityRotationImpl,
+};
+use crate::java::v_761::play::client::hud::{
+    ClientBoundBossBarImpl, ClientBoundClearTitlesImpl, ClientBoundSetActionBarTextImpl,
+    ClientBoundSetSubtitleTextImpl, ClientBoundSetTabListHeaderAndFooterImpl,
+    ClientBoundSetTitleAnimationTimesImpl, ClientBoundSetTitleTextImpl,
 };
 pub use crate::java::v_761::play::client::login::ClientBoundLoginPacketImpl;
-use crate::java::v_761::play::client::player_info::SyncPlayerPositionImpl;
+use crate::java::v_761::play::client::particle::ClientBoundParticleImpl;
+use crate::java::v_761::play::client::player_info::{
+    ClientBoundPlayerInfoRemoveImpl, ClientBoundPlayerInfoUpdateImpl, SyncPlayerPositionImpl,
+};
+use crate::java::v_761::play::client::respawn::{
+    ClientBoundRespawnImpl, ClientBoundSetDefaultSpawnPositionImpl,
+};
+use crate::java::v_761::play::client::scoreboard::{
+    ClientBoundDisplayObjectiveImpl, ClientBoundUpdateObjectivesImpl, ClientBoundUpdateScoreImpl,
+    ClientBoundUpdateTeamsImpl,
+};
+use crate::java::v_761::play::client::sound::{
+    ClientBoundEntitySoundEffectImpl, ClientBoundSoundEffectImpl, ClientBoundStopSoundImpl,
+};
+use crate::java::v_761::play::client::world::{
+    ClientBoundGameEventImpl, ClientBoundInitializeWorldBorderImpl, ClientBoundSetBorderCenterImpl,
+    ClientBoundSetBorderLerpSizeImpl, ClientBoundSetBorderSizeImpl,
+    ClientBoundSetBorderWarningDelayImpl, ClientBoundSetBorderWarningDistanceImpl,
+    ClientBoundUpdateTimeImpl,
+};
 use crate::packets::play::client::{
     AbilitiesPacket, AbilityFlags, ChangeDifficultyPacket, ClientBoundPlay, DisconnectPacket,
+    ResourcePack, ServerData,
 };
 use crate::packets::play::{KeepAlive, PlayPing, PlayPluginMessage};
 use crate::PacketIO;
 use crate::Protocol::Java;
 use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
 
+pub mod block;
+pub mod chat;
 pub mod chunk;
+pub mod commands;
+pub mod container;
+pub mod entity;
+pub mod hud;
 pub mod login;
-mod player_info;
+pub mod particle;
+pub mod player_info;
+pub mod respawn;
+pub mod scoreboard;
+pub mod sound;
+pub mod world;
 
 define_io!(ClientBoundPlay {
     0x24 => {
@@ -63,51 +122,254 @@ define_io!(ClientBoundPlay {
     0x23 => {
         type_name: ClientBoundLightUpdateImpl
         g_var:  UpdateLight
+    },
+    0x41 => {
+        type_name: ClientBoundServerDataImpl
+        g_var:  ServerData
+    },
+    0x60 => {
+        type_name: ClientBoundSystemChatImpl
+        g_var:  SystemChat
+    },
+    0x31 => {
+        type_name: ClientBoundPlayerChatImpl
+        g_var:  PlayerChat
+    },
+    0x18 => {
+        type_name: ClientBoundDisguisedChatImpl
+        g_var:  DisguisedChat
+    },
+    0x09 => {
+        type_name: ClientBoundBlockUpdateImpl
+        g_var:  BlockUpdate
+    },
+    0x3F => {
+        type_name: ClientBoundUpdateSectionBlocksImpl
+        g_var:  UpdateSectionBlocks
+    },
+    0x07 => {
+        type_name: ClientBoundBlockEntityDataImpl
+        g_var:  BlockEntityData
+    },
+    0x08 => {
+        type_name: ClientBoundBlockActionImpl
+        g_var:  BlockAction
+    },
+    0x05 => {
+        type_name: ClientBoundAcknowledgeBlockChangeImpl
+        g_var:  AcknowledgeBlockChange
+    },
+    0x00 => {
+        type_name: ClientBoundSpawnEntityImpl
+        g_var:  SpawnEntity
+    },
+    0x01 => {
+        type_name: ClientBoundSpawnExperienceOrbImpl
+        g_var:  SpawnExperienceOrb
+    },
+    0x02 => {
+        type_name: ClientBoundSpawnPlayerImpl
+        g_var:  SpawnPlayer
+    },
+    0x27 => {
+        type_name: ClientBoundUpdateEntityPositionImpl
+        g_var:  UpdateEntityPosition
+    },
+    0x28 => {
+        type_name: ClientBoundUpdateEntityPositionAndRotationImpl
+        g_var:  UpdateEntityPositionAndRotation
+    },
+    0x29 => {
+        type_name: ClientBoundUpdateEntityRotationImpl
+        g_var:  UpdateEntityRotation
+    },
+    0x64 => {
+        type_name: ClientBoundTeleportEntityImpl
+        g_var:  TeleportEntity
+    },
+    0x3E => {
+        type_name: ClientBoundSetHeadRotationImpl
+        g_var:  SetHeadRotation
+    },
+    0x50 => {
+        type_name: ClientBoundSetEntityVelocityImpl
+        g_var:  SetEntityVelocity
+    },
+    0x3A => {
+        type_name: ClientBoundRemoveEntitiesImpl
+        g_var:  RemoveEntities
+    },
+    0x4E => {
+        type_name: ClientBoundSetEntityMetadataImpl
+        g_var:  SetEntityMetadata
+    },
+    0x51 => {
+        type_name: ClientBoundSetEquipmentImpl
+        g_var:  SetEquipment
+    },
+    0x2C => {
+        type_name: ClientBoundOpenScreenImpl
+        g_var:  OpenScreen
+    },
+    0x10 => {
+        type_name: ClientBoundSetContainerContentImpl
+        g_var:  SetContainerContent
+    },
+    0x12 => {
+        type_name: ClientBoundSetContainerSlotImpl
+        g_var:  SetContainerSlot
+    },
+    0x11 => {
+        type_name: ClientBoundSetContainerPropertyImpl
+        g_var:  SetContainerProperty
+    },
+    0x0F => {
+        type_name: ClientBoundCloseContainerImpl
+        g_var:  CloseContainer
+    },
+    0x49 => {
+        type_name: ClientBoundSelectedSlotImpl
+        g_var:  SelectedSlot
+    },
+    0x0E => {
+        type_name: ClientBoundDeclareCommandsImpl
+        g_var:  DeclareCommands
+    },
+    0x0D => {
+        type_name: ClientBoundCommandSuggestionsImpl
+        g_var:  CommandSuggestions
+    },
+    0x36 => {
+        type_name: ClientBoundPlayerInfoUpdateImpl
+        g_var:  PlayerInfoUpdate
+    },
+    0x35 => {
+        type_name: ClientBoundPlayerInfoRemoveImpl
+        g_var:  PlayerInfoRemove
+    },
+    0x3C => {
+        type_name: ClientBoundResourcePackImpl
+        g_var:  ResourcePack
+    },
+    0x0A => {
+        type_name: ClientBoundBossBarImpl
+        g_var:  BossBar
+    },
+    0x0C => {
+        type_name: ClientBoundClearTitlesImpl
+        g_var:  ClearTitles
+    },
+    0x42 => {
+        type_name: ClientBoundSetActionBarTextImpl
+        g_var:  SetActionBarText
+    },
+    0x59 => {
+        type_name: ClientBoundSetSubtitleTextImpl
+        g_var:  SetSubtitleText
+    },
+    0x5B => {
+        type_name: ClientBoundSetTitleTextImpl
+        g_var:  SetTitleText
+    },
+    0x5C => {
+        type_name: ClientBoundSetTitleAnimationTimesImpl
+        g_var:  SetTitleAnimationTimes
+    },
+    0x61 => {
+        type_name: ClientBoundSetTabListHeaderAndFooterImpl
+        g_var:  SetTabListHeaderAndFooter
+    },
+    0x54 => {
+        type_name: ClientBoundUpdateObjectivesImpl
+        g_var:  UpdateObjectives
+    },
+    0x4D => {
+        type_name: ClientBoundDisplayObjectiveImpl
+        g_var:  DisplayObjective
+    },
+    0x57 => {
+        type_name: ClientBoundUpdateScoreImpl
+        g_var:  UpdateScore
+    },
+    0x56 => {
+        type_name: ClientBoundUpdateTeamsImpl
+        g_var:  UpdateTeams
+    },
+    0x1E => {
+        type_name: ClientBoundInitializeWorldBorderImpl
+        g_var:  InitializeWorldBorder
+    },
+    0x43 => {
+        type_name: ClientBoundSetBorderCenterImpl
+        g_var:  SetBorderCenter
+    },
+    0x44 => {
+        type_name: ClientBoundSetBorderLerpSizeImpl
+        g_var:  SetBorderLerpSize
+    },
+    0x45 => {
+        type_name: ClientBoundSetBorderSizeImpl
+        g_var:  SetBorderSize
+    },
+    0x46 => {
+        type_name: ClientBoundSetBorderWarningDelayImpl
+        g_var:  SetBorderWarningDelay
+    },
+    0x47 => {
+        type_name: ClientBoundSetBorderWarningDistanceImpl
+        g_var:  SetBorderWarningDistance
+    },
+    0x5A => {
+        type_name: ClientBoundUpdateTimeImpl
+        g_var:  UpdateTime
+    },
+    0x1C => {
+        type_name: ClientBoundGameEventImpl
+        g_var:  GameEvent
+    },
+    0x5E => {
+        type_name: ClientBoundSoundEffectImpl
+        g_var:  SoundEffect
+    },
+    0x5D => {
+        type_name: ClientBoundEntitySoundEffectImpl
+        g_var:  EntitySoundEffect
+    },
+    0x5F => {
+        type_name: ClientBoundStopSoundImpl
+        g_var:  StopSound
+    },
+    0x22 => {
+        type_name: ClientBoundParticleImpl
+        g_var:  Particle
+    },
+    0x3D => {
+        type_name: ClientBoundRespawnImpl
+        g_var:  Respawn
+    },
+    0x4C => {
+        type_name: ClientBoundSetDefaultSpawnPositionImpl
+        g_var:  SetDefaultSpawnPosition
+    },
+    0x4A => {
+        type_name: ClientBoundSetCenterChunkImpl
+        g_var:  SetCenterChunk
+    },
+    0x1B => {
+        type_name: ClientBoundUnloadChunkImpl
+        g_var:  UnloadChunk
     }
 });
-new_type_struct_define_packet!(
-    ClientBoundPingImpl,
-    PlayPing,
-    0x2E,
-    Bound::ClientBound,
-    Stage::Play,
-    Java(761),
-    i32
-);
-new_type_struct_define_packet!(
-    ClientBoundKeepAliveImpl,
-    KeepAlive,
-    0x1F,
-    Bound::ClientBound,
-    Stage::Play,
-    Java(761),
-    i64
-);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x2E, bound = ClientBound, stage = Play, protocol = 761, content = PlayPing)]
+pub struct ClientBoundPingImpl(i32);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x1F, bound = ClientBound, stage = Play, protocol = 761, content = KeepAlive)]
+pub struct ClientBoundKeepAliveImpl(i64);
 
-#[derive(PacketImplDebug)]
-pub struct ClientBoundDisconnectPacketImpl;
-impl Packet for ClientBoundDisconnectPacketImpl {
-    define_packet!(
-        DisconnectPacket,
-        0x17,
-        Bound::ClientBound,
-        Stage::Play,
-        Java(761)
-    );
-
-    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
-        var_int::inline::write(content.0.len() as i32, w)?;
-        w.write_all(content.0.as_bytes())?;
-        Ok(())
-    }
-
-    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
-        let len = var_int::inline::read(r)?;
-        let mut buf = Vec::with_capacity(len.0 as usize);
-        r.read_exact(&mut buf)?;
-        Ok(DisconnectPacket(String::from_utf8(buf)?))
-    }
-}
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x17, bound = ClientBound, stage = Play, protocol = 761, content = DisconnectPacket)]
+pub struct ClientBoundDisconnectPacketImpl(Component);
 
 #[derive(PacketImplDebug)]
 pub struct ClientBoundPluginMessageImpl;
@@ -144,7 +406,7 @@ impl Packet for ClientBoundPluginMessageImpl {
         length: usize,
     ) -> Result<Self::Content, PacketReadError> {
         let id = String::read(r)?;
-        let mut data = Vec::<u8>::with_capacity(length - id.len());
+        let mut data = Vec::<u8>::with_capacity(length.saturating_sub(id.len()));
         r.read_to_end(&mut data)?;
         Ok(PlayPluginMessage {
             id: Cow::Owned(id),
@@ -217,3 +479,83 @@ impl Packet for ClientBoundSetAbilities {
         })
     }
 }
+
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x41, bound = ClientBound, stage = Play, protocol = 761, content = ServerData)]
+pub struct ClientBoundServerDataImpl {
+    #[optional]
+    motd: Option<Component>,
+    #[optional]
+    icon: Option<String>,
+    /// Removed in 1.19.3
+    #[skip]
+    previews_chat: bool,
+    enforced_secure_chat: bool,
+}
+
+/// The longest resource pack url the client accepts
+pub const MAX_RESOURCE_PACK_URL_LENGTH: usize = 32767;
+/// A SHA-1 in hex
+pub const MAX_RESOURCE_PACK_HASH_LENGTH: usize = 40;
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundResourcePackImpl;
+
+impl Packet for ClientBoundResourcePackImpl {
+    define_packet!(
+        ResourcePack,
+        0x3C,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        if content.hash.len() > MAX_RESOURCE_PACK_HASH_LENGTH {
+            return Err(PacketWriteError::Other(format!(
+                "Resource pack hash is longer than {} characters",
+                MAX_RESOURCE_PACK_HASH_LENGTH
+            )));
+        }
+        Self::write_packet_id(w)?;
+        content.url.write(w)?;
+        content.hash.write(w)?;
+        content.forced.write(w)?;
+        if let Some(prompt) = content.prompt {
+            true.write(w)?;
+            prompt.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let url = String::read(r)?;
+        if url.len() > MAX_RESOURCE_PACK_URL_LENGTH {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Resource pack url is longer than {} bytes",
+                MAX_RESOURCE_PACK_URL_LENGTH
+            )));
+        }
+        let hash = String::read(r)?;
+        if hash.len() > MAX_RESOURCE_PACK_HASH_LENGTH {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Resource pack hash is longer than {} characters",
+                MAX_RESOURCE_PACK_HASH_LENGTH
+            )));
+        }
+        let forced = bool::read(r)?;
+        let prompt = if bool::read(r)? {
+            Some(Component::read(r)?)
+        } else {
+            None
+        };
+        Ok(ResourcePack {
+            url,
+            hash,
+            forced,
+            prompt,
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::packets::play::client::particle::{Particle, ParticleData, ParticlePacket};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// The particle data comes after the position. Not after the particle type
+#[derive(PacketImplDebug)]
+pub struct ClientBoundParticleImpl;
+
+impl Packet for ClientBoundParticleImpl {
+    define_packet!(
+        ParticlePacket,
+        0x22,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.particle.particle_type).write(w)?;
+        content.long_distance.write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.offset_x.write(w)?;
+        content.offset_y.write(w)?;
+        content.offset_z.write(w)?;
+        content.max_speed.write(w)?;
+        content.count.write(w)?;
+        content.particle.data.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let particle_type = VarInt::read(r)?.0;
+        let long_distance = bool::read(r)?;
+        let x = f64::read(r)?;
+        let y = f64::read(r)?;
+        let z = f64::read(r)?;
+        let offset_x = f32::read(r)?;
+        let offset_y = f32::read(r)?;
+        let offset_z = f32::read(r)?;
+        let max_speed = f32::read(r)?;
+        let count = i32::read(r)?;
+        Ok(ParticlePacket {
+            particle: Particle {
+                particle_type,
+                data: ParticleData::read(particle_type, r)?,
+            },
+            long_distance,
+            x,
+            y,
+            z,
+            offset_x,
+            offset_y,
+            offset_z,
+            max_speed,
+            count,
+        })
+    }
+}
@@ -2,9 +2,20 @@ use std::io::{Read, Write};
 
 use minecraft_protocol_macros::PacketImplDebug;
 
+use uuid::Uuid;
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
 use crate::data::PacketDataType;
 use crate::java::define_packet;
-use crate::packets::play::client::player_info::{SyncPlayerPosition, SyncPlayerPositionFlags};
+use crate::limits;
+use crate::packets::login::Property;
+use crate::packets::play::client::login::GameMode;
+use crate::packets::play::client::player_info::{
+    PlayerInfoActions, PlayerInfoEntry, PlayerInfoRemove, PlayerInfoUpdate, SyncPlayerPosition,
+    SyncPlayerPositionFlags,
+};
+use crate::packets::play::server::chat::{PlayerSession, ProfilePublicKey};
 use crate::Protocol;
 use crate::Protocol::Java;
 use crate::{Bound, Packet, PacketReadError, PacketWriteError, Stage};
@@ -41,9 +52,176 @@ impl Packet for SyncPlayerPositionImpl {
             z: PacketDataType::read(r)?,
             yaw: PacketDataType::read(r)?,
             pitch: PacketDataType::read(r)?,
-            flags: SyncPlayerPositionFlags::from_bits(PacketDataType::read(r)?).unwrap(),
+            flags: SyncPlayerPositionFlags::from_bits(PacketDataType::read(r)?).ok_or(
+                PacketReadError::InvalidData(anyhow::anyhow!("Invalid position flags")),
+            )?,
             teleport_id: PacketDataType::read(r)?,
             dismount_vehicle: PacketDataType::read(r)?,
         })
     }
 }
+
+fn write_entry<W: Write>(
+    actions: PlayerInfoActions,
+    entry: PlayerInfoEntry,
+    w: &mut W,
+) -> Result<(), PacketWriteError> {
+    entry.uuid.write(w)?;
+    if actions.contains(PlayerInfoActions::ADD_PLAYER) {
+        entry.name.write(w)?;
+        VarInt(entry.properties.len() as i32).write(w)?;
+        for property in entry.properties {
+            property.name.write(w)?;
+            property.value.write(w)?;
+            if let Some(signature) = property.signature {
+                true.write(w)?;
+                signature.write(w)?;
+            } else {
+                false.write(w)?;
+            }
+        }
+    }
+    if actions.contains(PlayerInfoActions::INITIALIZE_CHAT) {
+        if let Some(session) = entry.chat_session {
+            true.write(w)?;
+            session.session_id.write(w)?;
+            session.public_key.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_GAME_MODE) {
+        VarInt(entry.game_mode as i32).write(w)?;
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_LISTED) {
+        entry.listed.write(w)?;
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_LATENCY) {
+        VarInt(entry.latency).write(w)?;
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_DISPLAY_NAME) {
+        if let Some(display_name) = entry.display_name {
+            true.write(w)?;
+            display_name.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+    }
+    Ok(())
+}
+
+/// The fields the actions do not send keep the defaults of [PlayerInfoEntry::new]
+fn read_entry<R: Read>(
+    actions: PlayerInfoActions,
+    r: &mut R,
+) -> Result<PlayerInfoEntry, PacketReadError> {
+    let mut entry = PlayerInfoEntry::new(Uuid::read(r)?, String::new());
+    if actions.contains(PlayerInfoActions::ADD_PLAYER) {
+        entry.name = String::read(r)?;
+        let length = limits::read_array_length(r)?;
+        for _ in 0..length {
+            entry.properties.push(Property {
+                name: String::read(r)?,
+                value: String::read(r)?,
+                signature: if bool::read(r)? {
+                    Some(String::read(r)?)
+                } else {
+                    None
+                },
+            });
+        }
+    }
+    if actions.contains(PlayerInfoActions::INITIALIZE_CHAT) && bool::read(r)? {
+        entry.chat_session = Some(PlayerSession {
+            session_id: Uuid::read(r)?,
+            public_key: ProfilePublicKey::read(r)?,
+        });
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_GAME_MODE) {
+        let game_mode = VarInt::read(r)?.0;
+        entry.game_mode = u8::try_from(game_mode)
+            .ok()
+            .and_then(|game_mode| GameMode::try_from(game_mode).ok())
+            .ok_or(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Invalid game mode {}",
+                game_mode
+            )))?;
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_LISTED) {
+        entry.listed = bool::read(r)?;
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_LATENCY) {
+        entry.latency = VarInt::read(r)?.0;
+    }
+    if actions.contains(PlayerInfoActions::UPDATE_DISPLAY_NAME) && bool::read(r)? {
+        entry.display_name = Some(Component::read(r)?);
+    }
+    Ok(entry)
+}
+
+/// The actions are sent as a 6 bit EnumSet
+#[derive(PacketImplDebug)]
+pub struct ClientBoundPlayerInfoUpdateImpl;
+
+impl Packet for ClientBoundPlayerInfoUpdateImpl {
+    define_packet!(
+        PlayerInfoUpdate,
+        0x36,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.actions.bits().write(w)?;
+        VarInt(content.players.len() as i32).write(w)?;
+        for entry in content.players {
+            write_entry(content.actions, entry, w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let actions = PlayerInfoActions::from_bits(u8::read(r)?).ok_or(
+            PacketReadError::InvalidData(anyhow::anyhow!("Invalid player info actions")),
+        )?;
+        let length = limits::read_array_length(r)?;
+        let mut players = limits::with_capacity(length);
+        for _ in 0..length {
+            players.push(read_entry(actions, r)?);
+        }
+        Ok(PlayerInfoUpdate { actions, players })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundPlayerInfoRemoveImpl;
+
+impl Packet for ClientBoundPlayerInfoRemoveImpl {
+    define_packet!(
+        PlayerInfoRemove,
+        0x35,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.players.len() as i32).write(w)?;
+        for uuid in content.players {
+            uuid.write(w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let length = limits::read_array_length(r)?;
+        let mut players = limits::with_capacity(length);
+        for _ in 0..length {
+            players.push(Uuid::read(r)?);
+        }
+        Ok(PlayerInfoRemove { players })
+    }
+}
@@ -0,0 +1,112 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::{PackedPosition, PacketDataType};
+use crate::java::define_packet;
+use crate::packets::play::client::login::GameMode;
+use crate::packets::play::client::respawn::{Respawn, RespawnDataKept, SetDefaultSpawnPosition};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// 1.19.4 sends the kept data as flags. Older versions as a bool
+pub(crate) fn write_respawn<W: Write>(
+    content: Respawn,
+    data_kept_flags: bool,
+    w: &mut W,
+) -> Result<(), PacketWriteError> {
+    content.dimension_type.write(w)?;
+    content.dimension_name.write(w)?;
+    w.write_all(content.hashed_seed.as_ref())?;
+    content.game_mode.write(w)?;
+    content.previous_game_mode.write(w)?;
+    content.is_debug.write(w)?;
+    content.is_flat.write(w)?;
+    if data_kept_flags {
+        content.data_kept.bits().write(w)?;
+    } else {
+        content.data_kept.is_all().write(w)?;
+    }
+    if let Some((dimension, position)) = content.death_location {
+        true.write(w)?;
+        dimension.write(w)?;
+        position.write(w)?;
+    } else {
+        false.write(w)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_respawn<R: Read>(
+    r: &mut R,
+    data_kept_flags: bool,
+) -> Result<Respawn, PacketReadError> {
+    Ok(Respawn {
+        dimension_type: String::read(r)?,
+        dimension_name: String::read(r)?,
+        hashed_seed: {
+            let mut seed = [0u8; 8];
+            r.read_exact(&mut seed)?;
+            seed
+        },
+        game_mode: GameMode::read(r)?,
+        previous_game_mode: i8::read(r)?,
+        is_debug: bool::read(r)?,
+        is_flat: bool::read(r)?,
+        data_kept: if data_kept_flags {
+            RespawnDataKept::from_bits_truncate(u8::read(r)?)
+        } else if bool::read(r)? {
+            RespawnDataKept::all()
+        } else {
+            RespawnDataKept::empty()
+        },
+        death_location: if bool::read(r)? {
+            Some((String::read(r)?, i64::read(r)?))
+        } else {
+            None
+        },
+    })
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundRespawnImpl;
+
+impl Packet for ClientBoundRespawnImpl {
+    define_packet!(Respawn, 0x3D, Bound::ClientBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        write_respawn(content, false, w)
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        read_respawn(r, false)
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetDefaultSpawnPositionImpl;
+
+impl Packet for ClientBoundSetDefaultSpawnPositionImpl {
+    define_packet!(
+        SetDefaultSpawnPosition,
+        0x4C,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.position.write(w)?;
+        content.angle.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetDefaultSpawnPosition {
+            position: PackedPosition::read(r)?,
+            angle: f32::read(r)?,
+        })
+    }
+}
@@ -0,0 +1,277 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::chat::{Component, NamedColor};
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::limits;
+use crate::packets::play::client::scoreboard::{
+    CollisionRule, DisplayObjective, NameTagVisibility, ObjectiveAction, ObjectiveRenderType,
+    ScoreAction, TeamAction, TeamFlags, TeamInfo, UpdateObjectives, UpdateScore, UpdateTeams,
+    MAX_ENTITY_NAME_LENGTH, MAX_NAME_LENGTH,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// The `reset` formatting. Sent for a team without a color
+const RESET_COLOR: i32 = 21;
+
+fn read_name<R: Read>(r: &mut R, max: usize) -> Result<String, PacketReadError> {
+    let name = String::read(r)?;
+    if name.chars().count() > max {
+        return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+            "Name {:?} is longer than {} characters",
+            name,
+            max
+        )));
+    }
+    Ok(name)
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateObjectivesImpl;
+
+impl Packet for ClientBoundUpdateObjectivesImpl {
+    define_packet!(
+        UpdateObjectives,
+        0x54,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.name.write(w)?;
+        content.action.mode().write(w)?;
+        match content.action {
+            ObjectiveAction::Create {
+                display_name,
+                render_type,
+            }
+            | ObjectiveAction::Update {
+                display_name,
+                render_type,
+            } => {
+                display_name.write(w)?;
+                render_type.write(w)?;
+            }
+            ObjectiveAction::Remove => {}
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let name = read_name(r, MAX_NAME_LENGTH)?;
+        let action = match i8::read(r)? {
+            0 => ObjectiveAction::Create {
+                display_name: Component::read(r)?,
+                render_type: ObjectiveRenderType::read(r)?,
+            },
+            1 => ObjectiveAction::Remove,
+            2 => ObjectiveAction::Update {
+                display_name: Component::read(r)?,
+                render_type: ObjectiveRenderType::read(r)?,
+            },
+            mode => {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Invalid objective mode {}",
+                    mode
+                )))
+            }
+        };
+        Ok(UpdateObjectives { name, action })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundDisplayObjectiveImpl;
+
+impl Packet for ClientBoundDisplayObjectiveImpl {
+    define_packet!(
+        DisplayObjective,
+        0x4D,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.position.write(w)?;
+        content.name.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(DisplayObjective {
+            position: i8::read(r)?,
+            name: read_name(r, MAX_NAME_LENGTH)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateScoreImpl;
+
+impl Packet for ClientBoundUpdateScoreImpl {
+    define_packet!(
+        UpdateScore,
+        0x57,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.entity_name.write(w)?;
+        match content.action {
+            ScoreAction::Update(value) => {
+                VarInt(0).write(w)?;
+                content.objective.write(w)?;
+                VarInt(value).write(w)?;
+            }
+            ScoreAction::Remove => {
+                VarInt(1).write(w)?;
+                content.objective.write(w)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let entity_name = read_name(r, MAX_ENTITY_NAME_LENGTH)?;
+        let action = VarInt::read(r)?.0;
+        let objective = read_name(r, MAX_NAME_LENGTH)?;
+        let action = match action {
+            0 => ScoreAction::Update(VarInt::read(r)?.0),
+            1 => ScoreAction::Remove,
+            action => {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Invalid score action {}",
+                    action
+                )))
+            }
+        };
+        Ok(UpdateScore {
+            entity_name,
+            objective,
+            action,
+        })
+    }
+}
+
+fn write_team_info<W: Write>(info: TeamInfo, w: &mut W) -> Result<(), PacketWriteError> {
+    info.display_name.write(w)?;
+    info.flags.bits().write(w)?;
+    info.name_tag_visibility.name().write(w)?;
+    info.collision_rule.name().write(w)?;
+    VarInt(info.color.map_or(RESET_COLOR, |color| color as i32)).write(w)?;
+    info.prefix.write(w)?;
+    info.suffix.write(w)?;
+    Ok(())
+}
+
+fn read_team_info<R: Read>(r: &mut R) -> Result<TeamInfo, PacketReadError> {
+    let display_name = Component::read(r)?;
+    let flags = TeamFlags::from_bits_truncate(u8::read(r)?);
+    let name_tag_visibility = String::read(r)?;
+    let name_tag_visibility =
+        NameTagVisibility::from_name(&name_tag_visibility).ok_or(PacketReadError::InvalidData(
+            anyhow::anyhow!("Invalid name tag visibility {}", name_tag_visibility),
+        ))?;
+    let collision_rule = String::read(r)?;
+    let collision_rule = CollisionRule::from_name(&collision_rule).ok_or(
+        PacketReadError::InvalidData(anyhow::anyhow!("Invalid collision rule {}", collision_rule)),
+    )?;
+    // Formatting codes other than colors are treated like reset
+    let color = match VarInt::read(r)?.0 {
+        color @ 0..=15 => Some(NamedColor::ALL[color as usize]),
+        16..=RESET_COLOR => None,
+        color => {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Invalid team color {}",
+                color
+            )))
+        }
+    };
+    Ok(TeamInfo {
+        display_name,
+        flags,
+        name_tag_visibility,
+        collision_rule,
+        color,
+        prefix: Component::read(r)?,
+        suffix: Component::read(r)?,
+    })
+}
+
+fn write_entities<W: Write>(entities: Vec<String>, w: &mut W) -> Result<(), PacketWriteError> {
+    VarInt(entities.len() as i32).write(w)?;
+    for entity in entities {
+        entity.write(w)?;
+    }
+    Ok(())
+}
+
+fn read_entities<R: Read>(r: &mut R) -> Result<Vec<String>, PacketReadError> {
+    let length = limits::read_array_length(r)?;
+    (0..length)
+        .map(|_| read_name(r, MAX_ENTITY_NAME_LENGTH))
+        .collect()
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateTeamsImpl;
+
+impl Packet for ClientBoundUpdateTeamsImpl {
+    define_packet!(
+        UpdateTeams,
+        0x56,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.name.write(w)?;
+        content.action.mode().write(w)?;
+        match content.action {
+            TeamAction::Create { info, entities } => {
+                write_team_info(info, w)?;
+                write_entities(entities, w)?;
+            }
+            TeamAction::Remove => {}
+            TeamAction::UpdateInfo(info) => write_team_info(info, w)?,
+            TeamAction::AddEntities(entities) | TeamAction::RemoveEntities(entities) => {
+                write_entities(entities, w)?
+            }
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let name = read_name(r, MAX_NAME_LENGTH)?;
+        let action = match i8::read(r)? {
+            0 => TeamAction::Create {
+                info: read_team_info(r)?,
+                entities: read_entities(r)?,
+            },
+            1 => TeamAction::Remove,
+            2 => TeamAction::UpdateInfo(read_team_info(r)?),
+            3 => TeamAction::AddEntities(read_entities(r)?),
+            4 => TeamAction::RemoveEntities(read_entities(r)?),
+            mode => {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Invalid team mode {}",
+                    mode
+                )))
+            }
+        };
+        Ok(UpdateTeams { name, action })
+    }
+}
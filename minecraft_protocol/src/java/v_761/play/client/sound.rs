@@ -0,0 +1,159 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::packets::play::client::sound::{
+    EntitySoundEffect, SoundCategory, SoundEffect, SoundEvent, StopSound,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// Registry ids are sent plus one. Zero is followed by the name
+fn write_sound_event<W: Write>(sound: SoundEvent, w: &mut W) -> Result<(), PacketWriteError> {
+    match sound {
+        SoundEvent::Registered(id) => VarInt(id + 1).write(w)?,
+        SoundEvent::Named { name, fixed_range } => {
+            VarInt(0).write(w)?;
+            name.write(w)?;
+            if let Some(fixed_range) = fixed_range {
+                true.write(w)?;
+                fixed_range.write(w)?;
+            } else {
+                false.write(w)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_sound_event<R: Read>(r: &mut R) -> Result<SoundEvent, PacketReadError> {
+    let sound = match VarInt::read(r)?.0 {
+        0 => SoundEvent::Named {
+            name: String::read(r)?,
+            fixed_range: if bool::read(r)? {
+                Some(f32::read(r)?)
+            } else {
+                None
+            },
+        },
+        id if id > 0 => SoundEvent::Registered(id - 1),
+        id => {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Invalid sound id {}",
+                id
+            )))
+        }
+    };
+    Ok(sound)
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSoundEffectImpl;
+
+impl Packet for ClientBoundSoundEffectImpl {
+    define_packet!(
+        SoundEffect,
+        0x5E,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        write_sound_event(content.sound, w)?;
+        content.category.write(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.volume.write(w)?;
+        content.pitch.write(w)?;
+        content.seed.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SoundEffect {
+            sound: read_sound_event(r)?,
+            category: SoundCategory::read(r)?,
+            x: i32::read(r)?,
+            y: i32::read(r)?,
+            z: i32::read(r)?,
+            volume: f32::read(r)?,
+            pitch: f32::read(r)?,
+            seed: i64::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundEntitySoundEffectImpl;
+
+impl Packet for ClientBoundEntitySoundEffectImpl {
+    define_packet!(
+        EntitySoundEffect,
+        0x5D,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        write_sound_event(content.sound, w)?;
+        content.category.write(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.volume.write(w)?;
+        content.pitch.write(w)?;
+        content.seed.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(EntitySoundEffect {
+            sound: read_sound_event(r)?,
+            category: SoundCategory::read(r)?,
+            entity_id: VarInt::read(r)?.0,
+            volume: f32::read(r)?,
+            pitch: f32::read(r)?,
+            seed: i64::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundStopSoundImpl;
+
+impl Packet for ClientBoundStopSoundImpl {
+    define_packet!(StopSound, 0x5F, Bound::ClientBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.flags().write(w)?;
+        if let Some(category) = content.category {
+            category.write(w)?;
+        }
+        if let Some(sound) = content.sound {
+            sound.write(w)?;
+        }
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let flags = u8::read(r)?;
+        let category = if flags & StopSound::HAS_CATEGORY != 0 {
+            Some(SoundCategory::read(r)?)
+        } else {
+            None
+        };
+        let sound = if flags & StopSound::HAS_SOUND != 0 {
+            Some(String::read(r)?)
+        } else {
+            None
+        };
+        Ok(StopSound { category, sound })
+    }
+}
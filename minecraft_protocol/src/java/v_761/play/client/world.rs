@@ -0,0 +1,229 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::var_int::VarInt;
+use crate::data::var_long::VarLong;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::packets::play::client::world::{
+    GameEvent, InitializeWorldBorder, SetBorderCenter, SetBorderLerpSize, SetBorderSize,
+    SetBorderWarningDelay, SetBorderWarningDistance, UpdateTime,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundInitializeWorldBorderImpl;
+
+impl Packet for ClientBoundInitializeWorldBorderImpl {
+    define_packet!(
+        InitializeWorldBorder,
+        0x1E,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.x.write(w)?;
+        content.z.write(w)?;
+        content.old_diameter.write(w)?;
+        content.new_diameter.write(w)?;
+        VarLong(content.speed).write(w)?;
+        VarInt(content.portal_teleport_boundary).write(w)?;
+        VarInt(content.warning_blocks).write(w)?;
+        VarInt(content.warning_time).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(InitializeWorldBorder {
+            x: f64::read(r)?,
+            z: f64::read(r)?,
+            old_diameter: f64::read(r)?,
+            new_diameter: f64::read(r)?,
+            speed: VarLong::read(r)?.0,
+            portal_teleport_boundary: VarInt::read(r)?.0,
+            warning_blocks: VarInt::read(r)?.0,
+            warning_time: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetBorderCenterImpl;
+
+impl Packet for ClientBoundSetBorderCenterImpl {
+    define_packet!(
+        SetBorderCenter,
+        0x43,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.x.write(w)?;
+        content.z.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetBorderCenter {
+            x: f64::read(r)?,
+            z: f64::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetBorderLerpSizeImpl;
+
+impl Packet for ClientBoundSetBorderLerpSizeImpl {
+    define_packet!(
+        SetBorderLerpSize,
+        0x44,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.old_diameter.write(w)?;
+        content.new_diameter.write(w)?;
+        VarLong(content.speed).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetBorderLerpSize {
+            old_diameter: f64::read(r)?,
+            new_diameter: f64::read(r)?,
+            speed: VarLong::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetBorderSizeImpl;
+
+impl Packet for ClientBoundSetBorderSizeImpl {
+    define_packet!(
+        SetBorderSize,
+        0x45,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.diameter.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetBorderSize {
+            diameter: f64::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetBorderWarningDelayImpl;
+
+impl Packet for ClientBoundSetBorderWarningDelayImpl {
+    define_packet!(
+        SetBorderWarningDelay,
+        0x46,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.warning_time).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetBorderWarningDelay {
+            warning_time: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundSetBorderWarningDistanceImpl;
+
+impl Packet for ClientBoundSetBorderWarningDistanceImpl {
+    define_packet!(
+        SetBorderWarningDistance,
+        0x47,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.warning_blocks).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetBorderWarningDistance {
+            warning_blocks: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundUpdateTimeImpl;
+
+impl Packet for ClientBoundUpdateTimeImpl {
+    define_packet!(UpdateTime, 0x5A, Bound::ClientBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.world_age.write(w)?;
+        content.time_of_day.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UpdateTime {
+            world_age: i64::read(r)?,
+            time_of_day: i64::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ClientBoundGameEventImpl;
+
+impl Packet for ClientBoundGameEventImpl {
+    define_packet!(GameEvent, 0x1C, Bound::ClientBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.event_id().write(w)?;
+        content.value().write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let event_id = u8::read(r)?;
+        let value = f32::read(r)?;
+        GameEvent::from_parts(event_id, value).ok_or(PacketReadError::InvalidData(anyhow::anyhow!(
+            "Invalid game event {} with value {}",
+            event_id,
+            value
+        )))
+    }
+}
@@ -0,0 +1,161 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::packets::play::server::chat::{
+    ArgumentSignature, ChatCommand, ChatMessage, LastSeenUpdate, MessageAcknowledgment,
+    PlayerSession, ProfilePublicKey,
+};
+use crate::packets::play::MessageSignature;
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+/// The longest chat message the client sends. In UTF-16 code units like the string limits
+pub const MAX_MESSAGE_LENGTH: usize = 256;
+
+fn check_message_length(message: &str) -> Result<(), PacketReadError> {
+    if message.encode_utf16().count() > MAX_MESSAGE_LENGTH {
+        return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+            "Chat message is longer than {} characters",
+            MAX_MESSAGE_LENGTH
+        )));
+    }
+    Ok(())
+}
+
+#[derive(PacketImplDebug)]
+pub struct MessageAcknowledgmentImpl;
+
+impl Packet for MessageAcknowledgmentImpl {
+    define_packet!(
+        MessageAcknowledgment,
+        0x03,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.offset).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(MessageAcknowledgment {
+            offset: VarInt::read(r)?.0,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ChatCommandImpl;
+
+impl Packet for ChatCommandImpl {
+    define_packet!(
+        ChatCommand,
+        0x04,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.command.write(w)?;
+        content.timestamp.write(w)?;
+        content.salt.write(w)?;
+        content.argument_signatures.write(w)?;
+        content.last_seen.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let command = String::read(r)?;
+        check_message_length(&command)?;
+        Ok(ChatCommand {
+            command,
+            timestamp: i64::read(r)?,
+            salt: i64::read(r)?,
+            argument_signatures: Vec::<ArgumentSignature>::read(r)?,
+            last_seen: LastSeenUpdate::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ChatMessageImpl;
+
+impl Packet for ChatMessageImpl {
+    define_packet!(
+        ChatMessage,
+        0x05,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.message.write(w)?;
+        content.timestamp.write(w)?;
+        content.salt.write(w)?;
+        if let Some(signature) = content.signature {
+            true.write(w)?;
+            signature.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        content.last_seen.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let message = String::read(r)?;
+        check_message_length(&message)?;
+        let timestamp = i64::read(r)?;
+        let salt = i64::read(r)?;
+        let signature = if bool::read(r)? {
+            Some(MessageSignature::read(r)?)
+        } else {
+            None
+        };
+        Ok(ChatMessage {
+            message,
+            timestamp,
+            salt,
+            signature,
+            last_seen: LastSeenUpdate::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct PlayerSessionImpl;
+
+impl Packet for PlayerSessionImpl {
+    define_packet!(
+        PlayerSession,
+        0x20,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.session_id.write(w)?;
+        content.public_key.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(PlayerSession {
+            session_id: PacketDataType::read(r)?,
+            public_key: ProfilePublicKey::read(r)?,
+        })
+    }
+}
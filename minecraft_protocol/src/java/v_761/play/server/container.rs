@@ -0,0 +1,146 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::slot::Slot;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::limits;
+use crate::packets::play::server::container::{
+    ChangedSlot, ClickContainer, ClickMode, ServerBoundCloseContainer, SetCreativeModeSlot,
+    SetHeldItem,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+#[derive(PacketImplDebug)]
+pub struct ClickContainerImpl;
+
+impl Packet for ClickContainerImpl {
+    define_packet!(
+        ClickContainer,
+        0x0A,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.window_id.write(w)?;
+        VarInt(content.state_id).write(w)?;
+        content.slot.write(w)?;
+        content.button.write(w)?;
+        content.mode.write(w)?;
+        VarInt(content.changed_slots.len() as i32).write(w)?;
+        for changed in content.changed_slots {
+            changed.slot.write(w)?;
+            changed.item.write(w)?;
+        }
+        content.carried_item.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let window_id = u8::read(r)?;
+        let state_id = VarInt::read(r)?.0;
+        let slot = i16::read(r)?;
+        let button = i8::read(r)?;
+        let mode = ClickMode::read(r)?;
+        let length = limits::read_array_length(r)?;
+        let mut changed_slots = limits::with_capacity(length);
+        for _ in 0..length {
+            changed_slots.push(ChangedSlot {
+                slot: i16::read(r)?,
+                item: Slot::read(r)?,
+            });
+        }
+        Ok(ClickContainer {
+            window_id,
+            state_id,
+            slot,
+            button,
+            mode,
+            changed_slots,
+            carried_item: Slot::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ServerBoundCloseContainerImpl;
+
+impl Packet for ServerBoundCloseContainerImpl {
+    define_packet!(
+        ServerBoundCloseContainer,
+        0x0B,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.window_id.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(ServerBoundCloseContainer {
+            window_id: u8::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct SetHeldItemImpl;
+
+impl Packet for SetHeldItemImpl {
+    define_packet!(
+        SetHeldItem,
+        0x28,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.slot.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetHeldItem {
+            slot: i16::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct SetCreativeModeSlotImpl;
+
+impl Packet for SetCreativeModeSlotImpl {
+    define_packet!(
+        SetCreativeModeSlot,
+        0x2B,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.slot.write(w)?;
+        content.item.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SetCreativeModeSlot {
+            slot: i16::read(r)?,
+            item: Slot::read(r)?,
+        })
+    }
+}
@@ -0,0 +1,227 @@
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketImplDebug;
+
+use crate::data::var_int::VarInt;
+use crate::data::{PackedPosition, PacketDataType};
+use crate::java::define_packet;
+use crate::packets::play::client::metadata::Direction;
+use crate::packets::play::server::interaction::{
+    Hand, Interact, InteractAction, PlayerAction, PlayerActionStatus, PlayerCommand,
+    PlayerCommandAction, SwingArm, UseItem, UseItemOn,
+};
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+fn invalid(error: impl std::error::Error + Send + Sync + 'static) -> PacketReadError {
+    PacketReadError::InvalidData(error.into())
+}
+
+/// The enums are read as numbers so a bad value is invalid data and not an io error
+fn read_hand<R: Read>(r: &mut R) -> Result<Hand, PacketReadError> {
+    Hand::try_from(VarInt::read(r)?.0).map_err(invalid)
+}
+
+/// The server acknowledges up to the sequence. So it can not be negative
+fn read_sequence<R: Read>(r: &mut R) -> Result<i32, PacketReadError> {
+    let sequence = VarInt::read(r)?.0;
+    if sequence < 0 {
+        return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+            "Negative sequence {}",
+            sequence
+        )));
+    }
+    Ok(sequence)
+}
+
+#[derive(PacketImplDebug)]
+pub struct PlayerActionImpl;
+
+impl Packet for PlayerActionImpl {
+    define_packet!(
+        PlayerAction,
+        0x1C,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.status.write(w)?;
+        content.position.write(w)?;
+        (content.face as u8).write(w)?;
+        VarInt(content.sequence).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(PlayerAction {
+            status: PlayerActionStatus::try_from(VarInt::read(r)?.0).map_err(invalid)?,
+            position: PackedPosition::read(r)?,
+            face: Direction::try_from(u8::read(r)? as i32).map_err(invalid)?,
+            sequence: read_sequence(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct UseItemOnImpl;
+
+impl Packet for UseItemOnImpl {
+    define_packet!(UseItemOn, 0x31, Bound::ServerBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.hand.write(w)?;
+        content.position.write(w)?;
+        content.face.write(w)?;
+        content.cursor_x.write(w)?;
+        content.cursor_y.write(w)?;
+        content.cursor_z.write(w)?;
+        content.inside_block.write(w)?;
+        VarInt(content.sequence).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UseItemOn {
+            hand: read_hand(r)?,
+            position: PackedPosition::read(r)?,
+            face: Direction::try_from(VarInt::read(r)?.0).map_err(invalid)?,
+            cursor_x: f32::read(r)?,
+            cursor_y: f32::read(r)?,
+            cursor_z: f32::read(r)?,
+            inside_block: bool::read(r)?,
+            sequence: read_sequence(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct UseItemImpl;
+
+impl Packet for UseItemImpl {
+    define_packet!(UseItem, 0x32, Bound::ServerBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.hand.write(w)?;
+        VarInt(content.sequence).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(UseItem {
+            hand: read_hand(r)?,
+            sequence: read_sequence(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct SwingArmImpl;
+
+impl Packet for SwingArmImpl {
+    define_packet!(SwingArm, 0x2F, Bound::ServerBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.hand.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SwingArm {
+            hand: read_hand(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct InteractImpl;
+
+impl Packet for InteractImpl {
+    define_packet!(Interact, 0x0F, Bound::ServerBound, Stage::Play, Java(761));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        VarInt(content.action.action_id()).write(w)?;
+        match content.action {
+            InteractAction::Interact(hand) => hand.write(w)?,
+            InteractAction::Attack => {}
+            InteractAction::InteractAt { x, y, z, hand } => {
+                x.write(w)?;
+                y.write(w)?;
+                z.write(w)?;
+                hand.write(w)?;
+            }
+        }
+        content.sneaking.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let entity_id = VarInt::read(r)?.0;
+        let action = match VarInt::read(r)?.0 {
+            0 => InteractAction::Interact(read_hand(r)?),
+            1 => InteractAction::Attack,
+            2 => InteractAction::InteractAt {
+                x: f32::read(r)?,
+                y: f32::read(r)?,
+                z: f32::read(r)?,
+                hand: read_hand(r)?,
+            },
+            action => {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Invalid interact action {}",
+                    action
+                )))
+            }
+        };
+        Ok(Interact {
+            entity_id,
+            action,
+            sneaking: bool::read(r)?,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct PlayerCommandImpl;
+
+impl Packet for PlayerCommandImpl {
+    define_packet!(
+        PlayerCommand,
+        0x1D,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.entity_id).write(w)?;
+        content.action.write(w)?;
+        VarInt(content.jump_boost).write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let entity_id = VarInt::read(r)?.0;
+        let action = PlayerCommandAction::try_from(VarInt::read(r)?.0).map_err(invalid)?;
+        let jump_boost = VarInt::read(r)?.0;
+        if !(0..=PlayerCommand::MAX_JUMP_BOOST).contains(&jump_boost) {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Invalid jump boost {}",
+                jump_boost
+            )));
+        }
+        Ok(PlayerCommand {
+            entity_id,
+            action,
+            jump_boost,
+        })
+    }
+}
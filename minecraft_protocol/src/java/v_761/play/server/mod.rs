@@ -1,22 +1,36 @@
 use std::borrow::Cow;
 use std::io::{Read, Write};
 
-use minecraft_protocol_macros::PacketImplDebug;
+use minecraft_protocol_macros::{Packet, PacketImplDebug};
 pub use move_packet::*;
 
+use crate::java::v_761::play::server::chat::{
+    ChatCommandImpl, ChatMessageImpl, MessageAcknowledgmentImpl, PlayerSessionImpl,
+};
+use crate::java::v_761::play::server::container::{
+    ClickContainerImpl, ServerBoundCloseContainerImpl, SetCreativeModeSlotImpl, SetHeldItemImpl,
+};
+use crate::java::v_761::play::server::interaction::{
+    InteractImpl, PlayerActionImpl, PlayerCommandImpl, SwingArmImpl, UseItemImpl, UseItemOnImpl,
+};
+
 use crate::data::var_int::VarInt;
 use crate::data::PacketDataType;
 use crate::java::define_packet;
-use crate::java::v_761::new_type_struct_define_packet;
 pub use crate::java::v_761::play::client::login::ClientBoundLoginPacketImpl;
+use crate::java::v_761::processor::plugin_channels::MAX_PAYLOAD_SIZE;
 use crate::packets::play::server::{
-    ClientInformation, ConfirmTeleport, ServerBoundPlay, SkinParts,
+    ClientInformation, CommandSuggestionsRequest, ConfirmTeleport, ResourcePackStatus,
+    ServerBoundPlay, SkinParts,
 };
 use crate::packets::play::{KeepAlive, PlayPing, PlayPluginMessage};
 use crate::PacketIO;
 use crate::Protocol::Java;
 use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
 
+pub mod chat;
+pub mod container;
+pub mod interaction;
 mod move_packet;
 
 minecraft_protocol_macros::define_io!(ServerBoundPlay {
@@ -51,38 +65,84 @@ minecraft_protocol_macros::define_io!(ServerBoundPlay {
 0x0C	 => {
         type_name: ServerBoundPluginMessageImpl
         g_var:  PluginMessage
+    },
+    0x03 => {
+        type_name: MessageAcknowledgmentImpl
+        g_var:  MessageAcknowledgment
+    },
+    0x04 => {
+        type_name: ChatCommandImpl
+        g_var:  ChatCommand
+    },
+    0x05 => {
+        type_name: ChatMessageImpl
+        g_var:  ChatMessage
+    },
+    0x20 => {
+        type_name: PlayerSessionImpl
+        g_var:  PlayerSession
+    },
+    0x0A => {
+        type_name: ClickContainerImpl
+        g_var:  ClickContainer
+    },
+    0x0B => {
+        type_name: ServerBoundCloseContainerImpl
+        g_var:  CloseContainer
+    },
+    0x2B => {
+        type_name: SetCreativeModeSlotImpl
+        g_var:  SetCreativeModeSlot
+    },
+    0x28 => {
+        type_name: SetHeldItemImpl
+        g_var:  SetHeldItem
+    },
+    0x08 => {
+        type_name: CommandSuggestionsRequestImpl
+        g_var:  CommandSuggestionsRequest
+    },
+    0x24 => {
+        type_name: ResourcePackStatusImpl
+        g_var:  ResourcePackStatus
+    },
+    0x1C => {
+        type_name: PlayerActionImpl
+        g_var:  PlayerAction
+    },
+    0x31 => {
+        type_name: UseItemOnImpl
+        g_var:  UseItemOn
+    },
+    0x32 => {
+        type_name: UseItemImpl
+        g_var:  UseItem
+    },
+    0x2F => {
+        type_name: SwingArmImpl
+        g_var:  SwingArm
+    },
+    0x0F => {
+        type_name: InteractImpl
+        g_var:  Interact
+    },
+    0x1D => {
+        type_name: PlayerCommandImpl
+        g_var:  PlayerCommand
     }
 
 }
 );
 
-new_type_struct_define_packet!(
-    PongPacket,
-    PlayPing,
-    0x1F,
-    Bound::ServerBound,
-    Stage::Play,
-    Java(761),
-    i32
-);
-new_type_struct_define_packet!(
-    KeepAlivePacket,
-    KeepAlive,
-    0x11,
-    Bound::ServerBound,
-    Stage::Play,
-    Java(761),
-    i64
-);
-new_type_struct_define_packet!(
-    ConfirmTeleportImpl,
-    ConfirmTeleport,
-    0x00,
-    Bound::ServerBound,
-    Stage::Play,
-    Java(761),
-    VarInt
-);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x1F, bound = ServerBound, stage = Play, protocol = 761, content = PlayPing)]
+pub struct PongPacket(i32);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x11, bound = ServerBound, stage = Play, protocol = 761, content = KeepAlive)]
+pub struct KeepAlivePacket(i64);
+#[derive(PacketImplDebug, Packet)]
+#[packet(id = 0x00, bound = ServerBound, stage = Play, protocol = 761, content = ConfirmTeleport)]
+pub struct ConfirmTeleportImpl(VarInt);
 #[derive(PacketImplDebug)]
 pub struct ClientInformationImpl;
 impl Packet for ClientInformationImpl {
@@ -94,8 +154,17 @@ impl Packet for ClientInformationImpl {
         Java(761)
     );
 
-    fn write<W: Write>(_content: Self::Content, _w: &mut W) -> Result<(), PacketWriteError> {
-        todo!()
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.locale.write(w)?;
+        content.view_distance.write(w)?;
+        content.chat_mode.write(w)?;
+        content.chat_colors.write(w)?;
+        content.displayed_skin_parts.bits().write(w)?;
+        content.main_hand.write(w)?;
+        content.enable_text_filtering.write(w)?;
+        content.allow_server_listings.write(w)?;
+        Ok(())
     }
 
     fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
@@ -149,11 +218,78 @@ impl Packet for ServerBoundPluginMessageImpl {
         length: usize,
     ) -> Result<Self::Content, PacketReadError> {
         let id = String::read(r)?;
-        let mut data = Vec::<u8>::with_capacity(length - id.len());
-        r.read_to_end(&mut data)?;
+        let mut data = Vec::<u8>::with_capacity(length.saturating_sub(id.len()));
+        r.take(MAX_PAYLOAD_SIZE as u64 + 1).read_to_end(&mut data)?;
+        if data.len() > MAX_PAYLOAD_SIZE {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Plugin message payload is larger than {} bytes",
+                MAX_PAYLOAD_SIZE
+            )));
+        }
         Ok(PlayPluginMessage {
             id: Cow::Owned(id),
             data,
         })
     }
 }
+
+/// The longest command the client sends. In UTF-16 code units like the string limits
+pub const MAX_COMMAND_LENGTH: usize = 32500;
+
+#[derive(PacketImplDebug)]
+pub struct CommandSuggestionsRequestImpl;
+
+impl Packet for CommandSuggestionsRequestImpl {
+    define_packet!(
+        CommandSuggestionsRequest,
+        0x08,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        VarInt(content.transaction_id).write(w)?;
+        content.text.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let transaction_id = VarInt::read(r)?.0;
+        let text = String::read(r)?;
+        if text.encode_utf16().count() > MAX_COMMAND_LENGTH {
+            return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                "Command is longer than {} characters",
+                MAX_COMMAND_LENGTH
+            )));
+        }
+        Ok(CommandSuggestionsRequest {
+            transaction_id,
+            text,
+        })
+    }
+}
+
+#[derive(PacketImplDebug)]
+pub struct ResourcePackStatusImpl;
+
+impl Packet for ResourcePackStatusImpl {
+    define_packet!(
+        ResourcePackStatus,
+        0x24,
+        Bound::ServerBound,
+        Stage::Play,
+        Java(761)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(ResourcePackStatus::read(r)?)
+    }
+}
@@ -2,13 +2,52 @@ use std::io::{Read, Write};
 
 use minecraft_protocol_macros::PacketImplDebug;
 
-use crate::data::PacketDataType;
-use crate::java::define_packet;
+use crate::data::{var_int, PacketDataType};
+use crate::java::{call_write, define_packet};
 use crate::packets::play::server::ServerBoundMove;
 use crate::Protocol;
 use crate::Protocol::Java;
 use crate::{Bound, Packet, PacketReadError, PacketWriteError, Stage};
 
+/// The packet ids of [SetPlayerPosition], [SetPlayerPositionAndRotation] and [SetPlayerRotation]
+pub(crate) const MOVE_IDS: [i32; 3] = [0x13, 0x14, 0x15];
+
+/// All the move packets share [ServerBoundMove]. So the packet that is written depends on the variant
+///
+/// `ids` are the packet ids for Position, Position and Rotation, Rotation in that order
+pub(crate) fn write_move<W: Write>(
+    content: ServerBoundMove,
+    ids: [i32; 3],
+    w: &mut W,
+) -> Result<(), PacketWriteError> {
+    match content {
+        ServerBoundMove::PlayerPosition { x, y, z, on_ground } => {
+            var_int::inline::write(ids[0], w)?;
+            call_write!(w, x, y, z, on_ground);
+        }
+        ServerBoundMove::PlayerPositionAndRotation {
+            x,
+            y,
+            z,
+            yaw,
+            pitch,
+            on_ground,
+        } => {
+            var_int::inline::write(ids[1], w)?;
+            call_write!(w, x, y, z, yaw, pitch, on_ground);
+        }
+        ServerBoundMove::PlayerRotation {
+            yaw,
+            pitch,
+            on_ground,
+        } => {
+            var_int::inline::write(ids[2], w)?;
+            call_write!(w, yaw, pitch, on_ground);
+        }
+    }
+    Ok(())
+}
+
 #[derive(PacketImplDebug)]
 pub struct SetPlayerPosition;
 
@@ -21,8 +60,8 @@ impl Packet for SetPlayerPosition {
         Java(761)
     );
 
-    fn write<W: Write>(_content: Self::Content, _w: &mut W) -> Result<(), PacketWriteError> {
-        todo!("write")
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        write_move(content, MOVE_IDS, w)
     }
 
     fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
@@ -47,8 +86,8 @@ impl Packet for SetPlayerPositionAndRotation {
         Java(761)
     );
 
-    fn write<W: Write>(_content: Self::Content, _w: &mut W) -> Result<(), PacketWriteError> {
-        todo!("write")
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        write_move(content, MOVE_IDS, w)
     }
 
     fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
@@ -75,8 +114,8 @@ impl Packet for SetPlayerRotation {
         Java(761)
     );
 
-    fn write<W: Write>(_content: Self::Content, _w: &mut W) -> Result<(), PacketWriteError> {
-        todo!("write")
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        write_move(content, MOVE_IDS, w)
     }
 
     fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
@@ -0,0 +1,117 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+
+use md5::{Digest, Md5};
+use reqwest::StatusCode;
+use uuid::{Builder, Uuid};
+
+use crate::java::v_761::processor::server::{JavaResponse, LoginError};
+
+/// The default Mojang session server
+pub const MOJANG_SESSION_SERVER: &str =
+    "https://sessionserver.mojang.com/session/minecraft/hasJoined";
+
+pub type AuthFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<JavaResponse, LoginError>> + Send + 'a>>;
+
+/// Checks that a player is who they say they are.
+///
+/// Set on the [ServerClient](super::server::ServerClient) so every server can pick how players are authenticated
+pub trait Authenticator: Debug + Send + Sync {
+    /// If false the encryption request is skipped and [Authenticator::authenticate] is called with an empty server hash
+    fn online_mode(&self) -> bool {
+        true
+    }
+    /// Returns the profile of the player.
+    ///
+    /// The server hash is the Minecraft style hex digest of the server id, shared secret and public key
+    fn authenticate<'a>(&'a self, username: &'a str, server_hash: &'a str) -> AuthFuture<'a>;
+}
+
+/// Authenticates against the Mojang session server
+#[derive(Debug, Clone, Default)]
+pub struct MojangAuthenticator {
+    pub client: reqwest::Client,
+}
+
+impl Authenticator for MojangAuthenticator {
+    fn authenticate<'a>(&'a self, username: &'a str, server_hash: &'a str) -> AuthFuture<'a> {
+        Box::pin(has_joined(
+            &self.client,
+            MOJANG_SESSION_SERVER,
+            username,
+            server_hash,
+        ))
+    }
+}
+
+/// Authenticates against any server implementing the `hasJoined` endpoint.
+#[derive(Debug, Clone)]
+pub struct HttpAuthenticator {
+    pub client: reqwest::Client,
+    /// The full url of the `hasJoined` endpoint. `username` and `serverId` are added as query parameters
+    pub url: String,
+}
+
+impl HttpAuthenticator {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::default(),
+            url: url.into(),
+        }
+    }
+}
+
+impl Authenticator for HttpAuthenticator {
+    fn authenticate<'a>(&'a self, username: &'a str, server_hash: &'a str) -> AuthFuture<'a> {
+        Box::pin(has_joined(&self.client, &self.url, username, server_hash))
+    }
+}
+
+/// No authentication and no encryption. The UUID is generated from the username the same way vanilla does it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OfflineAuthenticator;
+
+impl OfflineAuthenticator {
+    /// Equivalent to Java's `UUID.nameUUIDFromBytes("OfflinePlayer:<name>")`
+    pub fn offline_uuid(username: &str) -> Uuid {
+        let hash = Md5::digest(format!("OfflinePlayer:{}", username).as_bytes());
+        Builder::from_md5_bytes(hash.into()).into_uuid()
+    }
+}
+
+impl Authenticator for OfflineAuthenticator {
+    fn online_mode(&self) -> bool {
+        false
+    }
+
+    fn authenticate<'a>(&'a self, username: &'a str, _: &'a str) -> AuthFuture<'a> {
+        Box::pin(async move {
+            Ok(JavaResponse {
+                id: Self::offline_uuid(username).simple().to_string(),
+                name: username.to_string(),
+                properties: vec![],
+            })
+        })
+    }
+}
+
+async fn has_joined(
+    client: &reqwest::Client,
+    url: &str,
+    username: &str,
+    server_hash: &str,
+) -> Result<JavaResponse, LoginError> {
+    let response = client
+        .get(url)
+        .query(&[("username", username), ("serverId", server_hash)])
+        .send()
+        .await?;
+    // The session server responds with No Content if the player has not joined
+    if response.status() == StatusCode::NO_CONTENT {
+        return Err(LoginError::NotAuthenticated(username.to_string()));
+    }
+    let response = response.error_for_status()?.text().await?;
+    Ok(serde_json::from_str::<JavaResponse>(&response)?)
+}
@@ -0,0 +1,457 @@
+//! Secure chat as of 1.19.3.
+//!
+//! A player starts a chat session with a key signed by Mojang. Every message is signed with that key
+//! over the sender, the session, the position in the message chain, the body and the last seen messages.
+//!
+//! Without `enforcesSecureChat` signatures are ignored and messages are sent as disguised chat
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use rsa::pkcs8::DecodePublicKey;
+use rsa::{PaddingScheme, PublicKey, RsaPublicKey};
+use serde::Deserialize;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::chat::Component;
+use crate::packets::play::client::chat::{
+    ChatTypeBinding, DisguisedChatMessage, FilterMask, PackedSignature, PlayerChatMessage,
+};
+use crate::packets::play::client::ClientBoundPlay;
+use crate::packets::play::server::chat::{
+    ChatCommand, ChatMessage, LastSeenUpdate, MessageAcknowledgment, PlayerSession,
+    ProfilePublicKey,
+};
+use crate::packets::play::MessageSignature;
+
+/// Lists the keys Mojang signs profile keys with
+pub const MOJANG_PUBLIC_KEYS: &str = "https://api.minecraftservices.com/publickeys";
+/// Vanilla disconnects players that did not acknowledge this many messages
+pub const MAX_PENDING_MESSAGES: usize = 4096;
+/// Included in the signature of every message
+const MESSAGE_SIGNATURE_VERSION: i32 = 1;
+
+#[derive(Debug, Error)]
+pub enum SignedChatError {
+    #[error("Failed to fetch the Mojang public keys: {0}")]
+    FetchKeys(#[from] reqwest::Error),
+    #[error("Invalid public key: {0}")]
+    InvalidKey(String),
+    #[error("The profile public key is not signed by Mojang")]
+    InvalidKeySignature,
+    #[error("The profile public key has expired")]
+    ExpiredKey,
+    #[error("Secure chat is enforced but the player has no chat session")]
+    MissingSession,
+    #[error("Secure chat is enforced but the message is not signed")]
+    Unsigned,
+    #[error("Invalid message signature")]
+    InvalidSignature,
+    #[error("Received chat messages out of order")]
+    OutOfOrder,
+    #[error("Invalid last seen messages")]
+    InvalidLastSeen,
+    #[error("Too many unacknowledged chat messages")]
+    TooManyPending,
+}
+
+/// The keys of the Mojang services that sign profile public keys
+#[derive(Debug, Clone, Default)]
+pub struct ServicesKeys {
+    pub player_certificate_keys: Vec<RsaPublicKey>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PublicKeysResponse {
+    player_certificate_keys: Vec<EncodedKey>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncodedKey {
+    /// Base64 of the DER encoded key
+    public_key: String,
+}
+
+impl ServicesKeys {
+    pub async fn fetch(client: &reqwest::Client) -> Result<Self, SignedChatError> {
+        let response = client
+            .get(MOJANG_PUBLIC_KEYS)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<PublicKeysResponse>()
+            .await?;
+        let keys = response
+            .player_certificate_keys
+            .into_iter()
+            .map(|key| {
+                STANDARD
+                    .decode(key.public_key)
+                    .map_err(|err| SignedChatError::InvalidKey(err.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_der(&keys)
+    }
+
+    /// Keys encoded as X.509 DER
+    pub fn from_der(keys: &[impl AsRef<[u8]>]) -> Result<Self, SignedChatError> {
+        let player_certificate_keys = keys
+            .iter()
+            .map(|key| decode_key(key.as_ref()))
+            .collect::<Result<_, _>>()?;
+        Ok(ServicesKeys {
+            player_certificate_keys,
+        })
+    }
+
+    /// SHA1withRSA with any of the keys
+    fn verify(&self, payload: &[u8], signature: &[u8]) -> bool {
+        let hash = Sha1::digest(payload);
+        self.player_certificate_keys.iter().any(|key| {
+            key.verify(PaddingScheme::new_pkcs1v15_sign::<Sha1>(), &hash, signature)
+                .is_ok()
+        })
+    }
+}
+
+fn decode_key(der: &[u8]) -> Result<RsaPublicKey, SignedChatError> {
+    RsaPublicKey::from_public_key_der(der)
+        .map_err(|err| SignedChatError::InvalidKey(err.to_string()))
+}
+
+fn now_millis(now: SystemTime) -> i64 {
+    now.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+/// The data Mojang signs. The player UUID, the expiry and the key
+pub fn profile_key_payload(player: Uuid, key: &ProfilePublicKey) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(24 + key.key.len());
+    payload.extend_from_slice(player.as_bytes());
+    payload.extend_from_slice(&key.expires_at.to_be_bytes());
+    payload.extend_from_slice(&key.key);
+    payload
+}
+
+/// The data a player signs for a chat message
+pub fn message_payload(
+    sender: Uuid,
+    session_id: Uuid,
+    index: i32,
+    salt: i64,
+    timestamp: i64,
+    message: &str,
+    last_seen: &[MessageSignature],
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(68 + message.len() + last_seen.len() * 256);
+    payload.extend_from_slice(&MESSAGE_SIGNATURE_VERSION.to_be_bytes());
+    payload.extend_from_slice(sender.as_bytes());
+    payload.extend_from_slice(session_id.as_bytes());
+    payload.extend_from_slice(&index.to_be_bytes());
+    payload.extend_from_slice(&salt.to_be_bytes());
+    // Signed in seconds. Sent in milliseconds
+    payload.extend_from_slice(&timestamp.div_euclid(1000).to_be_bytes());
+    payload.extend_from_slice(&(message.len() as i32).to_be_bytes());
+    payload.extend_from_slice(message.as_bytes());
+    payload.extend_from_slice(&(last_seen.len() as i32).to_be_bytes());
+    for signature in last_seen {
+        payload.extend_from_slice(&signature.0);
+    }
+    payload
+}
+
+/// A validated profile public key
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatSession {
+    pub session_id: Uuid,
+    pub public_key: RsaPublicKey,
+    /// Milliseconds since the unix epoch
+    pub expires_at: i64,
+}
+
+impl ChatSession {
+    /// Checks that Mojang signed the key for this player and that it has not expired
+    pub fn validate(
+        player: Uuid,
+        session: PlayerSession,
+        keys: &ServicesKeys,
+        now: SystemTime,
+    ) -> Result<Self, SignedChatError> {
+        let key = session.public_key;
+        if key.expires_at < now_millis(now) {
+            return Err(SignedChatError::ExpiredKey);
+        }
+        if !keys.verify(&profile_key_payload(player, &key), &key.key_signature) {
+            return Err(SignedChatError::InvalidKeySignature);
+        }
+        Ok(ChatSession {
+            session_id: session.session_id,
+            public_key: decode_key(&key.key)?,
+            expires_at: key.expires_at,
+        })
+    }
+
+    /// SHA256withRSA over [message_payload]
+    pub fn verify(&self, payload: &[u8], signature: &MessageSignature) -> bool {
+        self.public_key
+            .verify(
+                PaddingScheme::new_pkcs1v15_sign::<Sha256>(),
+                &Sha256::digest(payload),
+                &signature.0,
+            )
+            .is_ok()
+    }
+}
+
+/// A message sent to the client that it can acknowledge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TrackedMessage {
+    signature: MessageSignature,
+    pending: bool,
+}
+
+/// Follows the last seen messages of the client. The same way the vanilla server does
+#[derive(Debug, Clone, PartialEq)]
+pub struct LastSeenTracker {
+    tracked: Vec<Option<TrackedMessage>>,
+    last_pending: Option<MessageSignature>,
+}
+
+impl Default for LastSeenTracker {
+    fn default() -> Self {
+        Self {
+            tracked: vec![None; LastSeenUpdate::LAST_SEEN_COUNT],
+            last_pending: None,
+        }
+    }
+}
+
+impl LastSeenTracker {
+    /// Call for every signed message sent to the client
+    pub fn add_pending(&mut self, signature: MessageSignature) {
+        if self.last_pending != Some(signature) {
+            self.tracked.push(Some(TrackedMessage {
+                signature,
+                pending: true,
+            }));
+            self.last_pending = Some(signature);
+        }
+    }
+
+    pub fn tracked_count(&self) -> usize {
+        self.tracked.len()
+    }
+
+    /// Drops the messages the client no longer tracks
+    pub fn apply_offset(&mut self, offset: i32) -> Result<(), SignedChatError> {
+        let max = self.tracked.len() - LastSeenUpdate::LAST_SEEN_COUNT;
+        if offset < 0 || offset as usize > max {
+            return Err(SignedChatError::InvalidLastSeen);
+        }
+        self.tracked.drain(..offset as usize);
+        Ok(())
+    }
+
+    /// Returns the signatures the client signed its message with
+    pub fn apply_update(
+        &mut self,
+        update: LastSeenUpdate,
+    ) -> Result<Vec<MessageSignature>, SignedChatError> {
+        self.apply_offset(update.offset)?;
+        if update.acknowledged >> LastSeenUpdate::LAST_SEEN_COUNT != 0 {
+            return Err(SignedChatError::InvalidLastSeen);
+        }
+        let mut last_seen = Vec::with_capacity(update.acknowledged.count_ones() as usize);
+        for (index, tracked) in self.tracked[..LastSeenUpdate::LAST_SEEN_COUNT]
+            .iter_mut()
+            .enumerate()
+        {
+            if update.acknowledged & (1 << index) != 0 {
+                let message = tracked.as_mut().ok_or(SignedChatError::InvalidLastSeen)?;
+                message.pending = false;
+                last_seen.push(message.signature);
+            } else {
+                if matches!(tracked, Some(message) if !message.pending) {
+                    return Err(SignedChatError::InvalidLastSeen);
+                }
+                *tracked = None;
+            }
+        }
+        Ok(last_seen)
+    }
+}
+
+/// A chat message that passed validation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceivedMessage {
+    pub sender: Uuid,
+    pub index: i32,
+    /// None in unsigned mode
+    pub signature: Option<MessageSignature>,
+    pub message: String,
+    pub timestamp: i64,
+    pub salt: i64,
+    pub last_seen: Vec<MessageSignature>,
+}
+
+impl ReceivedMessage {
+    /// The packet sent to every receiver. Unsigned messages are sent as disguised chat
+    pub fn packet(&self, chat_type: ChatTypeBinding) -> ClientBoundPlay {
+        match self.signature {
+            Some(signature) => ClientBoundPlay::PlayerChat(PlayerChatMessage {
+                sender: self.sender,
+                index: self.index,
+                signature: Some(signature),
+                message: self.message.clone(),
+                timestamp: self.timestamp,
+                salt: self.salt,
+                previous_messages: self
+                    .last_seen
+                    .iter()
+                    .map(|signature| PackedSignature::Full(*signature))
+                    .collect(),
+                unsigned_content: None,
+                filter: FilterMask::PassThrough,
+                chat_type,
+            }),
+            None => ClientBoundPlay::DisguisedChat(DisguisedChatMessage {
+                message: Component::text(self.message.clone()),
+                chat_type,
+            }),
+        }
+    }
+}
+
+/// The chat state of one player
+#[derive(Debug, Clone)]
+pub struct PlayerChat {
+    pub player: Uuid,
+    pub enforces_secure_chat: bool,
+    pub session: Option<ChatSession>,
+    /// The index of the next message in the chain of the session
+    next_index: i32,
+    last_timestamp: i64,
+    last_seen: LastSeenTracker,
+}
+
+impl PlayerChat {
+    pub fn new(player: Uuid, enforces_secure_chat: bool) -> Self {
+        Self {
+            player,
+            enforces_secure_chat,
+            session: None,
+            next_index: 0,
+            last_timestamp: i64::MIN,
+            last_seen: LastSeenTracker::default(),
+        }
+    }
+
+    /// Validates the key and starts a new message chain
+    pub fn handle_session(
+        &mut self,
+        session: PlayerSession,
+        keys: &ServicesKeys,
+        now: SystemTime,
+    ) -> Result<(), SignedChatError> {
+        self.session = Some(ChatSession::validate(self.player, session, keys, now)?);
+        self.next_index = 0;
+        Ok(())
+    }
+
+    pub fn handle_acknowledgment(
+        &mut self,
+        acknowledgment: MessageAcknowledgment,
+    ) -> Result<(), SignedChatError> {
+        self.last_seen.apply_offset(acknowledgment.offset)
+    }
+
+    /// Call for every signed message sent to this player
+    pub fn track_sent(&mut self, signature: MessageSignature) -> Result<(), SignedChatError> {
+        self.last_seen.add_pending(signature);
+        if self.last_seen.tracked_count() > MAX_PENDING_MESSAGES {
+            return Err(SignedChatError::TooManyPending);
+        }
+        Ok(())
+    }
+
+    fn check_order(&mut self, timestamp: i64) -> Result<(), SignedChatError> {
+        if timestamp < self.last_timestamp {
+            return Err(SignedChatError::OutOfOrder);
+        }
+        self.last_timestamp = timestamp;
+        Ok(())
+    }
+
+    pub fn handle_message(
+        &mut self,
+        message: ChatMessage,
+    ) -> Result<ReceivedMessage, SignedChatError> {
+        self.check_order(message.timestamp)?;
+        let last_seen = self.last_seen.apply_update(message.last_seen)?;
+        if !self.enforces_secure_chat {
+            return Ok(ReceivedMessage {
+                sender: self.player,
+                index: 0,
+                signature: None,
+                message: message.message,
+                timestamp: message.timestamp,
+                salt: message.salt,
+                last_seen: vec![],
+            });
+        }
+        let session = self
+            .session
+            .as_ref()
+            .ok_or(SignedChatError::MissingSession)?;
+        let signature = message.signature.ok_or(SignedChatError::Unsigned)?;
+        if session.expires_at < message.timestamp {
+            return Err(SignedChatError::ExpiredKey);
+        }
+        let index = self.next_index;
+        let payload = message_payload(
+            self.player,
+            session.session_id,
+            index,
+            message.salt,
+            message.timestamp,
+            &message.message,
+            &last_seen,
+        );
+        if !session.verify(&payload, &signature) {
+            return Err(SignedChatError::InvalidSignature);
+        }
+        self.next_index += 1;
+        Ok(ReceivedMessage {
+            sender: self.player,
+            index,
+            signature: Some(signature),
+            message: message.message,
+            timestamp: message.timestamp,
+            salt: message.salt,
+            last_seen,
+        })
+    }
+
+    /// Checks the order and the last seen messages.
+    ///
+    /// The argument signatures are not verified. That needs the command tree to find the signed arguments
+    pub fn handle_command(&mut self, command: &ChatCommand) -> Result<(), SignedChatError> {
+        self.check_order(command.timestamp)?;
+        self.last_seen.apply_update(command.last_seen)?;
+        if self.enforces_secure_chat
+            && self.session.is_none()
+            && !command.argument_signatures.is_empty()
+        {
+            return Err(SignedChatError::MissingSession);
+        }
+        // Every signed argument is a link in the message chain
+        self.next_index += command.argument_signatures.len() as i32;
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+//! Moves a player into another dimension.
+//!
+//! The client drops every chunk and entity on respawn. So the abilities, the position and the chunks are sent again
+use std::io::Write;
+
+use crate::packets::play::client::chunk::{ChunkDataAndLight, SetCenterChunk, UnloadChunk};
+use crate::packets::play::client::player_info::SyncPlayerPosition;
+use crate::packets::play::client::respawn::{Respawn, SetDefaultSpawnPosition};
+use crate::packets::play::client::{AbilitiesPacket, ClientBoundPlay};
+use crate::{PacketWriteError, PacketWriter};
+
+/// The chunks within the view distance of the center. Nearest first
+pub fn chunks_in_view(center: SetCenterChunk, view_distance: i32) -> Vec<(i32, i32)> {
+    let mut chunks =
+        Vec::with_capacity(((view_distance * 2 + 1) * (view_distance * 2 + 1)) as usize);
+    for x in -view_distance..=view_distance {
+        for z in -view_distance..=view_distance {
+            chunks.push((center.chunk_x + x, center.chunk_z + z));
+        }
+    }
+    chunks.sort_by_key(|(x, z)| {
+        let (x, z) = (x - center.chunk_x, z - center.chunk_z);
+        x * x + z * z
+    });
+    chunks
+}
+
+/// The chunks that left the view after the center moved
+pub fn chunks_to_unload(
+    old: SetCenterChunk,
+    new: SetCenterChunk,
+    view_distance: i32,
+) -> Vec<UnloadChunk> {
+    chunks_in_view(old, view_distance)
+        .into_iter()
+        .filter(|(x, z)| {
+            (x - new.chunk_x).abs() > view_distance || (z - new.chunk_z).abs() > view_distance
+        })
+        .map(|(chunk_x, chunk_z)| UnloadChunk { chunk_x, chunk_z })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DimensionChange {
+    pub respawn: Respawn,
+    pub abilities: AbilitiesPacket,
+    pub position: SyncPlayerPosition,
+    pub spawn_position: SetDefaultSpawnPosition,
+    pub view_distance: i32,
+}
+
+impl DimensionChange {
+    pub fn center_chunk(&self) -> SetCenterChunk {
+        SetCenterChunk::containing(self.position.x, self.position.z)
+    }
+
+    /// Everything before the chunks. In the order vanilla sends them
+    pub fn packets(self) -> Vec<ClientBoundPlay> {
+        let center = self.center_chunk();
+        vec![
+            self.respawn.into(),
+            self.abilities.into(),
+            self.position.into(),
+            self.spawn_position.into(),
+            center.into(),
+        ]
+    }
+
+    /// Sends the packets followed by the chunks in view. Chunks without data are skipped
+    pub fn send<P, W>(
+        self,
+        packet_writer: &mut P,
+        writer: &mut W,
+        mut chunk: impl FnMut(i32, i32) -> Option<ChunkDataAndLight>,
+    ) -> Result<(), PacketWriteError>
+    where
+        P: PacketWriter<PacketOut = ClientBoundPlay>,
+        W: Write,
+    {
+        let chunks = chunks_in_view(self.center_chunk(), self.view_distance);
+        for packet in self.packets() {
+            packet_writer.send_packet(packet, writer)?;
+        }
+        for (x, z) in chunks {
+            if let Some(chunk) = chunk(x, z) {
+                packet_writer.send_packet(chunk, writer)?;
+            }
+        }
+        Ok(())
+    }
+}
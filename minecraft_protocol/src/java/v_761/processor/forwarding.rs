@@ -0,0 +1,158 @@
+//! Player info forwarded by a proxy.
+//!
+//! - Velocity (modern forwarding) answers a login plugin request with the player info signed by a shared secret.
+//! - BungeeCord (legacy forwarding) appends the player info to [HandShake::server_address] separated by `\0`
+use std::io::{Cursor, Read};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::java::handshake::HandShake;
+use crate::java::v_761::processor::server::{JavaResponse, LoginError};
+use crate::limits;
+use crate::packets::login::Property;
+
+/// The login plugin channel Velocity listens on
+pub const VELOCITY_CHANNEL: &str = "velocity:player_info";
+/// `MODERN_DEFAULT`. Does not contain the chat signing key
+pub const VELOCITY_FORWARDING_VERSION: u8 = 1;
+
+/// How the player info reaches the server
+#[derive(Debug, Clone, Default)]
+pub enum ForwardingMode {
+    /// The player connects directly
+    #[default]
+    None,
+    Velocity {
+        secret: Vec<u8>,
+    },
+    BungeeCord,
+}
+
+/// The player info sent by Velocity
+#[derive(Debug, Clone, PartialEq)]
+pub struct VelocityForwarding {
+    pub version: i32,
+    pub address: String,
+    pub uuid: Uuid,
+    pub username: String,
+    pub properties: Vec<Property>,
+}
+
+impl VelocityForwarding {
+    /// Verifies the signature and reads the data of the `velocity:player_info` response
+    pub fn read(secret: &[u8], data: &[u8]) -> Result<Self, LoginError> {
+        if data.len() < 32 {
+            return Err(LoginError::Forwarding(
+                "Velocity response is missing the signature".to_string(),
+            ));
+        }
+        let (signature, content) = data.split_at(32);
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+        mac.update(content);
+        // Constant time. So the time taken does not depend on the signature
+        mac.verify_slice(signature).map_err(|_| {
+            LoginError::Forwarding(
+                "Velocity response has an invalid signature. Check the forwarding secret"
+                    .to_string(),
+            )
+        })?;
+        let mut reader = Cursor::new(content);
+        let version = VarInt::read(&mut reader)?.0;
+        if version < VELOCITY_FORWARDING_VERSION as i32 {
+            return Err(LoginError::Forwarding(format!(
+                "Unsupported Velocity forwarding version {}",
+                version
+            )));
+        }
+        // Newer versions only add data after the properties
+        let address = String::read(&mut reader)?;
+        let uuid = Uuid::read(&mut reader)?;
+        let username = String::read(&mut reader)?;
+        let properties = read_properties(&mut reader)?;
+        Ok(Self {
+            version,
+            address,
+            uuid,
+            username,
+            properties,
+        })
+    }
+}
+
+impl From<VelocityForwarding> for JavaResponse {
+    fn from(value: VelocityForwarding) -> Self {
+        JavaResponse {
+            id: value.uuid.simple().to_string(),
+            name: value.username,
+            properties: value.properties,
+        }
+    }
+}
+
+/// The player info sent by BungeeCord. The username is sent in the login start like normal
+#[derive(Debug, Clone, PartialEq)]
+pub struct BungeeCordForwarding {
+    pub host: String,
+    pub address: String,
+    pub uuid: Uuid,
+    pub properties: Vec<Property>,
+}
+
+impl BungeeCordForwarding {
+    /// `host\0address\0uuid\0properties` The properties are optional and sent as JSON
+    pub fn read(handshake: &HandShake) -> Result<Self, LoginError> {
+        let mut parts = handshake.server_address.split('\0');
+        let (Some(host), Some(address), Some(uuid)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(LoginError::Forwarding(
+                "Handshake does not contain BungeeCord forwarding. Is ip_forward enabled?"
+                    .to_string(),
+            ));
+        };
+        let uuid = Uuid::parse_str(uuid)
+            .map_err(|err| LoginError::Forwarding(format!("Invalid forwarded UUID: {}", err)))?;
+        let properties = match parts.next() {
+            Some(properties) => serde_json::from_str(properties)?,
+            None => vec![],
+        };
+        Ok(Self {
+            host: host.to_string(),
+            address: address.to_string(),
+            uuid,
+            properties,
+        })
+    }
+
+    pub fn into_response(self, username: String) -> JavaResponse {
+        JavaResponse {
+            id: self.uuid.simple().to_string(),
+            name: username,
+            properties: self.properties,
+        }
+    }
+}
+
+fn read_properties<R: Read>(reader: &mut R) -> Result<Vec<Property>, LoginError> {
+    let len = limits::read_array_length(reader)?;
+    let mut properties = limits::with_capacity(len);
+    for _ in 0..len {
+        let name = String::read(reader)?;
+        let value = String::read(reader)?;
+        let signature = if bool::read(reader)? {
+            Some(String::read(reader)?)
+        } else {
+            None
+        };
+        properties.push(Property {
+            name,
+            value,
+            signature,
+        });
+    }
+    Ok(properties)
+}
@@ -0,0 +1,148 @@
+//! Keeps a play connection alive.
+//!
+//! The server sends a random id every 15 seconds and the client echoes it back.
+//! The time until the echo is the latency shown in the player list
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+
+use crate::chat::Component;
+use crate::packets::play::server::ServerBoundPlay;
+use crate::packets::play::KeepAlive;
+use crate::{PacketWriteError, PacketWriter};
+
+/// How often a keep alive is sent
+pub const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+/// How long the client can go without answering
+pub const KEEP_ALIVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Where the current time comes from. So the timing can be tested without waiting
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum KeepAliveError {
+    #[error("The client did not answer a keep alive in time")]
+    Timeout,
+    #[error("The client answered with unknown keep alive id {0}")]
+    UnknownId(i64),
+    #[error(transparent)]
+    Write(#[from] PacketWriteError),
+}
+
+impl KeepAliveError {
+    /// What the client is disconnected with. Vanilla times out for a wrong id as well
+    pub fn disconnect_reason(&self) -> Component {
+        Component::translatable("disconnect.timeout", vec![])
+    }
+}
+
+#[derive(Debug)]
+pub struct KeepAliveManager<C: Clock = SystemClock> {
+    clock: C,
+    /// The id and when it was sent
+    pending: Option<(i64, Instant)>,
+    last_sent: Instant,
+    last_response: Instant,
+    latency: Option<Duration>,
+}
+
+impl Default for KeepAliveManager {
+    fn default() -> Self {
+        Self::new(SystemClock)
+    }
+}
+
+impl<C: Clock> KeepAliveManager<C> {
+    /// The first keep alive is sent one interval after creation
+    pub fn new(clock: C) -> Self {
+        let now = clock.now();
+        Self {
+            clock,
+            pending: None,
+            last_sent: now,
+            last_response: now,
+            latency: None,
+        }
+    }
+
+    /// Sends a keep alive if one is due
+    ///
+    /// Call this regularly. Such as every tick
+    pub fn tick<P: PacketWriter, W: Write>(
+        &mut self,
+        packet_writer: &mut P,
+        writer: &mut W,
+    ) -> Result<(), KeepAliveError>
+    where
+        KeepAlive: Into<P::PacketOut>,
+    {
+        let now = self.clock.now();
+        if now.duration_since(self.last_response) >= KEEP_ALIVE_TIMEOUT {
+            return Err(KeepAliveError::Timeout);
+        }
+        if self.pending.is_some() || now.duration_since(self.last_sent) < KEEP_ALIVE_INTERVAL {
+            return Ok(());
+        }
+        let id = rand::random::<i64>();
+        packet_writer.send_packet(KeepAlive(id), writer)?;
+        self.pending = Some((id, now));
+        self.last_sent = now;
+        Ok(())
+    }
+
+    /// Handles the echo of a keep alive
+    pub fn handle_response(&mut self, packet: KeepAlive) -> Result<(), KeepAliveError> {
+        match self.pending {
+            Some((id, sent)) if id == packet.0 => {
+                let now = self.clock.now();
+                let sample = now.duration_since(sent);
+                // Smoothed the same way as vanilla
+                self.latency = Some(match self.latency {
+                    Some(latency) => (latency * 3 + sample) / 4,
+                    None => sample,
+                });
+                self.pending = None;
+                self.last_response = now;
+                Ok(())
+            }
+            _ => Err(KeepAliveError::UnknownId(packet.0)),
+        }
+    }
+
+    /// Passes keep alive packets to [KeepAliveManager::handle_response] and ignores the rest
+    pub fn handle_packet(&mut self, packet: &ServerBoundPlay) -> Result<(), KeepAliveError> {
+        match packet {
+            ServerBoundPlay::KeepAlive(keep_alive) => self.handle_response(keep_alive.clone()),
+            _ => Ok(()),
+        }
+    }
+
+    /// The id the client has not answered yet
+    pub fn pending_id(&self) -> Option<i64> {
+        self.pending.map(|(id, _)| id)
+    }
+
+    /// None until the first answer
+    pub fn latency(&self) -> Option<Duration> {
+        self.latency
+    }
+
+    /// The latency for the player list. In milliseconds
+    pub fn latency_millis(&self) -> i32 {
+        self.latency.map_or(0, |latency| {
+            latency.as_millis().min(i32::MAX as u128) as i32
+        })
+    }
+}
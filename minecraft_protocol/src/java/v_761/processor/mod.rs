@@ -1 +1,8 @@
+pub mod authenticator;
+pub mod chat;
+pub mod dimension;
+pub mod forwarding;
+pub mod keep_alive;
+pub mod plugin_channels;
+pub mod resource_pack;
 pub mod server;
@@ -0,0 +1,242 @@
+//! Plugin message channels.
+//!
+//! Clients announce the channels they listen on with `minecraft:register` and `minecraft:unregister`.
+//! Handlers subscribe to a channel in the [ChannelRegistry] and are called with the payload
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::io;
+
+use thiserror::Error;
+
+use crate::data::PacketDataType;
+use crate::packets::play::PlayPluginMessage;
+
+pub const BRAND: &str = "minecraft:brand";
+pub const REGISTER: &str = "minecraft:register";
+pub const UNREGISTER: &str = "minecraft:unregister";
+/// The largest payload the client sends
+pub const MAX_PAYLOAD_SIZE: usize = 32767;
+/// The most channels a client can register
+pub const MAX_CLIENT_CHANNELS: usize = 128;
+
+#[derive(Debug, Error)]
+pub enum PluginMessageError {
+    #[error("Plugin message payload of {0} bytes is too large")]
+    TooLarge(usize),
+    #[error("Invalid channel name {0}")]
+    InvalidChannel(String),
+    #[error("The client registered more than {} channels", MAX_CLIENT_CHANNELS)]
+    TooManyChannels,
+    #[error("Invalid payload: {0}")]
+    InvalidPayload(#[from] io::Error),
+}
+
+/// A channel name is an identifier. Such as `minecraft:brand`
+pub fn is_valid_channel(channel: &str) -> bool {
+    let Some((namespace, path)) = channel.split_once(':') else {
+        return false;
+    };
+    let valid = |c: char, extra: &str| {
+        c.is_ascii_lowercase() || c.is_ascii_digit() || "_-.".contains(c) || extra.contains(c)
+    };
+    !namespace.is_empty()
+        && !path.is_empty()
+        && namespace.chars().all(|c| valid(c, ""))
+        && path.chars().all(|c| valid(c, "/"))
+}
+
+/// Converts the payload of a channel to a message and back
+pub trait ChannelCodec {
+    type Message;
+
+    const CHANNEL: &'static str;
+
+    fn decode(data: &[u8]) -> Result<Self::Message, PluginMessageError>;
+
+    fn encode(message: Self::Message) -> Vec<u8>;
+
+    fn message(message: Self::Message) -> PlayPluginMessage {
+        PlayPluginMessage {
+            id: Cow::Borrowed(Self::CHANNEL),
+            data: Self::encode(message),
+        }
+    }
+}
+
+/// The client or server brand. Such as `vanilla`
+pub struct BrandCodec;
+
+impl ChannelCodec for BrandCodec {
+    type Message = String;
+
+    const CHANNEL: &'static str = BRAND;
+
+    fn decode(mut data: &[u8]) -> Result<Self::Message, PluginMessageError> {
+        Ok(String::read(&mut data)?)
+    }
+
+    fn encode(message: Self::Message) -> Vec<u8> {
+        let mut data = Vec::with_capacity(message.len() + 2);
+        message
+            .write(&mut data)
+            .expect("Writing to a Vec does not fail");
+        data
+    }
+}
+
+/// Channel names separated by NUL
+pub struct RegisterCodec;
+
+/// Same payload as [RegisterCodec]
+pub struct UnregisterCodec;
+
+fn decode_channels(data: &[u8]) -> Result<Vec<String>, PluginMessageError> {
+    data.split(|byte| *byte == 0)
+        .filter(|channel| !channel.is_empty())
+        .map(|channel| {
+            let channel = String::from_utf8_lossy(channel);
+            if is_valid_channel(&channel) {
+                Ok(channel.into_owned())
+            } else {
+                Err(PluginMessageError::InvalidChannel(channel.into_owned()))
+            }
+        })
+        .collect()
+}
+
+fn encode_channels(channels: Vec<String>) -> Vec<u8> {
+    channels.join("\0").into_bytes()
+}
+
+impl ChannelCodec for RegisterCodec {
+    type Message = Vec<String>;
+
+    const CHANNEL: &'static str = REGISTER;
+
+    fn decode(data: &[u8]) -> Result<Self::Message, PluginMessageError> {
+        decode_channels(data)
+    }
+
+    fn encode(message: Self::Message) -> Vec<u8> {
+        encode_channels(message)
+    }
+}
+
+impl ChannelCodec for UnregisterCodec {
+    type Message = Vec<String>;
+
+    const CHANNEL: &'static str = UNREGISTER;
+
+    fn decode(data: &[u8]) -> Result<Self::Message, PluginMessageError> {
+        decode_channels(data)
+    }
+
+    fn encode(message: Self::Message) -> Vec<u8> {
+        encode_channels(message)
+    }
+}
+
+/// What a client told the server about its channels
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientChannels {
+    pub channels: HashSet<String>,
+    pub brand: Option<String>,
+}
+
+impl ClientChannels {
+    pub fn supports(&self, channel: &str) -> bool {
+        self.channels.contains(channel)
+    }
+
+    /// None if the client did not register the channel
+    pub fn message<C: ChannelCodec>(&self, message: C::Message) -> Option<PlayPluginMessage> {
+        self.supports(C::CHANNEL).then(|| C::message(message))
+    }
+}
+
+type Handler<T> = Box<dyn Fn(&mut T, &[u8]) -> Result<(), PluginMessageError> + Send + Sync>;
+
+/// The channels the server listens on. `T` is passed to the handlers. Such as the player
+pub struct ChannelRegistry<T> {
+    handlers: HashMap<String, Handler<T>>,
+}
+
+impl<T> Default for ChannelRegistry<T> {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+}
+
+impl<T> ChannelRegistry<T> {
+    /// Replaces the handler of the channel
+    pub fn subscribe(
+        &mut self,
+        channel: impl Into<String>,
+        handler: impl Fn(&mut T, &[u8]) -> Result<(), PluginMessageError> + Send + Sync + 'static,
+    ) -> Result<(), PluginMessageError> {
+        let channel = channel.into();
+        if !is_valid_channel(&channel) {
+            return Err(PluginMessageError::InvalidChannel(channel));
+        }
+        self.handlers.insert(channel, Box::new(handler));
+        Ok(())
+    }
+
+    /// Subscribes with the decoded message
+    pub fn subscribe_typed<C: ChannelCodec + 'static>(
+        &mut self,
+        handler: impl Fn(&mut T, C::Message) -> Result<(), PluginMessageError> + Send + Sync + 'static,
+    ) -> Result<(), PluginMessageError> {
+        self.subscribe(C::CHANNEL, move |context, data| {
+            handler(context, C::decode(data)?)
+        })
+    }
+
+    pub fn unsubscribe(&mut self, channel: &str) -> bool {
+        self.handlers.remove(channel).is_some()
+    }
+
+    /// Announces the channels the server listens on
+    pub fn register_message(&self) -> PlayPluginMessage {
+        let mut channels: Vec<String> = self.handlers.keys().cloned().collect();
+        channels.sort();
+        RegisterCodec::message(channels)
+    }
+
+    /// Tracks register, unregister and brand messages then calls the handler of the channel
+    ///
+    /// Messages on channels without a handler are ignored
+    pub fn handle(
+        &self,
+        client: &mut ClientChannels,
+        context: &mut T,
+        message: &PlayPluginMessage,
+    ) -> Result<(), PluginMessageError> {
+        if message.data.len() > MAX_PAYLOAD_SIZE {
+            return Err(PluginMessageError::TooLarge(message.data.len()));
+        }
+        match message.id.as_ref() {
+            REGISTER => {
+                for channel in RegisterCodec::decode(&message.data)? {
+                    if client.channels.len() >= MAX_CLIENT_CHANNELS {
+                        return Err(PluginMessageError::TooManyChannels);
+                    }
+                    client.channels.insert(channel);
+                }
+            }
+            UNREGISTER => {
+                for channel in UnregisterCodec::decode(&message.data)? {
+                    client.channels.remove(&channel);
+                }
+            }
+            BRAND => client.brand = Some(BrandCodec::decode(&message.data)?),
+            _ => {}
+        }
+        match self.handlers.get(message.id.as_ref()) {
+            Some(handler) => handler(context, &message.data),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Sends a resource pack and waits for the answer.
+//!
+//! The client answers with accepted followed by loaded or failed. Or with declined.
+//! The [ResourcePackPolicy] decides what happens when the pack is not applied
+use thiserror::Error;
+
+use crate::chat::Component;
+use crate::packets::play::client::ResourcePack;
+use crate::packets::play::server::{ResourcePackStatus, ServerBoundPlay};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourcePackPolicy {
+    /// Disconnect the client if it declines a forced pack
+    pub kick_on_decline: bool,
+    /// How many times the pack is sent again after a failed download
+    pub download_retries: u8,
+    /// Disconnect the client if a forced pack still fails after the retries
+    pub kick_on_failure: bool,
+}
+
+impl Default for ResourcePackPolicy {
+    fn default() -> Self {
+        Self {
+            kick_on_decline: true,
+            download_retries: 2,
+            kick_on_failure: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourcePackState {
+    NotSent,
+    /// Waiting for the client to accept or decline
+    Sent,
+    /// Waiting for the download
+    Accepted,
+    Loaded,
+    Declined,
+    Failed,
+}
+
+impl ResourcePackState {
+    /// No more answers are expected
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            ResourcePackState::Loaded | ResourcePackState::Declined | ResourcePackState::Failed
+        )
+    }
+}
+
+/// What the connection should do after an answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResourcePackAction {
+    /// Wait for the next answer
+    Wait,
+    /// Send the pack again
+    Resend(ResourcePack),
+    /// Disconnect the client with the reason
+    Kick(Component),
+    /// The pack is loaded or the client may play without it. See [ResourcePackSession::state]
+    Done,
+}
+
+#[derive(Debug, Error)]
+pub enum ResourcePackError {
+    #[error("The client sent {0:?} while the resource pack was {1:?}")]
+    UnexpectedStatus(ResourcePackStatus, ResourcePackState),
+}
+
+/// The resource pack of one connection
+#[derive(Debug, Clone)]
+pub struct ResourcePackSession {
+    pack: ResourcePack,
+    policy: ResourcePackPolicy,
+    state: ResourcePackState,
+    retries: u8,
+}
+
+impl ResourcePackSession {
+    pub fn new(pack: ResourcePack, policy: ResourcePackPolicy) -> Self {
+        Self {
+            pack,
+            policy,
+            state: ResourcePackState::NotSent,
+            retries: 0,
+        }
+    }
+
+    /// The packet to send to the client
+    pub fn start(&mut self) -> ResourcePack {
+        self.state = ResourcePackState::Sent;
+        self.pack.clone()
+    }
+
+    pub fn handle_status(
+        &mut self,
+        status: ResourcePackStatus,
+    ) -> Result<ResourcePackAction, ResourcePackError> {
+        let action = match (self.state, status) {
+            (ResourcePackState::Sent, ResourcePackStatus::Accepted) => {
+                self.state = ResourcePackState::Accepted;
+                ResourcePackAction::Wait
+            }
+            (ResourcePackState::Sent, ResourcePackStatus::Declined) => {
+                self.state = ResourcePackState::Declined;
+                if self.pack.forced && self.policy.kick_on_decline {
+                    ResourcePackAction::Kick(Component::translatable(
+                        "multiplayer.requiredTexturePrompt.disconnect",
+                        vec![],
+                    ))
+                } else {
+                    ResourcePackAction::Done
+                }
+            }
+            (ResourcePackState::Accepted, ResourcePackStatus::SuccessfullyLoaded) => {
+                self.state = ResourcePackState::Loaded;
+                ResourcePackAction::Done
+            }
+            // Older clients fail without accepting first
+            (
+                ResourcePackState::Sent | ResourcePackState::Accepted,
+                ResourcePackStatus::FailedDownload,
+            ) => {
+                if self.retries < self.policy.download_retries {
+                    self.retries += 1;
+                    ResourcePackAction::Resend(self.start())
+                } else {
+                    self.state = ResourcePackState::Failed;
+                    if self.pack.forced && self.policy.kick_on_failure {
+                        ResourcePackAction::Kick(Component::translatable(
+                            "multiplayer.texturePrompt.failure.line1",
+                            vec![],
+                        ))
+                    } else {
+                        ResourcePackAction::Done
+                    }
+                }
+            }
+            (state, status) => return Err(ResourcePackError::UnexpectedStatus(status, state)),
+        };
+        Ok(action)
+    }
+
+    /// Passes resource pack statuses to [ResourcePackSession::handle_status]. Other packets are ignored
+    pub fn handle_packet(
+        &mut self,
+        packet: &ServerBoundPlay,
+    ) -> Result<ResourcePackAction, ResourcePackError> {
+        match packet {
+            ServerBoundPlay::ResourcePackStatus(status) => self.handle_status(*status),
+            _ => Ok(ResourcePackAction::Wait),
+        }
+    }
+
+    pub fn state(&self) -> ResourcePackState {
+        self.state
+    }
+
+    /// How many times the pack was sent again
+    pub fn retries(&self) -> u8 {
+        self.retries
+    }
+
+    pub fn pack(&self) -> &ResourcePack {
+        &self.pack
+    }
+}
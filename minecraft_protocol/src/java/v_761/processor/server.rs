@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use aes::cipher::KeyIvInit;
 use log::{debug, error, warn};
 use num_bigint::BigInt;
@@ -7,9 +9,18 @@ use serde::Deserialize;
 use sha1::{Digest, Sha1};
 use thiserror::Error;
 
-use crate::packets::login::client_bound::ClientBoundEncryptionRequest;
+use crate::data::var_int::VarInt;
+use crate::java::handshake::HandShake;
+pub use crate::java::v_761::processor::authenticator::{
+    Authenticator, HttpAuthenticator, MojangAuthenticator, OfflineAuthenticator,
+};
+pub use crate::java::v_761::processor::forwarding::{
+    BungeeCordForwarding, ForwardingMode, VelocityForwarding,
+};
+use crate::java::v_761::processor::forwarding::{VELOCITY_CHANNEL, VELOCITY_FORWARDING_VERSION};
+use crate::packets::login::client_bound::{ClientBoundEncryptionRequest, ClientBoundPluginRequest};
 use crate::packets::login::{ClientBoundLogin, Property, ServerBoundLogin};
-use crate::{AsyncStreamCipher, Decryptor, Encryptor};
+use crate::{Decryptor, Encryptor};
 
 #[derive(Debug, Error)]
 pub enum LoginError {
@@ -23,14 +34,22 @@ pub enum LoginError {
     InvalidLength,
     #[error("Json Error: {0}")]
     JsonError(#[from] serde_json::Error),
+    #[error("{0} has not joined through the session server")]
+    NotAuthenticated(String),
+    #[error("Invalid forwarded player info: {0}")]
+    Forwarding(String),
+    #[error("Failed to read forwarded player info: {0}")]
+    IoError(#[from] std::io::Error),
 }
 
 #[derive(Debug, Clone)]
 pub struct ServerClient {
     pub server_id: String,
-    pub reqwest_client: reqwest::Client,
+    pub authenticator: Arc<dyn Authenticator>,
     pub key: RsaPrivateKey,
     pub key_encoded: Vec<u8>,
+    /// If a proxy forwards the player info. The proxy handles authentication and encryption
+    pub forwarding: ForwardingMode,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -41,9 +60,14 @@ pub enum LoginState {
         username: String,
         random_token: [u8; 16],
     },
+    VelocityRequested {
+        message_id: i32,
+    },
+    /// The handshake contained the player info. Waiting on the login start for the username
+    BungeeCordForwarded(BungeeCordForwarding),
     Completed {
-        encryptor: Encryptor,
-        decryptor: Decryptor,
+        /// None if the [Authenticator] is not in online mode or the player is forwarded by a proxy
+        cipher: Option<(Encryptor, Decryptor)>,
         data: JavaResponse,
     },
 }
@@ -55,12 +79,82 @@ pub struct JavaResponse {
     pub properties: Vec<Property>,
 }
 
+impl LoginState {
+    /// The state to start the login in.
+    ///
+    /// With BungeeCord forwarding the player info is read from the handshake
+    pub fn from_handshake(
+        server_client: &ServerClient,
+        handshake: &HandShake,
+    ) -> Result<Self, LoginError> {
+        match server_client.forwarding {
+            ForwardingMode::BungeeCord => Ok(LoginState::BungeeCordForwarded(
+                BungeeCordForwarding::read(handshake)?,
+            )),
+            _ => Ok(LoginState::Pending),
+        }
+    }
+}
+
+/// The Minecraft style hex digest sent to the session server as `serverId`
+pub fn server_hash(server_id: &str, shared_secret: &[u8], public_key: &[u8]) -> String {
+    let mut hasher = Sha1::default();
+    hasher.update(server_id.as_bytes());
+    hasher.update(shared_secret);
+    hasher.update(public_key);
+    let bigint = BigInt::from_signed_bytes_be(hasher.finalize().as_slice());
+    format!("{:x}", bigint)
+}
+
 pub async fn handle(
     server_client: &ServerClient,
     login: ServerBoundLogin,
     login_state: &mut LoginState,
 ) -> Result<Option<ClientBoundLogin>, LoginError> {
     match (login, &login_state) {
+        (ServerBoundLogin::LoginStart(_), LoginState::Pending)
+            if matches!(server_client.forwarding, ForwardingMode::Velocity { .. }) =>
+        {
+            debug!("Requesting Velocity player info");
+            let message_id: i32 = random::<u16>() as i32;
+            *login_state = LoginState::VelocityRequested { message_id };
+            return Ok(Some(ClientBoundLogin::LoginPluginRequest(
+                ClientBoundPluginRequest {
+                    message_id: VarInt(message_id),
+                    channel: VELOCITY_CHANNEL.to_string(),
+                    data: vec![VELOCITY_FORWARDING_VERSION],
+                },
+            )));
+        }
+        (ServerBoundLogin::LoginStart(start), LoginState::BungeeCordForwarded(forwarding)) => {
+            *login_state = LoginState::Completed {
+                cipher: None,
+                data: forwarding.clone().into_response(start.name),
+            };
+            debug!("BungeeCord login completed");
+            return Ok(None);
+        }
+        (ServerBoundLogin::LoginStart(_), LoginState::Pending)
+            if matches!(server_client.forwarding, ForwardingMode::BungeeCord) =>
+        {
+            return Err(LoginError::Forwarding(
+                "The login state was not created from the handshake".to_string(),
+            ));
+        }
+        (ServerBoundLogin::LoginStart(start), LoginState::Pending)
+            if !server_client.authenticator.online_mode() =>
+        {
+            let response = server_client
+                .authenticator
+                .authenticate(&start.name, "")
+                .await?;
+            *login_state = LoginState::Completed {
+                cipher: None,
+                data: response,
+            };
+            debug!("Offline login completed");
+            return Ok(None);
+        }
         (ServerBoundLogin::LoginStart(start), LoginState::Pending) => {
             debug!("Encryption requested");
             let bytes: [u8; 16] = random();
@@ -77,7 +171,7 @@ pub async fn handle(
             return Ok(Some(ClientBoundLogin::EncryptionRequest(packet)));
         }
         (
-            ServerBoundLogin::EncryptionResponse(mut enc),
+            ServerBoundLogin::EncryptionResponse(enc),
             LoginState::EncryptionRequested {
                 username,
                 random_token,
@@ -101,36 +195,59 @@ pub async fn handle(
             let decryptor = Decryptor::new_from_slices(&shared_sec, &shared_sec)
                 .map_err(|_| LoginError::InvalidLength)?;
 
-            decryptor.clone().decrypt(&mut enc.verify_token);
-            if enc.verify_token != *random_token {
+            // The verify token is encrypted with the public key. Not the shared secret
+            let verify_token = server_client
+                .key
+                .decrypt(PaddingScheme::PKCS1v15Encrypt, &enc.verify_token)
+                .map_err(|err| {
+                    LoginError::Other(format!("Failed to decrypt verify token: {}", err))
+                })?;
+            if verify_token != *random_token {
                 return Err(LoginError::Other(format!(
                     "Got {:?} but expected {:?}",
-                    enc.verify_token, random_token
+                    verify_token, random_token
                 )));
             }
 
-            let mut hasher = Sha1::default();
-            hasher.update(b"");
-            hasher.update(&shared_sec.as_slice()[0..16]);
-            hasher.update(&*server_client.key_encoded);
-            let bigint = BigInt::from_signed_bytes_be(hasher.finalize().as_slice());
-            let url = format!(
-                "https://sessionserver.mojang.com/session/minecraft/hasJoined?username={}&serverId={:x}",
-                username, bigint
+            let server_hash = server_hash(
+                &server_client.server_id,
+                &shared_sec,
+                &server_client.key_encoded,
             );
-            let request = server_client.reqwest_client.get(url).build()?;
-            let response1 = server_client.reqwest_client.execute(request).await?;
-            let v = response1.text().await?;
-            let response = serde_json::from_str::<JavaResponse>(&v)?;
+            let response = server_client
+                .authenticator
+                .authenticate(username, &server_hash)
+                .await?;
 
             *login_state = LoginState::Completed {
-                encryptor,
-                decryptor,
+                cipher: Some((encryptor, decryptor)),
                 data: response,
             };
             debug!("Encryption completed");
             return Ok(None);
         }
+        (
+            ServerBoundLogin::PluginResponse(plugin),
+            LoginState::VelocityRequested { message_id },
+        ) if plugin.message_id.0 == *message_id => {
+            let ForwardingMode::Velocity { secret } = &server_client.forwarding else {
+                return Err(LoginError::Forwarding(
+                    "Velocity forwarding is not enabled".to_string(),
+                ));
+            };
+            if !plugin.successful {
+                return Err(LoginError::Forwarding(
+                    "The client did not connect through Velocity".to_string(),
+                ));
+            }
+            let forwarding = VelocityForwarding::read(secret, &plugin.data)?;
+            *login_state = LoginState::Completed {
+                cipher: None,
+                data: forwarding.into(),
+            };
+            debug!("Velocity login completed");
+            return Ok(None);
+        }
         (ServerBoundLogin::PluginResponse(plugin), LoginState::Completed { .. }) => {
             warn!(
                 "Received plugin response: Message ID: {}",
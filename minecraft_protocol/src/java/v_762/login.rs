@@ -0,0 +1,2 @@
+//! The login packets did not change in 1.19.4
+pub use crate::java::v_761::login::{ClientIO, ServerIO};
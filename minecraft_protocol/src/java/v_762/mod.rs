@@ -0,0 +1,5 @@
+//! 1.19.4
+//!
+//! Packets with the same layout and id as 1.19.3 use the [v_761](crate::java::v_761) implementations.
+pub mod login;
+pub mod play;
@@ -0,0 +1,631 @@
+use std::io::{Read, Write};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use minecraft_protocol_macros::PacketImplDebug;
+
+pub use client_bound::PacketIOImpl as ClientIO;
+pub use server_bound::PacketIOImpl as ServerIO;
+
+use crate::chat::Component;
+use crate::data::PacketDataType;
+use crate::java::define_packet;
+use crate::java::v_761::play::client::respawn::{read_respawn, write_respawn};
+use crate::java::v_761::play::server::{
+    write_move, SetPlayerPosition, SetPlayerPositionAndRotation, SetPlayerRotation,
+};
+use crate::packets::play::client::player_info::{SyncPlayerPosition, SyncPlayerPositionFlags};
+use crate::packets::play::client::respawn::Respawn;
+use crate::packets::play::client::ServerData;
+use crate::packets::play::server::ServerBoundMove;
+use crate::Protocol::Java;
+use crate::{Bound, Packet, PacketReadError, PacketWriteError, Protocol, Stage};
+
+const ICON_PREFIX: &str = "data:image/png;base64,";
+/// The packet ids of the move packets. Back to the ids of 1.19.2
+const MOVE_IDS: [i32; 3] = [0x14, 0x15, 0x16];
+
+/// The MOTD is always sent and the icon is sent as the raw PNG
+///
+/// The icon is still exposed as a `data:image/png;base64,` string like in the status response
+#[derive(PacketImplDebug)]
+pub struct ClientBoundServerDataImpl;
+
+impl Packet for ClientBoundServerDataImpl {
+    define_packet!(ServerData, 0x45, Bound::ClientBound, Stage::Play, Java(762));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content
+            .motd
+            .unwrap_or_else(|| Component::text(""))
+            .write(w)?;
+        let icon = content
+            .icon
+            .map(|icon| {
+                let data = icon.strip_prefix(ICON_PREFIX).unwrap_or(&icon);
+                STANDARD.decode(data)
+            })
+            .transpose()
+            .map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid icon {}", err),
+                )
+            })?;
+        if let Some(icon) = icon {
+            true.write(w)?;
+            icon.write(w)?;
+        } else {
+            false.write(w)?;
+        }
+        content.enforced_secure_chat.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        let motd = Component::read(r)?;
+        let icon = if bool::read(r)? {
+            let png = Vec::<u8>::read(r)?;
+            Some(format!("{}{}", ICON_PREFIX, STANDARD.encode(png)))
+        } else {
+            None
+        };
+        Ok(ServerData {
+            motd: Some(motd),
+            icon,
+            previews_chat: false,
+            enforced_secure_chat: bool::read(r)?,
+        })
+    }
+}
+
+/// The dismount vehicle field was removed in 1.19.4
+#[derive(PacketImplDebug)]
+pub struct SyncPlayerPositionImpl;
+
+impl Packet for SyncPlayerPositionImpl {
+    define_packet!(
+        SyncPlayerPosition,
+        0x3C,
+        Bound::ClientBound,
+        Stage::Play,
+        Java(762)
+    );
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        content.x.write(w)?;
+        content.y.write(w)?;
+        content.z.write(w)?;
+        content.yaw.write(w)?;
+        content.pitch.write(w)?;
+        content.flags.bits().write(w)?;
+        content.teleport_id.write(w)?;
+        Ok(())
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        Ok(SyncPlayerPosition {
+            x: PacketDataType::read(r)?,
+            y: PacketDataType::read(r)?,
+            z: PacketDataType::read(r)?,
+            yaw: PacketDataType::read(r)?,
+            pitch: PacketDataType::read(r)?,
+            flags: SyncPlayerPositionFlags::from_bits(PacketDataType::read(r)?).ok_or(
+                PacketReadError::InvalidData(anyhow::anyhow!("Invalid position flags")),
+            )?,
+            teleport_id: PacketDataType::read(r)?,
+            dismount_vehicle: false,
+        })
+    }
+}
+
+/// The kept data is sent as flags since 1.19.4
+#[derive(PacketImplDebug)]
+pub struct ClientBoundRespawnImpl;
+
+impl Packet for ClientBoundRespawnImpl {
+    define_packet!(Respawn, 0x41, Bound::ClientBound, Stage::Play, Java(762));
+
+    fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+        Self::write_packet_id(w)?;
+        write_respawn(content, true, w)
+    }
+
+    fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+        read_respawn(r, true)
+    }
+}
+
+macro_rules! move_packet {
+    ($name:ident, $shared:ty, $id:literal) => {
+        /// The layout is shared with 1.19.3. Only the packet id changed
+        #[derive(PacketImplDebug)]
+        pub struct $name;
+
+        impl Packet for $name {
+            define_packet!(
+                ServerBoundMove,
+                $id,
+                Bound::ServerBound,
+                Stage::Play,
+                Java(762)
+            );
+
+            fn write<W: Write>(content: Self::Content, w: &mut W) -> Result<(), PacketWriteError> {
+                write_move(content, MOVE_IDS, w)
+            }
+
+            fn read<R: Read>(r: &mut R) -> Result<Self::Content, PacketReadError> {
+                <$shared>::read(r)
+            }
+        }
+    };
+}
+move_packet!(SetPlayerPositionImpl, SetPlayerPosition, 0x14);
+move_packet!(
+    SetPlayerPositionAndRotationImpl,
+    SetPlayerPositionAndRotation,
+    0x15
+);
+move_packet!(SetPlayerRotationImpl, SetPlayerRotation, 0x16);
+
+mod client_bound {
+    use minecraft_protocol_macros::define_io;
+
+    use crate::java::v_761::play::client::block::{
+        ClientBoundAcknowledgeBlockChangeImpl, ClientBoundBlockActionImpl,
+        ClientBoundBlockEntityDataImpl, ClientBoundBlockUpdateImpl,
+        ClientBoundUpdateSectionBlocksImpl,
+    };
+    use crate::java::v_761::play::client::chat::{
+        ClientBoundDisguisedChatImpl, ClientBoundPlayerChatImpl, ClientBoundSystemChatImpl,
+    };
+    use crate::java::v_761::play::client::chunk::{
+        ClientBoundChunkDataImpl, ClientBoundLightUpdateImpl, ClientBoundSetCenterChunkImpl,
+        ClientBoundUnloadChunkImpl,
+    };
+    use crate::java::v_761::play::client::commands::ClientBoundCommandSuggestionsImpl;
+    use crate::java::v_761::play::client::container::{
+        ClientBoundCloseContainerImpl, ClientBoundOpenScreenImpl, ClientBoundSelectedSlotImpl,
+        ClientBoundSetContainerContentImpl, ClientBoundSetContainerPropertyImpl,
+        ClientBoundSetContainerSlotImpl,
+    };
+    use crate::java::v_761::play::client::entity::{
+        ClientBoundRemoveEntitiesImpl, ClientBoundSetEntityVelocityImpl,
+        ClientBoundSetEquipmentImpl, ClientBoundSetHeadRotationImpl, ClientBoundSpawnEntityImpl,
+        ClientBoundSpawnExperienceOrbImpl, ClientBoundSpawnPlayerImpl,
+        ClientBoundTeleportEntityImpl, ClientBoundUpdateEntityPositionAndRotationImpl,
+        ClientBoundUpdateEntityPositionImpl, ClientBoundUpdateEntityRotationImpl,
+    };
+    use crate::java::v_761::play::client::hud::{
+        ClientBoundBossBarImpl, ClientBoundClearTitlesImpl, ClientBoundSetActionBarTextImpl,
+        ClientBoundSetSubtitleTextImpl, ClientBoundSetTabListHeaderAndFooterImpl,
+        ClientBoundSetTitleAnimationTimesImpl, ClientBoundSetTitleTextImpl,
+    };
+    use crate::java::v_761::play::client::particle::ClientBoundParticleImpl;
+    use crate::java::v_761::play::client::player_info::{
+        ClientBoundPlayerInfoRemoveImpl, ClientBoundPlayerInfoUpdateImpl,
+    };
+    use crate::java::v_761::play::client::respawn::ClientBoundSetDefaultSpawnPositionImpl;
+    use crate::java::v_761::play::client::scoreboard::{
+        ClientBoundDisplayObjectiveImpl, ClientBoundUpdateObjectivesImpl,
+        ClientBoundUpdateScoreImpl, ClientBoundUpdateTeamsImpl,
+    };
+    use crate::java::v_761::play::client::sound::{
+        ClientBoundEntitySoundEffectImpl, ClientBoundSoundEffectImpl, ClientBoundStopSoundImpl,
+    };
+    use crate::java::v_761::play::client::world::{
+        ClientBoundGameEventImpl, ClientBoundInitializeWorldBorderImpl,
+        ClientBoundSetBorderCenterImpl, ClientBoundSetBorderLerpSizeImpl,
+        ClientBoundSetBorderSizeImpl, ClientBoundSetBorderWarningDelayImpl,
+        ClientBoundSetBorderWarningDistanceImpl, ClientBoundUpdateTimeImpl,
+    };
+    use crate::java::v_761::play::client::{
+        ClientBoundChangeDifficulty, ClientBoundDisconnectPacketImpl, ClientBoundKeepAliveImpl,
+        ClientBoundLoginPacketImpl, ClientBoundPingImpl, ClientBoundPluginMessageImpl,
+        ClientBoundResourcePackImpl, ClientBoundSetAbilities,
+    };
+    use crate::java::RemappedPacket;
+    use crate::packets::play::client::ClientBoundPlay;
+    use crate::PacketIO;
+    use crate::{PacketReadError, PacketWriteError};
+
+    use super::*;
+
+    define_io!(ClientBoundPlay {
+        0x28 => {
+            type_name: RemappedPacket<ClientBoundLoginPacketImpl, 0x28, 762>
+            g_var: Login
+        },
+        0x17 => {
+            type_name: RemappedPacket<ClientBoundPluginMessageImpl, 0x17, 762>
+            g_var: PluginMessage
+        },
+        0x34 => {
+            type_name: RemappedPacket<ClientBoundSetAbilities, 0x34, 762>
+            g_var: Abilities
+        },
+        0x0C => {
+            type_name: RemappedPacket<ClientBoundChangeDifficulty, 0x0C, 762>
+            g_var: ChangeDifficulty
+        },
+        0x1A => {
+            type_name: RemappedPacket<ClientBoundDisconnectPacketImpl, 0x1A, 762>
+            g_var: Disconnect
+        },
+        0x23 => {
+            type_name: RemappedPacket<ClientBoundKeepAliveImpl, 0x23, 762>
+            g_var: KeepAlive
+        },
+        0x32 => {
+            type_name: RemappedPacket<ClientBoundPingImpl, 0x32, 762>
+            g_var: Ping
+        },
+        0x3C => {
+            type_name: SyncPlayerPositionImpl
+            g_var: SyncPlayerPosition
+        },
+        0x24 => {
+            type_name: RemappedPacket<ClientBoundChunkDataImpl, 0x24, 762>
+            g_var: ChunkData
+        },
+        0x27 => {
+            type_name: RemappedPacket<ClientBoundLightUpdateImpl, 0x27, 762>
+            g_var: UpdateLight
+        },
+        0x45 => {
+            type_name: ClientBoundServerDataImpl
+            g_var: ServerData
+        },
+        0x64 => {
+            type_name: RemappedPacket<ClientBoundSystemChatImpl, 0x64, 762>
+            g_var: SystemChat
+        },
+        0x35 => {
+            type_name: RemappedPacket<ClientBoundPlayerChatImpl, 0x35, 762>
+            g_var: PlayerChat
+        },
+        0x1B => {
+            type_name: RemappedPacket<ClientBoundDisguisedChatImpl, 0x1B, 762>
+            g_var: DisguisedChat
+        },
+        0x0A => {
+            type_name: RemappedPacket<ClientBoundBlockUpdateImpl, 0x0A, 762>
+            g_var: BlockUpdate
+        },
+        0x43 => {
+            type_name: RemappedPacket<ClientBoundUpdateSectionBlocksImpl, 0x43, 762>
+            g_var: UpdateSectionBlocks
+        },
+        0x08 => {
+            type_name: RemappedPacket<ClientBoundBlockEntityDataImpl, 0x08, 762>
+            g_var: BlockEntityData
+        },
+        0x09 => {
+            type_name: RemappedPacket<ClientBoundBlockActionImpl, 0x09, 762>
+            g_var: BlockAction
+        },
+        0x06 => {
+            type_name: RemappedPacket<ClientBoundAcknowledgeBlockChangeImpl, 0x06, 762>
+            g_var: AcknowledgeBlockChange
+        },
+        0x01 => {
+            type_name: RemappedPacket<ClientBoundSpawnEntityImpl, 0x01, 762>
+            g_var: SpawnEntity
+        },
+        0x02 => {
+            type_name: RemappedPacket<ClientBoundSpawnExperienceOrbImpl, 0x02, 762>
+            g_var: SpawnExperienceOrb
+        },
+        0x03 => {
+            type_name: RemappedPacket<ClientBoundSpawnPlayerImpl, 0x03, 762>
+            g_var: SpawnPlayer
+        },
+        0x2B => {
+            type_name: RemappedPacket<ClientBoundUpdateEntityPositionImpl, 0x2B, 762>
+            g_var: UpdateEntityPosition
+        },
+        0x2C => {
+            type_name: RemappedPacket<ClientBoundUpdateEntityPositionAndRotationImpl, 0x2C, 762>
+            g_var: UpdateEntityPositionAndRotation
+        },
+        0x2D => {
+            type_name: RemappedPacket<ClientBoundUpdateEntityRotationImpl, 0x2D, 762>
+            g_var: UpdateEntityRotation
+        },
+        0x68 => {
+            type_name: RemappedPacket<ClientBoundTeleportEntityImpl, 0x68, 762>
+            g_var: TeleportEntity
+        },
+        0x42 => {
+            type_name: RemappedPacket<ClientBoundSetHeadRotationImpl, 0x42, 762>
+            g_var: SetHeadRotation
+        },
+        0x54 => {
+            type_name: RemappedPacket<ClientBoundSetEntityVelocityImpl, 0x54, 762>
+            g_var: SetEntityVelocity
+        },
+        0x3E => {
+            type_name: RemappedPacket<ClientBoundRemoveEntitiesImpl, 0x3E, 762>
+            g_var: RemoveEntities
+        },
+        0x55 => {
+            type_name: RemappedPacket<ClientBoundSetEquipmentImpl, 0x55, 762>
+            g_var: SetEquipment
+        },
+        0x30 => {
+            type_name: RemappedPacket<ClientBoundOpenScreenImpl, 0x30, 762>
+            g_var: OpenScreen
+        },
+        0x12 => {
+            type_name: RemappedPacket<ClientBoundSetContainerContentImpl, 0x12, 762>
+            g_var: SetContainerContent
+        },
+        0x14 => {
+            type_name: RemappedPacket<ClientBoundSetContainerSlotImpl, 0x14, 762>
+            g_var: SetContainerSlot
+        },
+        0x13 => {
+            type_name: RemappedPacket<ClientBoundSetContainerPropertyImpl, 0x13, 762>
+            g_var: SetContainerProperty
+        },
+        0x11 => {
+            type_name: RemappedPacket<ClientBoundCloseContainerImpl, 0x11, 762>
+            g_var: CloseContainer
+        },
+        0x4D => {
+            type_name: RemappedPacket<ClientBoundSelectedSlotImpl, 0x4D, 762>
+            g_var: SelectedSlot
+        },
+        0x0F => {
+            type_name: RemappedPacket<ClientBoundCommandSuggestionsImpl, 0x0F, 762>
+            g_var: CommandSuggestions
+        },
+        0x3A => {
+            type_name: RemappedPacket<ClientBoundPlayerInfoUpdateImpl, 0x3A, 762>
+            g_var: PlayerInfoUpdate
+        },
+        0x39 => {
+            type_name: RemappedPacket<ClientBoundPlayerInfoRemoveImpl, 0x39, 762>
+            g_var: PlayerInfoRemove
+        },
+        0x40 => {
+            type_name: RemappedPacket<ClientBoundResourcePackImpl, 0x40, 762>
+            g_var: ResourcePack
+        },
+        0x0B => {
+            type_name: RemappedPacket<ClientBoundBossBarImpl, 0x0B, 762>
+            g_var: BossBar
+        },
+        0x0E => {
+            type_name: RemappedPacket<ClientBoundClearTitlesImpl, 0x0E, 762>
+            g_var: ClearTitles
+        },
+        0x46 => {
+            type_name: RemappedPacket<ClientBoundSetActionBarTextImpl, 0x46, 762>
+            g_var: SetActionBarText
+        },
+        0x5D => {
+            type_name: RemappedPacket<ClientBoundSetSubtitleTextImpl, 0x5D, 762>
+            g_var: SetSubtitleText
+        },
+        0x5F => {
+            type_name: RemappedPacket<ClientBoundSetTitleTextImpl, 0x5F, 762>
+            g_var: SetTitleText
+        },
+        0x60 => {
+            type_name: RemappedPacket<ClientBoundSetTitleAnimationTimesImpl, 0x60, 762>
+            g_var: SetTitleAnimationTimes
+        },
+        0x65 => {
+            type_name: RemappedPacket<ClientBoundSetTabListHeaderAndFooterImpl, 0x65, 762>
+            g_var: SetTabListHeaderAndFooter
+        },
+        0x58 => {
+            type_name: RemappedPacket<ClientBoundUpdateObjectivesImpl, 0x58, 762>
+            g_var: UpdateObjectives
+        },
+        0x51 => {
+            type_name: RemappedPacket<ClientBoundDisplayObjectiveImpl, 0x51, 762>
+            g_var: DisplayObjective
+        },
+        0x5B => {
+            type_name: RemappedPacket<ClientBoundUpdateScoreImpl, 0x5B, 762>
+            g_var: UpdateScore
+        },
+        0x5A => {
+            type_name: RemappedPacket<ClientBoundUpdateTeamsImpl, 0x5A, 762>
+            g_var: UpdateTeams
+        },
+        0x22 => {
+            type_name: RemappedPacket<ClientBoundInitializeWorldBorderImpl, 0x22, 762>
+            g_var: InitializeWorldBorder
+        },
+        0x47 => {
+            type_name: RemappedPacket<ClientBoundSetBorderCenterImpl, 0x47, 762>
+            g_var: SetBorderCenter
+        },
+        0x48 => {
+            type_name: RemappedPacket<ClientBoundSetBorderLerpSizeImpl, 0x48, 762>
+            g_var: SetBorderLerpSize
+        },
+        0x49 => {
+            type_name: RemappedPacket<ClientBoundSetBorderSizeImpl, 0x49, 762>
+            g_var: SetBorderSize
+        },
+        0x4A => {
+            type_name: RemappedPacket<ClientBoundSetBorderWarningDelayImpl, 0x4A, 762>
+            g_var: SetBorderWarningDelay
+        },
+        0x4B => {
+            type_name: RemappedPacket<ClientBoundSetBorderWarningDistanceImpl, 0x4B, 762>
+            g_var: SetBorderWarningDistance
+        },
+        0x5E => {
+            type_name: RemappedPacket<ClientBoundUpdateTimeImpl, 0x5E, 762>
+            g_var: UpdateTime
+        },
+        0x1F => {
+            type_name: RemappedPacket<ClientBoundGameEventImpl, 0x1F, 762>
+            g_var: GameEvent
+        },
+        0x62 => {
+            type_name: RemappedPacket<ClientBoundSoundEffectImpl, 0x62, 762>
+            g_var: SoundEffect
+        },
+        0x61 => {
+            type_name: RemappedPacket<ClientBoundEntitySoundEffectImpl, 0x61, 762>
+            g_var: EntitySoundEffect
+        },
+        0x63 => {
+            type_name: RemappedPacket<ClientBoundStopSoundImpl, 0x63, 762>
+            g_var: StopSound
+        },
+        0x26 => {
+            type_name: RemappedPacket<ClientBoundParticleImpl, 0x26, 762>
+            g_var: Particle
+        },
+        0x41 => {
+            type_name: ClientBoundRespawnImpl
+            g_var: Respawn
+        },
+        0x50 => {
+            type_name: RemappedPacket<ClientBoundSetDefaultSpawnPositionImpl, 0x50, 762>
+            g_var: SetDefaultSpawnPosition
+        },
+        0x4E => {
+            type_name: RemappedPacket<ClientBoundSetCenterChunkImpl, 0x4E, 762>
+            g_var: SetCenterChunk
+        },
+        0x1E => {
+            type_name: RemappedPacket<ClientBoundUnloadChunkImpl, 0x1E, 762>
+            g_var: UnloadChunk
+        }
+    });
+}
+
+/// Player Session moved from 0x20 to 0x06 in 1.19.4. The packets from 0x06 to 0x1F moved up by one. The ones after 0x20 kept their ids
+mod server_bound {
+    use minecraft_protocol_macros::define_io;
+
+    use crate::java::v_761::play::server::chat::{
+        ChatCommandImpl, ChatMessageImpl, MessageAcknowledgmentImpl, PlayerSessionImpl,
+    };
+    use crate::java::v_761::play::server::container::{
+        ClickContainerImpl, ServerBoundCloseContainerImpl, SetCreativeModeSlotImpl, SetHeldItemImpl,
+    };
+    use crate::java::v_761::play::server::interaction::{
+        InteractImpl, PlayerActionImpl, PlayerCommandImpl, SwingArmImpl, UseItemImpl, UseItemOnImpl,
+    };
+    use crate::java::v_761::play::server::{
+        ClientInformationImpl, CommandSuggestionsRequestImpl, ConfirmTeleportImpl, KeepAlivePacket,
+        PongPacket, ResourcePackStatusImpl, ServerBoundPluginMessageImpl,
+    };
+    use crate::java::RemappedPacket;
+    use crate::packets::play::server::ServerBoundPlay;
+    use crate::PacketIO;
+    use crate::{PacketReadError, PacketWriteError};
+
+    use super::*;
+
+    define_io!(ServerBoundPlay {
+        0x14 => {
+            type_name: SetPlayerPositionImpl
+            g_var: PlayerMove
+        },
+        0x15 => {
+            type_name: SetPlayerPositionAndRotationImpl
+            g_var: PlayerMove
+        },
+        0x16 => {
+            type_name: SetPlayerRotationImpl
+            g_var: PlayerMove
+        },
+        0x20 => {
+            type_name: RemappedPacket<PongPacket, 0x20, 762>
+            g_var: Ping
+        },
+        0x12 => {
+            type_name: RemappedPacket<KeepAlivePacket, 0x12, 762>
+            g_var: KeepAlive
+        },
+        0x08 => {
+            type_name: RemappedPacket<ClientInformationImpl, 0x08, 762>
+            g_var: ClientInformation
+        },
+        0x00 => {
+            type_name: ConfirmTeleportImpl
+            g_var: ConfirmTeleport
+        },
+        0x0D => {
+            type_name: RemappedPacket<ServerBoundPluginMessageImpl, 0x0D, 762>
+            g_var: PluginMessage
+        },
+        0x03 => {
+            type_name: MessageAcknowledgmentImpl
+            g_var: MessageAcknowledgment
+        },
+        0x04 => {
+            type_name: ChatCommandImpl
+            g_var: ChatCommand
+        },
+        0x05 => {
+            type_name: ChatMessageImpl
+            g_var: ChatMessage
+        },
+        0x06 => {
+            type_name: RemappedPacket<PlayerSessionImpl, 0x06, 762>
+            g_var: PlayerSession
+        },
+        0x0B => {
+            type_name: RemappedPacket<ClickContainerImpl, 0x0B, 762>
+            g_var: ClickContainer
+        },
+        0x0C => {
+            type_name: RemappedPacket<ServerBoundCloseContainerImpl, 0x0C, 762>
+            g_var: CloseContainer
+        },
+        0x2B => {
+            type_name: SetCreativeModeSlotImpl
+            g_var: SetCreativeModeSlot
+        },
+        0x28 => {
+            type_name: SetHeldItemImpl
+            g_var: SetHeldItem
+        },
+        0x09 => {
+            type_name: RemappedPacket<CommandSuggestionsRequestImpl, 0x09, 762>
+            g_var: CommandSuggestionsRequest
+        },
+        0x24 => {
+            type_name: ResourcePackStatusImpl
+            g_var: ResourcePackStatus
+        },
+        0x1D => {
+            type_name: RemappedPacket<PlayerActionImpl, 0x1D, 762>
+            g_var: PlayerAction
+        },
+        0x31 => {
+            type_name: UseItemOnImpl
+            g_var: UseItemOn
+        },
+        0x32 => {
+            type_name: UseItemImpl
+            g_var: UseItem
+        },
+        0x2F => {
+            type_name: SwingArmImpl
+            g_var: SwingArm
+        },
+        0x10 => {
+            type_name: RemappedPacket<InteractImpl, 0x10, 762>
+            g_var: Interact
+        },
+        0x1E => {
+            type_name: RemappedPacket<PlayerCommandImpl, 0x1E, 762>
+            g_var: PlayerCommand
+        }
+    });
+}
@@ -0,0 +1,139 @@
+//! Maps the protocol version sent in the [HandShake] to the packet implementations of that version.
+use thiserror::Error;
+
+use crate::chat::Component;
+use crate::java::handshake::HandShake;
+use crate::java::{v_760, v_761, v_762};
+use crate::packets::login::client_bound::Disconnect;
+use crate::packets::login::{ClientBoundLogin, ServerBoundLogin};
+use crate::packets::play::client::ClientBoundPlay;
+use crate::packets::play::server::ServerBoundPlay;
+use crate::PacketIO;
+
+/// The packet implementations of a single protocol version
+pub trait JavaVersion {
+    const PROTOCOL: i32;
+    /// The name of the release. Such as `1.19.3`
+    const NAME: &'static str;
+    type LoginServerIO: PacketIO<Type = ServerBoundLogin>;
+    type LoginClientIO: PacketIO<Type = ClientBoundLogin>;
+    type PlayServerIO: PacketIO<Type = ServerBoundPlay>;
+    type PlayClientIO: PacketIO<Type = ClientBoundPlay>;
+}
+
+/// 1.19.2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V760;
+
+impl JavaVersion for V760 {
+    const PROTOCOL: i32 = 760;
+    const NAME: &'static str = "1.19.2";
+    type LoginServerIO = v_760::login::ServerIO;
+    type LoginClientIO = v_760::login::ClientIO;
+    type PlayServerIO = v_760::play::ServerIO;
+    type PlayClientIO = v_760::play::ClientIO;
+}
+
+/// 1.19.3
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V761;
+
+impl JavaVersion for V761 {
+    const PROTOCOL: i32 = 761;
+    const NAME: &'static str = "1.19.3";
+    type LoginServerIO = v_761::login::ServerIO;
+    type LoginClientIO = v_761::login::ClientIO;
+    type PlayServerIO = v_761::play::ServerIO;
+    type PlayClientIO = v_761::play::ClientIO;
+}
+
+/// 1.19.4
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct V762;
+
+impl JavaVersion for V762 {
+    const PROTOCOL: i32 = 762;
+    const NAME: &'static str = "1.19.4";
+    type LoginServerIO = v_762::login::ServerIO;
+    type LoginClientIO = v_762::login::ClientIO;
+    type PlayServerIO = v_762::play::ServerIO;
+    type PlayClientIO = v_762::play::ClientIO;
+}
+
+/// Called with the [JavaVersion] matching a [SupportedVersion].
+///
+/// The packet implementations are types so the version has to be picked through a generic function
+pub trait VersionVisitor {
+    type Output;
+    fn visit<V: JavaVersion>(self) -> Self::Output;
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("Unsupported protocol version {0}")]
+pub struct UnsupportedVersion(pub i32);
+
+impl UnsupportedVersion {
+    /// The packet to send the client before closing the connection
+    pub fn disconnect(&self) -> ClientBoundLogin {
+        let reason = Component::text(format!(
+            "Unsupported client version. This server supports {} - {}",
+            SupportedVersion::OLDEST.name(),
+            SupportedVersion::NEWEST.name()
+        ));
+        ClientBoundLogin::LoginDisconnect(Disconnect { reason })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SupportedVersion {
+    V760,
+    V761,
+    V762,
+}
+
+impl SupportedVersion {
+    pub const OLDEST: SupportedVersion = SupportedVersion::V760;
+    pub const NEWEST: SupportedVersion = SupportedVersion::V762;
+    pub const ALL: [SupportedVersion; 3] = [
+        SupportedVersion::V760,
+        SupportedVersion::V761,
+        SupportedVersion::V762,
+    ];
+
+    pub fn from_protocol(protocol: i32) -> Result<Self, UnsupportedVersion> {
+        match protocol {
+            V760::PROTOCOL => Ok(SupportedVersion::V760),
+            V761::PROTOCOL => Ok(SupportedVersion::V761),
+            V762::PROTOCOL => Ok(SupportedVersion::V762),
+            other => Err(UnsupportedVersion(other)),
+        }
+    }
+
+    pub fn from_handshake(handshake: &HandShake) -> Result<Self, UnsupportedVersion> {
+        Self::from_protocol(handshake.protocol_version.0)
+    }
+
+    pub fn protocol(&self) -> i32 {
+        match self {
+            SupportedVersion::V760 => V760::PROTOCOL,
+            SupportedVersion::V761 => V761::PROTOCOL,
+            SupportedVersion::V762 => V762::PROTOCOL,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SupportedVersion::V760 => V760::NAME,
+            SupportedVersion::V761 => V761::NAME,
+            SupportedVersion::V762 => V762::NAME,
+        }
+    }
+
+    pub fn visit<Visitor: VersionVisitor>(&self, visitor: Visitor) -> Visitor::Output {
+        match self {
+            SupportedVersion::V760 => visitor.visit::<V760>(),
+            SupportedVersion::V761 => visitor.visit::<V761>(),
+            SupportedVersion::V762 => visitor.visit::<V762>(),
+        }
+    }
+}
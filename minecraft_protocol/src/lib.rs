@@ -6,21 +6,25 @@ use std::io::{Read, Write};
 
 use aes::Aes128;
 use bytes::BytesMut;
-pub use cfb_mode::cipher::AsyncStreamCipher;
+pub use cfb8::cipher::AsyncStreamCipher;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::limits::{DecodeLimits, LimitExceeded};
+
+pub mod chat;
 pub mod data;
 pub mod java;
+pub mod limits;
 pub mod packets;
 pub mod simple_handlers;
 
 /// The Encryptor type used for Encrypting Packets
 #[cfg(feature = "encryption")]
-pub type Encryptor = cfb_mode::Encryptor<Aes128>;
+pub type Encryptor = cfb8::Encryptor<Aes128>;
 /// The Decryptor type used for Decrypting Packets
 #[cfg(feature = "encryption")]
-pub type Decryptor = cfb_mode::Decryptor<Aes128>;
+pub type Decryptor = cfb8::Decryptor<Aes128>;
 
 #[derive(Debug, Error)]
 pub enum PacketWriteError {
@@ -37,7 +41,7 @@ pub enum PacketWriteError {
 #[derive(Debug, Error)]
 pub enum PacketReadError {
     #[error("Failed to write value: {0}")]
-    IoError(#[from] std::io::Error),
+    IoError(#[source] std::io::Error),
 
     #[error("Unknown packet id: {0}")]
     UnknownPacketId(i32),
@@ -46,6 +50,22 @@ pub enum PacketReadError {
     InvalidData(anyhow::Error),
     #[error("UTF-8 Error: {0}")]
     Utf8Error(#[from] std::string::FromUtf8Error),
+    #[error("Decode limit exceeded: {0}")]
+    LimitExceeded(#[from] LimitExceeded),
+}
+
+impl From<std::io::Error> for PacketReadError {
+    /// Limits hit inside a [PacketDataType](data::PacketDataType) come through as io errors
+    fn from(error: std::io::Error) -> Self {
+        let limit = error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<LimitExceeded>())
+            .copied();
+        match limit {
+            Some(limit) => PacketReadError::LimitExceeded(limit),
+            None => PacketReadError::IoError(error),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -133,6 +153,12 @@ pub trait PacketReader: PacketHandler {
     }
 
     fn packet_len(&self) -> &PacketLength;
+
+    /// Does nothing by default. Readers that do not override this always use [DecodeLimits::default]
+    fn set_decode_limits(&mut self, _limits: DecodeLimits) {}
+    fn decode_limits(&self) -> DecodeLimits {
+        DecodeLimits::default()
+    }
     /// The minimum numbers of bytes needed to read before attempt_packet_read should be called
     fn minimum_bytes_needed(&self) -> usize {
         if let PacketLength::LengthRead { length, .. } = self.packet_len() {
@@ -0,0 +1,147 @@
+//! Hard limits applied while decoding packets.
+//!
+//! Every length prefix read off the wire is checked against these before anything is allocated.
+//! The packet and decompressed sizes are checked by the packet readers.
+//! The string and array limits are checked by the [PacketDataType](crate::data::PacketDataType) readers.
+//! These do not have access to the reader. So the limits of the reader are made available to them for the duration of [PacketIO::handle_read](crate::PacketIO::handle_read)
+use std::cell::Cell;
+use std::io;
+use std::io::Read;
+
+use thiserror::Error;
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+
+thread_local! {
+    static CURRENT: Cell<DecodeLimits> = Cell::new(DecodeLimits::default());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DecodeLimits {
+    /// The Packet Length. Before decompression
+    pub max_packet_length: usize,
+    /// The Data Length of a compressed packet
+    pub max_decompressed_size: usize,
+    /// In UTF-16 code units. Same as vanilla
+    pub max_string_length: usize,
+    /// The number of elements of a length prefixed array
+    pub max_array_length: usize,
+}
+
+impl DecodeLimits {
+    pub const MAX_PACKET_LENGTH: usize = 1 << 21;
+    pub const MAX_DECOMPRESSED_SIZE: usize = 1 << 23;
+    pub const MAX_STRING_LENGTH: usize = 32767;
+    pub const MAX_ARRAY_LENGTH: usize = 1 << 21;
+
+    /// The limits of the packet currently being read on this thread
+    pub fn current() -> Self {
+        CURRENT.with(Cell::get)
+    }
+
+    /// Makes these the [DecodeLimits::current] limits while `f` runs
+    pub fn scope<T>(self, f: impl FnOnce() -> T) -> T {
+        struct Restore(DecodeLimits);
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                CURRENT.with(|current| current.set(self.0));
+            }
+        }
+        let _restore = Restore(CURRENT.with(|current| current.replace(self)));
+        f()
+    }
+
+    pub fn check_packet_length(&self, length: i32) -> Result<usize, LimitExceeded> {
+        check(length, self.max_packet_length, |length, max| {
+            LimitExceeded::PacketLength { length, max }
+        })
+    }
+
+    pub fn check_decompressed_size(&self, size: i32) -> Result<usize, LimitExceeded> {
+        check(size, self.max_decompressed_size, |length, max| {
+            LimitExceeded::DecompressedSize { length, max }
+        })
+    }
+
+    pub fn check_array_length(&self, length: i32) -> Result<usize, LimitExceeded> {
+        check(length, self.max_array_length, |length, max| {
+            LimitExceeded::ArrayLength { length, max }
+        })
+    }
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_packet_length: Self::MAX_PACKET_LENGTH,
+            max_decompressed_size: Self::MAX_DECOMPRESSED_SIZE,
+            max_string_length: Self::MAX_STRING_LENGTH,
+            max_array_length: Self::MAX_ARRAY_LENGTH,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum LimitExceeded {
+    #[error("Packet length of {length} is over the limit of {max}")]
+    PacketLength { length: i64, max: usize },
+    #[error("Decompressed size of {length} is over the limit of {max}")]
+    DecompressedSize { length: i64, max: usize },
+    #[error("String length of {length} is over the limit of {max}")]
+    StringLength { length: i64, max: usize },
+    #[error("Array length of {length} is over the limit of {max}")]
+    ArrayLength { length: i64, max: usize },
+}
+
+impl From<LimitExceeded> for io::Error {
+    /// Carried through [PacketDataType] as invalid data. Turned back into [PacketReadError::LimitExceeded](crate::PacketReadError::LimitExceeded)
+    fn from(value: LimitExceeded) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, value)
+    }
+}
+
+/// Negative lengths are treated as over the limit
+fn check(
+    length: i32,
+    max: usize,
+    error: impl FnOnce(i64, usize) -> LimitExceeded,
+) -> Result<usize, LimitExceeded> {
+    if length < 0 || length as usize > max {
+        return Err(error(length as i64, max));
+    }
+    Ok(length as usize)
+}
+
+/// Reads a VarInt array length and checks it against the current limits
+pub fn read_array_length<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let length = VarInt::read(reader)?.0;
+    Ok(DecodeLimits::current().check_array_length(length)?)
+}
+
+/// A Vec for a length read with [read_array_length]. The length is only a claim. So at most 1024 elements are reserved up front
+pub fn with_capacity<T>(length: usize) -> Vec<T> {
+    Vec::with_capacity(length.min(1024))
+}
+
+/// Reads a string of at most `max` UTF-16 code units. The byte length is checked before reading and the UTF-16 length after
+pub(crate) fn read_string<R: Read>(reader: &mut R, max: usize) -> io::Result<String> {
+    let length = VarInt::read(reader)?.0;
+    // A UTF-16 code unit takes at most 3 bytes in UTF-8
+    let bytes = check(length, max * 3, |length, _| LimitExceeded::StringLength {
+        length,
+        max,
+    })?;
+    let mut buf = Vec::with_capacity(bytes);
+    reader.take(bytes as u64).read_to_end(&mut buf)?;
+    let string = String::from_utf8_lossy(buf.as_ref()).into_owned();
+    let units = string.encode_utf16().count();
+    if units > max {
+        return Err(LimitExceeded::StringLength {
+            length: units as i64,
+            max,
+        }
+        .into());
+    }
+    Ok(string)
+}
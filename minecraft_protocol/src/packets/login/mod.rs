@@ -78,14 +78,14 @@ pub mod client_bound {
     use serde::{Deserialize, Serialize};
     use uuid::Uuid;
 
+    use crate::chat::Component;
     use crate::data::var_int::VarInt;
     use crate::PacketContent;
 
     /// First Packet sent after the handshake
     #[derive(Debug, Clone, PartialEq, Hash, Serialize, Deserialize)]
     pub struct Disconnect {
-        // Implied that it is a chat message
-        pub reason: String,
+        pub reason: Component,
     }
 
     impl PacketContent for Disconnect {}
@@ -6,6 +6,7 @@ macro_rules! define_group {
       $($variant:ident: $typ:path),*
     } ) => {
         #[derive(Debug, Clone, PartialEq)]
+        #[allow(clippy::large_enum_variant)]
         pub enum $name {
             $($variant($typ)),*
         }
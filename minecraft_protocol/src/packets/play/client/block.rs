@@ -0,0 +1,141 @@
+use std::io;
+use std::io::{Read, Write};
+
+use nbt::Blob;
+
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::data::var_int::VarInt;
+use crate::data::var_long::VarLong;
+use crate::data::{PackedPosition, PacketDataType, Position};
+use crate::packets::play::client::chunk::GetVanillaId;
+use crate::PacketContent;
+
+/// Sets a single block
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct BlockUpdate {
+    pub location: PackedPosition,
+    /// The block state id
+    pub block_state: i32,
+}
+
+impl BlockUpdate {
+    pub fn new(location: impl Position, block: &impl GetVanillaId) -> Self {
+        Self {
+            location: PackedPosition::new(location),
+            block_state: block.get_vanilla_id(),
+        }
+    }
+}
+
+/// The position of a 16x16x16 chunk section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SectionPosition {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// X and Z are 22 bits. Y is 20 bits
+impl PacketDataType for SectionPosition {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let long = i64::read(reader)?;
+        Ok(SectionPosition {
+            x: (long >> 42) as i32,
+            y: ((long << 44) >> 44) as i32,
+            z: ((long << 22) >> 42) as i32,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        let long = ((self.x as i64 & 0x3FFFFF) << 42)
+            | ((self.z as i64 & 0x3FFFFF) << 20)
+            | (self.y as i64 & 0xFFFFF);
+        long.write(writer)
+    }
+}
+
+/// A block inside a section. The coordinates are relative to the section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionBlock {
+    pub block_state: i32,
+    pub x: u8,
+    pub y: u8,
+    pub z: u8,
+}
+
+impl SectionBlock {
+    pub fn new(x: u8, y: u8, z: u8, block: &impl GetVanillaId) -> Self {
+        Self {
+            block_state: block.get_vanilla_id(),
+            x: x & 15,
+            y: y & 15,
+            z: z & 15,
+        }
+    }
+}
+
+/// A VarLong of `block_state << 12 | x << 8 | z << 4 | y`
+impl PacketDataType for SectionBlock {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let long = VarLong::read(reader)?.0;
+        Ok(SectionBlock {
+            block_state: (long >> 12) as i32,
+            x: ((long >> 8) & 15) as u8,
+            y: (long & 15) as u8,
+            z: ((long >> 4) & 15) as u8,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        let long = (self.block_state as i64) << 12
+            | (self.x as i64 & 15) << 8
+            | (self.z as i64 & 15) << 4
+            | (self.y as i64 & 15);
+        VarLong(long).write(writer)
+    }
+}
+
+/// Sets multiple blocks in one section
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UpdateSectionBlocks {
+    pub section: SectionPosition,
+    pub suppress_light_updates: bool,
+    pub blocks: Vec<SectionBlock>,
+}
+
+/// Updates the data of a block entity
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct BlockEntityData {
+    pub location: PackedPosition,
+    /// The id in the `minecraft:block_entity_type` registry
+    pub block_entity_type: i32,
+    /// None removes the data. Sent as an empty NBT tag
+    pub data: Option<Blob>,
+}
+
+/// Block specific actions. Such as opening a chest or playing a note block
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct BlockAction {
+    pub location: PackedPosition,
+    pub action_id: u8,
+    pub action_parameter: u8,
+    /// The id in the `minecraft:block` registry. Not the block state
+    pub block_type: i32,
+}
+
+/// Confirms every block change the client predicted up to the sequence
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct AcknowledgeBlockChange(pub VarInt);
+
+impl From<VarInt> for AcknowledgeBlockChange {
+    fn from(sequence: VarInt) -> Self {
+        Self(sequence)
+    }
+}
@@ -0,0 +1,163 @@
+use std::io;
+use std::io::{Read, Write};
+
+use uuid::Uuid;
+
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::packets::play::client::chunk::BitSet;
+use crate::packets::play::MessageSignature;
+use crate::PacketContent;
+
+/// A message from the server. Not signed and not reportable
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SystemChatMessage {
+    pub content: Component,
+    /// Shown above the hotbar instead of in the chat
+    pub overlay: bool,
+}
+
+/// Decorates a message with a chat type of the `minecraft:chat_type` registry
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatTypeBinding {
+    /// The id in the registry sent in the login packet
+    pub chat_type: i32,
+    /// Usually the display name of the sender
+    pub name: Component,
+    /// The receiver of a private message
+    pub target_name: Option<Component>,
+}
+
+impl PacketDataType for ChatTypeBinding {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let chat_type = VarInt::read(reader)?.0;
+        let name = Component::read(reader)?;
+        let target_name = if bool::read(reader)? {
+            Some(Component::read(reader)?)
+        } else {
+            None
+        };
+        Ok(ChatTypeBinding {
+            chat_type,
+            name,
+            target_name,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        VarInt(self.chat_type).write(writer)?;
+        self.name.write(writer)?;
+        if let Some(target_name) = self.target_name {
+            true.write(writer)?;
+            target_name.write(writer)?;
+        } else {
+            false.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// A signature of a message the client has seen.
+///
+/// Signatures the client already received can be sent as an id into its signature cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
+pub enum PackedSignature {
+    Id(i32),
+    Full(MessageSignature),
+}
+
+/// The id is sent plus one. Zero is followed by the full signature
+impl PacketDataType for PackedSignature {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        match VarInt::read(reader)?.0 {
+            0 => Ok(PackedSignature::Full(MessageSignature::read(reader)?)),
+            id if id > 0 => Ok(PackedSignature::Id(id - 1)),
+            id => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid signature id {}", id),
+            )),
+        }
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        match self {
+            PackedSignature::Id(id) => VarInt(id + 1).write(writer),
+            PackedSignature::Full(signature) => {
+                VarInt(0).write(writer)?;
+                signature.write(writer)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterMask {
+    PassThrough,
+    FullyFiltered,
+    /// The set bits are the filtered characters
+    PartiallyFiltered(BitSet),
+}
+
+impl PacketDataType for FilterMask {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        match VarInt::read(reader)?.0 {
+            0 => Ok(FilterMask::PassThrough),
+            1 => Ok(FilterMask::FullyFiltered),
+            2 => Ok(FilterMask::PartiallyFiltered(BitSet::read(reader)?)),
+            other => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid filter type {}", other),
+            )),
+        }
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        match self {
+            FilterMask::PassThrough => VarInt(0).write(writer),
+            FilterMask::FullyFiltered => VarInt(1).write(writer),
+            FilterMask::PartiallyFiltered(mask) => {
+                VarInt(2).write(writer)?;
+                mask.write(writer)
+            }
+        }
+    }
+}
+
+/// A message from a player. Signed if the player has a chat session
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct PlayerChatMessage {
+    pub sender: Uuid,
+    /// The index in the message chain of the sender
+    pub index: i32,
+    pub signature: Option<MessageSignature>,
+    pub message: String,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    /// The last seen messages the sender signed the message with
+    pub previous_messages: Vec<PackedSignature>,
+    /// Shown instead of the message. Set if the server decorated the message
+    pub unsigned_content: Option<Component>,
+    pub filter: FilterMask,
+    pub chat_type: ChatTypeBinding,
+}
+
+/// A chat message without a signature. Formatted with a chat type like a [PlayerChatMessage]
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct DisguisedChatMessage {
+    pub message: Component,
+    pub chat_type: ChatTypeBinding,
+}
@@ -7,6 +7,7 @@ use minecraft_protocol_macros::PacketContentType;
 use crate::data::var_int::inline::get_size;
 use crate::data::var_int::VarInt;
 use crate::data::{NBTOrByteArray, PacketDataType};
+use crate::limits;
 use crate::PacketContent;
 
 pub type BitSet = Vec<i64>;
@@ -95,14 +96,14 @@ pub trait PalettePacketContentReader: PalettePacketContent {
     {
         let bits_per_entry = u8::read(reader)?;
 
-        let palette_len = VarInt::read(reader)?.0 as usize;
-        let mut palette = Vec::with_capacity(palette_len);
+        let palette_len = limits::read_array_length(reader)?;
+        let mut palette = limits::with_capacity(palette_len);
         for _ in 0..palette_len {
             palette.push(VarInt::read(reader)?.0);
         }
 
-        let indexes_len = VarInt::read(reader)?.0 as usize;
-        let mut indexes = Vec::with_capacity(indexes_len);
+        let indexes_len = limits::read_array_length(reader)?;
+        let mut indexes = limits::with_capacity(indexes_len);
         for _ in 0..indexes_len {
             indexes.push(i64::read(reader)?);
         }
@@ -189,3 +190,26 @@ pub struct ChunkDataAndLight {
     pub chunk_data: ChunkPacket,
     pub light: LightPacket,
 }
+
+/// The chunk the player is in. The client only keeps the chunks in view of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PacketContentType)]
+pub struct SetCenterChunk {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+}
+
+impl SetCenterChunk {
+    /// The chunk containing the block position
+    pub fn containing(x: f64, z: f64) -> Self {
+        Self {
+            chunk_x: (x.floor() as i32) >> 4,
+            chunk_z: (z.floor() as i32) >> 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PacketContentType)]
+pub struct UnloadChunk {
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+}
@@ -0,0 +1,537 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::limits;
+use crate::PacketContent;
+
+/// Asks the server for the suggestions with Command Suggestions Request
+pub const ASK_SERVER: &str = "minecraft:ask_server";
+pub const ALL_RECIPES: &str = "minecraft:all_recipes";
+pub const AVAILABLE_SOUNDS: &str = "minecraft:available_sounds";
+pub const AVAILABLE_BIOMES: &str = "minecraft:available_biomes";
+pub const SUMMONABLE_ENTITIES: &str = "minecraft:summonable_entities";
+
+const NODE_TYPE_MASK: u8 = 0x03;
+const EXECUTABLE: u8 = 0x04;
+const HAS_REDIRECT: u8 = 0x08;
+const HAS_SUGGESTIONS_TYPE: u8 = 0x10;
+
+/// The command graph. The client uses it for completion and highlighting
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct DeclareCommands {
+    pub nodes: Vec<CommandNode>,
+    pub root_index: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandNode {
+    pub kind: CommandNodeKind,
+    /// If the command can be run when it ends at this node
+    pub executable: bool,
+    /// Indices into the node list
+    pub children: Vec<i32>,
+    /// The node parsing continues at. Such as the root for `execute run`
+    pub redirect: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommandNodeKind {
+    Root,
+    Literal {
+        name: String,
+    },
+    Argument {
+        name: String,
+        parser: ArgumentParser,
+        /// Such as [ASK_SERVER]. None uses the suggestions of the parser
+        suggestions: Option<String>,
+    },
+}
+
+impl CommandNodeKind {
+    /// Empty for the root
+    pub fn name(&self) -> &str {
+        match self {
+            CommandNodeKind::Root => "",
+            CommandNodeKind::Literal { name } | CommandNodeKind::Argument { name, .. } => name,
+        }
+    }
+}
+
+/// Flags, children, redirect, name, parser and suggestions type. Only the parts the node type has are sent
+impl PacketDataType for CommandNode {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let flags = u8::read(reader)?;
+        let length = limits::read_array_length(reader)?;
+        let mut children = limits::with_capacity(length);
+        for _ in 0..length {
+            children.push(VarInt::read(reader)?.0);
+        }
+        let redirect = if flags & HAS_REDIRECT != 0 {
+            Some(VarInt::read(reader)?.0)
+        } else {
+            None
+        };
+        let kind = match flags & NODE_TYPE_MASK {
+            0 => CommandNodeKind::Root,
+            1 => CommandNodeKind::Literal {
+                name: String::read(reader)?,
+            },
+            2 => CommandNodeKind::Argument {
+                name: String::read(reader)?,
+                parser: ArgumentParser::read(reader)?,
+                suggestions: if flags & HAS_SUGGESTIONS_TYPE != 0 {
+                    Some(String::read(reader)?)
+                } else {
+                    None
+                },
+            },
+            node_type => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unknown command node type {}", node_type),
+                ))
+            }
+        };
+        Ok(CommandNode {
+            kind,
+            executable: flags & EXECUTABLE != 0,
+            children,
+            redirect,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        let mut flags = match &self.kind {
+            CommandNodeKind::Root => 0,
+            CommandNodeKind::Literal { .. } => 1,
+            CommandNodeKind::Argument { suggestions, .. } => {
+                2 | if suggestions.is_some() {
+                    HAS_SUGGESTIONS_TYPE
+                } else {
+                    0
+                }
+            }
+        };
+        if self.executable {
+            flags |= EXECUTABLE;
+        }
+        if self.redirect.is_some() {
+            flags |= HAS_REDIRECT;
+        }
+        flags.write(writer)?;
+        VarInt(self.children.len() as i32).write(writer)?;
+        for child in self.children {
+            VarInt(child).write(writer)?;
+        }
+        if let Some(redirect) = self.redirect {
+            VarInt(redirect).write(writer)?;
+        }
+        match self.kind {
+            CommandNodeKind::Root => Ok(()),
+            CommandNodeKind::Literal { name } => name.write(writer),
+            CommandNodeKind::Argument {
+                name,
+                parser,
+                suggestions,
+            } => {
+                name.write(writer)?;
+                parser.write(writer)?;
+                if let Some(suggestions) = suggestions {
+                    suggestions.write(writer)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(i32)]
+#[error("Invalid String Behavior {0}")]
+#[packet_type(VarInt)]
+pub enum StringBehavior {
+    SingleWord = 0,
+    /// A single word or a quoted string
+    QuotablePhrase = 1,
+    /// The rest of the command
+    GreedyPhrase = 2,
+}
+
+/// How the client parses an argument
+///
+/// Written as the 1.19.3 parser id followed by the properties
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgumentParser {
+    Bool,
+    Float {
+        min: Option<f32>,
+        max: Option<f32>,
+    },
+    Double {
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Integer {
+        min: Option<i32>,
+        max: Option<i32>,
+    },
+    Long {
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    String(StringBehavior),
+    Entity {
+        single: bool,
+        players_only: bool,
+    },
+    ScoreHolder {
+        allow_multiple: bool,
+    },
+    ResourceOrTag {
+        registry: String,
+    },
+    ResourceOrTagKey {
+        registry: String,
+    },
+    Resource {
+        registry: String,
+    },
+    ResourceKey {
+        registry: String,
+    },
+    /// A parser without properties. Such as [ArgumentParser::BLOCK_POS]
+    Simple(i32),
+}
+
+impl ArgumentParser {
+    pub const BOOL: i32 = 0;
+    pub const FLOAT: i32 = 1;
+    pub const DOUBLE: i32 = 2;
+    pub const INTEGER: i32 = 3;
+    pub const LONG: i32 = 4;
+    pub const STRING: i32 = 5;
+    pub const ENTITY: i32 = 6;
+    pub const GAME_PROFILE: i32 = 7;
+    pub const BLOCK_POS: i32 = 8;
+    pub const COLUMN_POS: i32 = 9;
+    pub const VEC3: i32 = 10;
+    pub const VEC2: i32 = 11;
+    pub const BLOCK_STATE: i32 = 12;
+    pub const BLOCK_PREDICATE: i32 = 13;
+    pub const ITEM_STACK: i32 = 14;
+    pub const ITEM_PREDICATE: i32 = 15;
+    pub const COLOR: i32 = 16;
+    pub const COMPONENT: i32 = 17;
+    pub const MESSAGE: i32 = 18;
+    pub const NBT: i32 = 19;
+    pub const NBT_TAG: i32 = 20;
+    pub const NBT_PATH: i32 = 21;
+    pub const OBJECTIVE: i32 = 22;
+    pub const OBJECTIVE_CRITERIA: i32 = 23;
+    pub const OPERATION: i32 = 24;
+    pub const PARTICLE: i32 = 25;
+    pub const ANGLE: i32 = 26;
+    pub const ROTATION: i32 = 27;
+    pub const SCOREBOARD_SLOT: i32 = 28;
+    pub const SCORE_HOLDER: i32 = 29;
+    pub const SWIZZLE: i32 = 30;
+    pub const TEAM: i32 = 31;
+    pub const ITEM_SLOT: i32 = 32;
+    pub const RESOURCE_LOCATION: i32 = 33;
+    pub const FUNCTION: i32 = 34;
+    pub const ENTITY_ANCHOR: i32 = 35;
+    pub const INT_RANGE: i32 = 36;
+    pub const FLOAT_RANGE: i32 = 37;
+    pub const DIMENSION: i32 = 38;
+    pub const GAMEMODE: i32 = 39;
+    pub const TIME: i32 = 40;
+    pub const RESOURCE_OR_TAG: i32 = 41;
+    pub const RESOURCE_OR_TAG_KEY: i32 = 42;
+    pub const RESOURCE: i32 = 43;
+    pub const RESOURCE_KEY: i32 = 44;
+    pub const TEMPLATE_MIRROR: i32 = 45;
+    pub const TEMPLATE_ROTATION: i32 = 46;
+    pub const UUID: i32 = 47;
+
+    /// The id in the `minecraft:command_argument_type` registry
+    pub fn parser_id(&self) -> i32 {
+        match self {
+            ArgumentParser::Bool => Self::BOOL,
+            ArgumentParser::Float { .. } => Self::FLOAT,
+            ArgumentParser::Double { .. } => Self::DOUBLE,
+            ArgumentParser::Integer { .. } => Self::INTEGER,
+            ArgumentParser::Long { .. } => Self::LONG,
+            ArgumentParser::String(_) => Self::STRING,
+            ArgumentParser::Entity { .. } => Self::ENTITY,
+            ArgumentParser::ScoreHolder { .. } => Self::SCORE_HOLDER,
+            ArgumentParser::ResourceOrTag { .. } => Self::RESOURCE_OR_TAG,
+            ArgumentParser::ResourceOrTagKey { .. } => Self::RESOURCE_OR_TAG_KEY,
+            ArgumentParser::Resource { .. } => Self::RESOURCE,
+            ArgumentParser::ResourceKey { .. } => Self::RESOURCE_KEY,
+            ArgumentParser::Simple(id) => *id,
+        }
+    }
+}
+
+/// A flag byte saying which bounds follow
+fn read_range<Reader: Read, T: PacketDataType>(
+    reader: &mut Reader,
+) -> io::Result<(Option<T>, Option<T>)> {
+    let flags = u8::read(reader)?;
+    let min = if flags & 0x01 != 0 {
+        Some(T::read(reader)?)
+    } else {
+        None
+    };
+    let max = if flags & 0x02 != 0 {
+        Some(T::read(reader)?)
+    } else {
+        None
+    };
+    Ok((min, max))
+}
+
+fn write_range<Writer: Write, T: PacketDataType>(
+    min: Option<T>,
+    max: Option<T>,
+    writer: &mut Writer,
+) -> io::Result<()> {
+    let flags = min.is_some() as u8 | (max.is_some() as u8) << 1;
+    flags.write(writer)?;
+    if let Some(min) = min {
+        min.write(writer)?;
+    }
+    if let Some(max) = max {
+        max.write(writer)?;
+    }
+    Ok(())
+}
+
+impl PacketDataType for ArgumentParser {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let parser = match VarInt::read(reader)?.0 {
+            Self::BOOL => ArgumentParser::Bool,
+            Self::FLOAT => {
+                let (min, max) = read_range(reader)?;
+                ArgumentParser::Float { min, max }
+            }
+            Self::DOUBLE => {
+                let (min, max) = read_range(reader)?;
+                ArgumentParser::Double { min, max }
+            }
+            Self::INTEGER => {
+                let (min, max) = read_range(reader)?;
+                ArgumentParser::Integer { min, max }
+            }
+            Self::LONG => {
+                let (min, max) = read_range(reader)?;
+                ArgumentParser::Long { min, max }
+            }
+            Self::STRING => ArgumentParser::String(StringBehavior::read(reader)?),
+            Self::ENTITY => {
+                let flags = u8::read(reader)?;
+                ArgumentParser::Entity {
+                    single: flags & 0x01 != 0,
+                    players_only: flags & 0x02 != 0,
+                }
+            }
+            Self::SCORE_HOLDER => ArgumentParser::ScoreHolder {
+                allow_multiple: u8::read(reader)? & 0x01 != 0,
+            },
+            Self::RESOURCE_OR_TAG => ArgumentParser::ResourceOrTag {
+                registry: String::read(reader)?,
+            },
+            Self::RESOURCE_OR_TAG_KEY => ArgumentParser::ResourceOrTagKey {
+                registry: String::read(reader)?,
+            },
+            Self::RESOURCE => ArgumentParser::Resource {
+                registry: String::read(reader)?,
+            },
+            Self::RESOURCE_KEY => ArgumentParser::ResourceKey {
+                registry: String::read(reader)?,
+            },
+            id @ 0..=Self::UUID => ArgumentParser::Simple(id),
+            id => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unknown argument parser {}", id),
+                ))
+            }
+        };
+        Ok(parser)
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        VarInt(self.parser_id()).write(writer)?;
+        match self {
+            ArgumentParser::Bool | ArgumentParser::Simple(_) => Ok(()),
+            ArgumentParser::Float { min, max } => write_range(min, max, writer),
+            ArgumentParser::Double { min, max } => write_range(min, max, writer),
+            ArgumentParser::Integer { min, max } => write_range(min, max, writer),
+            ArgumentParser::Long { min, max } => write_range(min, max, writer),
+            ArgumentParser::String(behavior) => behavior.write(writer),
+            ArgumentParser::Entity {
+                single,
+                players_only,
+            } => (single as u8 | (players_only as u8) << 1).write(writer),
+            ArgumentParser::ScoreHolder { allow_multiple } => (allow_multiple as u8).write(writer),
+            ArgumentParser::ResourceOrTag { registry }
+            | ArgumentParser::ResourceOrTagKey { registry }
+            | ArgumentParser::Resource { registry }
+            | ArgumentParser::ResourceKey { registry } => registry.write(writer),
+        }
+    }
+}
+
+/// A node of a command tree. Flattened into the graph by [DeclareCommands::from_tree]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandTreeNode {
+    pub kind: CommandNodeKind,
+    pub executable: bool,
+    /// The names from the root to the node to redirect to. Empty is the root
+    pub redirect: Option<Vec<String>>,
+    pub children: Vec<CommandTreeNode>,
+}
+
+impl CommandTreeNode {
+    pub fn literal(name: impl Into<String>) -> Self {
+        Self::new(CommandNodeKind::Literal { name: name.into() })
+    }
+
+    pub fn argument(name: impl Into<String>, parser: ArgumentParser) -> Self {
+        Self::new(CommandNodeKind::Argument {
+            name: name.into(),
+            parser,
+            suggestions: None,
+        })
+    }
+
+    fn new(kind: CommandNodeKind) -> Self {
+        Self {
+            kind,
+            executable: false,
+            redirect: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn then(mut self, child: CommandTreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    pub fn executes(mut self) -> Self {
+        self.executable = true;
+        self
+    }
+
+    /// Only argument nodes have a suggestions type
+    pub fn suggests(mut self, suggestions_type: impl Into<String>) -> Self {
+        if let CommandNodeKind::Argument { suggestions, .. } = &mut self.kind {
+            *suggestions = Some(suggestions_type.into());
+        }
+        self
+    }
+
+    pub fn redirect<I: Into<String>>(mut self, path: impl IntoIterator<Item = I>) -> Self {
+        self.redirect = Some(path.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Redirect to unknown command node `{0}`")]
+pub struct UnknownRedirect(pub String);
+
+#[derive(Default)]
+struct GraphBuilder {
+    nodes: Vec<CommandNode>,
+    paths: HashMap<Vec<String>, i32>,
+    redirects: Vec<(usize, Vec<String>)>,
+}
+
+impl GraphBuilder {
+    fn add(&mut self, node: CommandTreeNode, parent: usize, path: &mut Vec<String>) {
+        let index = self.nodes.len();
+        path.push(node.kind.name().to_string());
+        self.paths.insert(path.clone(), index as i32);
+        self.nodes.push(CommandNode {
+            kind: node.kind,
+            executable: node.executable,
+            children: Vec::with_capacity(node.children.len()),
+            redirect: None,
+        });
+        self.nodes[parent].children.push(index as i32);
+        if let Some(redirect) = node.redirect {
+            self.redirects.push((index, redirect));
+        }
+        for child in node.children {
+            self.add(child, index, path);
+        }
+        path.pop();
+    }
+}
+
+impl DeclareCommands {
+    /// Flattens the commands into a graph under a new root node
+    pub fn from_tree(
+        commands: impl IntoIterator<Item = CommandTreeNode>,
+    ) -> Result<Self, UnknownRedirect> {
+        let mut builder = GraphBuilder::default();
+        builder.nodes.push(CommandNode {
+            kind: CommandNodeKind::Root,
+            executable: false,
+            children: Vec::new(),
+            redirect: None,
+        });
+        builder.paths.insert(Vec::new(), 0);
+        let mut path = Vec::new();
+        for command in commands {
+            builder.add(command, 0, &mut path);
+        }
+        for (index, redirect) in builder.redirects {
+            let target = builder
+                .paths
+                .get(&redirect)
+                .ok_or_else(|| UnknownRedirect(redirect.join(" ")))?;
+            builder.nodes[index].redirect = Some(*target);
+        }
+        Ok(DeclareCommands {
+            nodes: builder.nodes,
+            root_index: 0,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandSuggestion {
+    pub text: String,
+    pub tooltip: Option<Component>,
+}
+
+/// The answer to a Command Suggestions Request
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct CommandSuggestionsResponse {
+    /// The id of the request
+    pub transaction_id: i32,
+    /// The range of the text that is replaced
+    pub start: i32,
+    pub length: i32,
+    pub matches: Vec<CommandSuggestion>,
+}
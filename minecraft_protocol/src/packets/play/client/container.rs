@@ -0,0 +1,72 @@
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::chat::Component;
+use crate::data::slot::Slot;
+use crate::PacketContent;
+
+/// The window id of the player inventory. It is always open
+pub const PLAYER_INVENTORY: u8 = 0;
+
+/// Opens a container window. Not used for the player inventory
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct OpenScreen {
+    pub window_id: i32,
+    /// The id in the `minecraft:menu` registry
+    pub window_type: i32,
+    pub title: Component,
+}
+
+/// Replaces every slot of a window
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetContainerContent {
+    pub window_id: u8,
+    /// Echoed back by the client in Click Container
+    pub state_id: i32,
+    pub slots: Vec<Slot>,
+    /// The item on the cursor
+    pub carried_item: Slot,
+}
+
+/// Sets one slot of a window
+///
+/// There is no separate Set Cursor Item packet in 1.19. The cursor is set with the window and slot `-1`
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetContainerSlot {
+    /// `-1` is the cursor. `-2` is the player inventory no matter what window is open
+    pub window_id: i8,
+    pub state_id: i32,
+    pub slot: i16,
+    pub item: Slot,
+}
+
+impl SetContainerSlot {
+    pub const CURSOR: i8 = -1;
+    pub const PLAYER_INVENTORY: i8 = -2;
+
+    /// Sets the item on the cursor
+    pub fn cursor(state_id: i32, item: Slot) -> Self {
+        Self {
+            window_id: Self::CURSOR,
+            state_id,
+            slot: -1,
+            item,
+        }
+    }
+
+    pub fn is_cursor(&self) -> bool {
+        self.window_id == Self::CURSOR && self.slot == -1
+    }
+}
+
+/// Such as the progress arrow of a furnace. The meaning of the property depends on the window type
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetContainerProperty {
+    pub window_id: u8,
+    pub property: i16,
+    pub value: i16,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct CloseContainer {
+    pub window_id: u8,
+}
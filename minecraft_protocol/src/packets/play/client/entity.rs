@@ -0,0 +1,318 @@
+use std::io;
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::data::slot::Slot;
+use crate::data::PacketDataType;
+use crate::packets::play::client::metadata::MetadataEntry;
+use crate::packets::play::client::ClientBoundPlay;
+use crate::PacketContent;
+
+/// A rotation in steps of 1/256 of a full turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Angle(pub u8);
+
+impl Angle {
+    pub fn from_degrees(degrees: f32) -> Self {
+        Angle((degrees * 256.0 / 360.0).floor() as i32 as u8)
+    }
+
+    pub fn to_degrees(self) -> f32 {
+        self.0 as f32 * 360.0 / 256.0
+    }
+}
+
+impl PacketDataType for Angle {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        u8::read(reader).map(Angle)
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        self.0.write(writer)
+    }
+}
+
+/// Velocity in units of 1/8000 of a block per tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Velocity {
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+}
+
+impl Velocity {
+    /// The client clamps the velocity to 3.9 blocks per tick
+    pub const MAX_BLOCKS_PER_TICK: f64 = 3.9;
+
+    pub fn from_blocks_per_tick(x: f64, y: f64, z: f64) -> Self {
+        let encode = |value: f64| {
+            (value.clamp(-Self::MAX_BLOCKS_PER_TICK, Self::MAX_BLOCKS_PER_TICK) * 8000.0) as i16
+        };
+        Velocity {
+            x: encode(x),
+            y: encode(y),
+            z: encode(z),
+        }
+    }
+}
+
+impl PacketDataType for Velocity {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Velocity {
+            x: i16::read(reader)?,
+            y: i16::read(reader)?,
+            z: i16::read(reader)?,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        self.x.write(writer)?;
+        self.y.write(writer)?;
+        self.z.write(writer)
+    }
+}
+
+/// Spawns any entity other than players and experience orbs
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SpawnEntity {
+    pub entity_id: i32,
+    pub uuid: Uuid,
+    /// The id in the `minecraft:entity_type` registry
+    pub entity_type: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub pitch: Angle,
+    pub yaw: Angle,
+    pub head_yaw: Angle,
+    /// Depends on the entity type. Such as the block state of a falling block
+    pub data: i32,
+    pub velocity: Velocity,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SpawnExperienceOrb {
+    pub entity_id: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    /// The amount of experience the orb gives
+    pub count: i16,
+}
+
+/// Spawns another player. The player must be in the player info list first
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SpawnPlayer {
+    pub entity_id: i32,
+    pub uuid: Uuid,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+}
+
+/// Moves an entity less than 8 blocks. The deltas are in 1/4096 of a block
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UpdateEntityPosition {
+    pub entity_id: i32,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+    pub on_ground: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UpdateEntityPositionAndRotation {
+    pub entity_id: i32,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub on_ground: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UpdateEntityRotation {
+    pub entity_id: i32,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub on_ground: bool,
+}
+
+/// Moves an entity to an absolute position
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct TeleportEntity {
+    pub entity_id: i32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: Angle,
+    pub pitch: Angle,
+    pub on_ground: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetHeadRotation {
+    pub entity_id: i32,
+    pub head_yaw: Angle,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetEntityVelocity {
+    pub entity_id: i32,
+    pub velocity: Velocity,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct RemoveEntities {
+    pub entity_ids: Vec<i32>,
+}
+
+/// Only the changed entries need to be sent
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetEntityMetadata {
+    pub entity_id: i32,
+    pub metadata: Vec<MetadataEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(u8)]
+#[error("Invalid Equipment Slot {0}")]
+pub enum EquipmentSlot {
+    MainHand = 0,
+    OffHand = 1,
+    Boots = 2,
+    Leggings = 3,
+    Chestplate = 4,
+    Helmet = 5,
+}
+
+/// Sent as the slot with the top bit set if another entry follows
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetEquipment {
+    pub entity_id: i32,
+    pub equipment: Vec<(EquipmentSlot, Slot)>,
+}
+
+/// The position, yaw and pitch of an entity
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EntityLocation {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+/// Tracks what the client last received for an entity and encodes moves as deltas
+///
+/// Falls back to [TeleportEntity] when a move is too long for a delta
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityMovement {
+    entity_id: i32,
+    /// The position in 1/4096 of a block. So rounding errors do not add up
+    encoded: (i64, i64, i64),
+    yaw: Angle,
+    pitch: Angle,
+}
+
+impl EntityMovement {
+    pub fn new(entity_id: i32, location: EntityLocation) -> Self {
+        Self {
+            entity_id,
+            encoded: Self::encode(&location),
+            yaw: Angle::from_degrees(location.yaw),
+            pitch: Angle::from_degrees(location.pitch),
+        }
+    }
+
+    fn encode(location: &EntityLocation) -> (i64, i64, i64) {
+        let encode = |value: f64| (value * 4096.0).round() as i64;
+        (encode(location.x), encode(location.y), encode(location.z))
+    }
+
+    pub fn entity_id(&self) -> i32 {
+        self.entity_id
+    }
+
+    /// Returns the packet that moves the entity to the location. None if nothing the client sees changed
+    pub fn move_to(
+        &mut self,
+        location: EntityLocation,
+        on_ground: bool,
+    ) -> Option<ClientBoundPlay> {
+        let encoded = Self::encode(&location);
+        let yaw = Angle::from_degrees(location.yaw);
+        let pitch = Angle::from_degrees(location.pitch);
+        let delta = (
+            encoded.0 - self.encoded.0,
+            encoded.1 - self.encoded.1,
+            encoded.2 - self.encoded.2,
+        );
+        let rotated = yaw != self.yaw || pitch != self.pitch;
+        let moved = delta != (0, 0, 0);
+        let entity_id = self.entity_id;
+        self.encoded = encoded;
+        self.yaw = yaw;
+        self.pitch = pitch;
+
+        let delta = match (
+            i16::try_from(delta.0),
+            i16::try_from(delta.1),
+            i16::try_from(delta.2),
+        ) {
+            (Ok(x), Ok(y), Ok(z)) => (x, y, z),
+            _ => {
+                return Some(ClientBoundPlay::TeleportEntity(TeleportEntity {
+                    entity_id,
+                    x: location.x,
+                    y: location.y,
+                    z: location.z,
+                    yaw,
+                    pitch,
+                    on_ground,
+                }))
+            }
+        };
+        let packet = match (moved, rotated) {
+            (false, false) => return None,
+            (true, false) => ClientBoundPlay::UpdateEntityPosition(UpdateEntityPosition {
+                entity_id,
+                delta_x: delta.0,
+                delta_y: delta.1,
+                delta_z: delta.2,
+                on_ground,
+            }),
+            (false, true) => ClientBoundPlay::UpdateEntityRotation(UpdateEntityRotation {
+                entity_id,
+                yaw,
+                pitch,
+                on_ground,
+            }),
+            (true, true) => {
+                ClientBoundPlay::UpdateEntityPositionAndRotation(UpdateEntityPositionAndRotation {
+                    entity_id,
+                    delta_x: delta.0,
+                    delta_y: delta.1,
+                    delta_z: delta.2,
+                    yaw,
+                    pitch,
+                    on_ground,
+                })
+            }
+        };
+        Some(packet)
+    }
+}
@@ -0,0 +1,153 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::chat::Component;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::PacketContent;
+
+/// The title is shown once the times are sent. Or with the times of the last title
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetTitleText(pub Component);
+
+impl From<Component> for SetTitleText {
+    fn from(text: Component) -> Self {
+        Self(text)
+    }
+}
+
+/// Shown under the title. The subtitle is only shown with a title
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetSubtitleText(pub Component);
+
+impl From<Component> for SetSubtitleText {
+    fn from(text: Component) -> Self {
+        Self(text)
+    }
+}
+
+/// Shown above the hotbar
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetActionBarText(pub Component);
+
+impl From<Component> for SetActionBarText {
+    fn from(text: Component) -> Self {
+        Self(text)
+    }
+}
+
+/// In ticks
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct SetTitleAnimationTimes {
+    pub fade_in: i32,
+    pub stay: i32,
+    pub fade_out: i32,
+}
+
+impl Default for SetTitleAnimationTimes {
+    /// The vanilla times
+    fn default() -> Self {
+        Self {
+            fade_in: 10,
+            stay: 70,
+            fade_out: 20,
+        }
+    }
+}
+
+/// Hides the title and subtitle
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct ClearTitles {
+    /// Also resets the text and times
+    pub reset: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetTabListHeaderAndFooter {
+    pub header: Component,
+    pub footer: Component,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PacketContentType, PacketEnum,
+)]
+#[repr(i32)]
+#[error("Invalid Boss Bar Color {0}")]
+#[packet_type(VarInt)]
+pub enum BossBarColor {
+    Pink = 0,
+    Blue = 1,
+    Red = 2,
+    Green = 3,
+    Yellow = 4,
+    Purple = 5,
+    White = 6,
+}
+
+/// How many notches the bar is split into
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PacketContentType, PacketEnum,
+)]
+#[repr(i32)]
+#[error("Invalid Boss Bar Division {0}")]
+#[packet_type(VarInt)]
+pub enum BossBarDivision {
+    None = 0,
+    Notches6 = 1,
+    Notches10 = 2,
+    Notches12 = 3,
+    Notches20 = 4,
+}
+
+bitflags! {
+     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+     pub struct BossBarFlags: u8 {
+        const DARKEN_SKY = 0b0000_0001;
+        /// Plays the end music
+        const DRAGON_BAR = 0b0000_0010;
+        const CREATE_FOG = 0b0000_0100;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BossBarAction {
+    Add {
+        title: Component,
+        /// From 0 to 1
+        health: f32,
+        color: BossBarColor,
+        division: BossBarDivision,
+        flags: BossBarFlags,
+    },
+    Remove,
+    UpdateHealth(f32),
+    UpdateTitle(Component),
+    UpdateStyle {
+        color: BossBarColor,
+        division: BossBarDivision,
+    },
+    UpdateFlags(BossBarFlags),
+}
+
+impl BossBarAction {
+    pub fn action_id(&self) -> i32 {
+        match self {
+            BossBarAction::Add { .. } => 0,
+            BossBarAction::Remove => 1,
+            BossBarAction::UpdateHealth(_) => 2,
+            BossBarAction::UpdateTitle(_) => 3,
+            BossBarAction::UpdateStyle { .. } => 4,
+            BossBarAction::UpdateFlags(_) => 5,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct BossBar {
+    /// Picked by the server. Identifies the bar in later updates
+    pub uuid: Uuid,
+    pub action: BossBarAction,
+}
@@ -0,0 +1,320 @@
+use std::io;
+use std::io::{Read, Write};
+
+use nbt::Blob;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use minecraft_protocol_macros::PacketEnum;
+
+use crate::chat::Component;
+use crate::data::slot::Slot;
+use crate::data::var_int::VarInt;
+use crate::data::var_long::VarLong;
+use crate::data::{PackedPosition, PacketDataType};
+use crate::packets::play::client::particle::Particle;
+
+/// Marks the end of the metadata
+pub const METADATA_END: u8 = 0xFF;
+
+/// One entry of the entity metadata
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataEntry {
+    /// The field index. Depends on the entity type
+    pub index: u8,
+    pub value: EntityMetadataValue,
+}
+
+impl MetadataEntry {
+    pub fn new(index: u8, value: impl Into<EntityMetadataValue>) -> Self {
+        Self {
+            index,
+            value: value.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(i32)]
+#[error("Invalid Direction {0}")]
+#[packet_type(VarInt)]
+pub enum Direction {
+    Down = 0,
+    Up = 1,
+    North = 2,
+    South = 3,
+    West = 4,
+    East = 5,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(i32)]
+#[error("Invalid Pose {0}")]
+#[packet_type(VarInt)]
+pub enum Pose {
+    Standing = 0,
+    FallFlying = 1,
+    Sleeping = 2,
+    Swimming = 3,
+    SpinAttack = 4,
+    Crouching = 5,
+    LongJumping = 6,
+    Dying = 7,
+    Croaking = 8,
+    UsingTongue = 9,
+    Roaring = 10,
+    Sniffing = 11,
+    Emerging = 12,
+    Digging = 13,
+}
+
+/// A position in a dimension. Such as the last death location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobalPosition {
+    pub dimension: String,
+    pub position: PackedPosition,
+}
+
+/// The value of a metadata entry. One variant per metadata serializer
+///
+/// Written as the 1.19.3 serializer id followed by the value
+#[derive(Debug, Clone, PartialEq)]
+pub enum EntityMetadataValue {
+    Byte(i8),
+    VarInt(i32),
+    VarLong(i64),
+    Float(f32),
+    String(String),
+    Component(Component),
+    OptionalComponent(Option<Component>),
+    Slot(Slot),
+    Boolean(bool),
+    Rotations {
+        x: f32,
+        y: f32,
+        z: f32,
+    },
+    Position(PackedPosition),
+    OptionalPosition(Option<PackedPosition>),
+    Direction(Direction),
+    OptionalUuid(Option<Uuid>),
+    /// None is air. Sent as zero
+    OptionalBlockState(Option<i32>),
+    Nbt(Blob),
+    Particle(Particle),
+    VillagerData {
+        villager_type: i32,
+        profession: i32,
+        level: i32,
+    },
+    /// Sent as the value plus one. Zero is None
+    OptionalVarInt(Option<i32>),
+    Pose(Pose),
+    CatVariant(i32),
+    FrogVariant(i32),
+    OptionalGlobalPosition(Option<GlobalPosition>),
+    PaintingVariant(i32),
+}
+
+impl EntityMetadataValue {
+    /// The id of the serializer in 1.19.3
+    pub fn serializer_id(&self) -> i32 {
+        match self {
+            EntityMetadataValue::Byte(_) => 0,
+            EntityMetadataValue::VarInt(_) => 1,
+            EntityMetadataValue::VarLong(_) => 2,
+            EntityMetadataValue::Float(_) => 3,
+            EntityMetadataValue::String(_) => 4,
+            EntityMetadataValue::Component(_) => 5,
+            EntityMetadataValue::OptionalComponent(_) => 6,
+            EntityMetadataValue::Slot(_) => 7,
+            EntityMetadataValue::Boolean(_) => 8,
+            EntityMetadataValue::Rotations { .. } => 9,
+            EntityMetadataValue::Position(_) => 10,
+            EntityMetadataValue::OptionalPosition(_) => 11,
+            EntityMetadataValue::Direction(_) => 12,
+            EntityMetadataValue::OptionalUuid(_) => 13,
+            EntityMetadataValue::OptionalBlockState(_) => 14,
+            EntityMetadataValue::Nbt(_) => 15,
+            EntityMetadataValue::Particle(_) => 16,
+            EntityMetadataValue::VillagerData { .. } => 17,
+            EntityMetadataValue::OptionalVarInt(_) => 18,
+            EntityMetadataValue::Pose(_) => 19,
+            EntityMetadataValue::CatVariant(_) => 20,
+            EntityMetadataValue::FrogVariant(_) => 21,
+            EntityMetadataValue::OptionalGlobalPosition(_) => 22,
+            EntityMetadataValue::PaintingVariant(_) => 23,
+        }
+    }
+}
+
+fn read_optional<Reader: Read, T>(
+    reader: &mut Reader,
+    read: impl FnOnce(&mut Reader) -> io::Result<T>,
+) -> io::Result<Option<T>> {
+    if bool::read(reader)? {
+        read(reader).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn write_optional<Writer: Write, T: PacketDataType>(
+    value: Option<T>,
+    writer: &mut Writer,
+) -> io::Result<()> {
+    match value {
+        Some(value) => {
+            true.write(writer)?;
+            value.write(writer)
+        }
+        None => false.write(writer),
+    }
+}
+
+impl PacketDataType for EntityMetadataValue {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let value = match VarInt::read(reader)?.0 {
+            0 => EntityMetadataValue::Byte(i8::read(reader)?),
+            1 => EntityMetadataValue::VarInt(VarInt::read(reader)?.0),
+            2 => EntityMetadataValue::VarLong(VarLong::read(reader)?.0),
+            3 => EntityMetadataValue::Float(f32::read(reader)?),
+            4 => EntityMetadataValue::String(String::read(reader)?),
+            5 => EntityMetadataValue::Component(Component::read(reader)?),
+            6 => EntityMetadataValue::OptionalComponent(read_optional(reader, Component::read)?),
+            7 => EntityMetadataValue::Slot(Slot::read(reader)?),
+            8 => EntityMetadataValue::Boolean(bool::read(reader)?),
+            9 => EntityMetadataValue::Rotations {
+                x: f32::read(reader)?,
+                y: f32::read(reader)?,
+                z: f32::read(reader)?,
+            },
+            10 => EntityMetadataValue::Position(PackedPosition::read(reader)?),
+            11 => {
+                EntityMetadataValue::OptionalPosition(read_optional(reader, PackedPosition::read)?)
+            }
+            12 => EntityMetadataValue::Direction(Direction::read(reader)?),
+            13 => EntityMetadataValue::OptionalUuid(read_optional(reader, Uuid::read)?),
+            14 => EntityMetadataValue::OptionalBlockState(match VarInt::read(reader)?.0 {
+                0 => None,
+                block_state => Some(block_state),
+            }),
+            15 => EntityMetadataValue::Nbt(Blob::read(reader)?),
+            16 => EntityMetadataValue::Particle(Particle::read(reader)?),
+            17 => EntityMetadataValue::VillagerData {
+                villager_type: VarInt::read(reader)?.0,
+                profession: VarInt::read(reader)?.0,
+                level: VarInt::read(reader)?.0,
+            },
+            18 => EntityMetadataValue::OptionalVarInt(match VarInt::read(reader)?.0 {
+                0 => None,
+                value if value > 0 => Some(value - 1),
+                value => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("Invalid optional VarInt {}", value),
+                    ))
+                }
+            }),
+            19 => EntityMetadataValue::Pose(Pose::read(reader)?),
+            20 => EntityMetadataValue::CatVariant(VarInt::read(reader)?.0),
+            21 => EntityMetadataValue::FrogVariant(VarInt::read(reader)?.0),
+            22 => EntityMetadataValue::OptionalGlobalPosition(read_optional(reader, |reader| {
+                Ok(GlobalPosition {
+                    dimension: String::read(reader)?,
+                    position: PackedPosition::read(reader)?,
+                })
+            })?),
+            23 => EntityMetadataValue::PaintingVariant(VarInt::read(reader)?.0),
+            id => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Unknown metadata serializer {}", id),
+                ))
+            }
+        };
+        Ok(value)
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        VarInt(self.serializer_id()).write(writer)?;
+        match self {
+            EntityMetadataValue::Byte(value) => value.write(writer),
+            EntityMetadataValue::VarInt(value)
+            | EntityMetadataValue::CatVariant(value)
+            | EntityMetadataValue::FrogVariant(value)
+            | EntityMetadataValue::PaintingVariant(value) => VarInt(value).write(writer),
+            EntityMetadataValue::VarLong(value) => VarLong(value).write(writer),
+            EntityMetadataValue::Float(value) => value.write(writer),
+            EntityMetadataValue::String(value) => value.write(writer),
+            EntityMetadataValue::Component(value) => value.write(writer),
+            EntityMetadataValue::OptionalComponent(value) => write_optional(value, writer),
+            EntityMetadataValue::Slot(value) => value.write(writer),
+            EntityMetadataValue::Boolean(value) => value.write(writer),
+            EntityMetadataValue::Rotations { x, y, z } => {
+                x.write(writer)?;
+                y.write(writer)?;
+                z.write(writer)
+            }
+            EntityMetadataValue::Position(value) => value.write(writer),
+            EntityMetadataValue::OptionalPosition(value) => write_optional(value, writer),
+            EntityMetadataValue::Direction(value) => value.write(writer),
+            EntityMetadataValue::OptionalUuid(value) => write_optional(value, writer),
+            EntityMetadataValue::OptionalBlockState(value) => {
+                VarInt(value.unwrap_or(0)).write(writer)
+            }
+            EntityMetadataValue::Nbt(value) => value.write(writer),
+            EntityMetadataValue::Particle(value) => value.write(writer),
+            EntityMetadataValue::VillagerData {
+                villager_type,
+                profession,
+                level,
+            } => {
+                VarInt(villager_type).write(writer)?;
+                VarInt(profession).write(writer)?;
+                VarInt(level).write(writer)
+            }
+            EntityMetadataValue::OptionalVarInt(value) => {
+                VarInt(value.map_or(0, |value| value + 1)).write(writer)
+            }
+            EntityMetadataValue::Pose(value) => value.write(writer),
+            EntityMetadataValue::OptionalGlobalPosition(value) => match value {
+                Some(value) => {
+                    true.write(writer)?;
+                    value.dimension.write(writer)?;
+                    value.position.write(writer)
+                }
+                None => false.write(writer),
+            },
+        }
+    }
+}
+
+macro_rules! metadata_from {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl From<$t> for EntityMetadataValue {
+                fn from(value: $t) -> Self {
+                    EntityMetadataValue::$variant(value)
+                }
+            }
+        )*
+    };
+}
+metadata_from!(
+    i8 => Byte,
+    i32 => VarInt,
+    i64 => VarLong,
+    f32 => Float,
+    String => String,
+    Component => Component,
+    Slot => Slot,
+    bool => Boolean,
+    PackedPosition => Position,
+    Direction => Direction,
+    Pose => Pose,
+    Particle => Particle
+);
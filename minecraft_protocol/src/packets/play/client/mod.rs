@@ -3,17 +3,63 @@ use serde::{Deserialize, Serialize};
 
 use minecraft_protocol_macros::{PacketContentType, PacketEnum};
 
+use crate::chat::Component;
 use crate::data::PacketDataType;
 use crate::packets::define_group;
-use crate::packets::play::client::chunk::{ChunkDataAndLight, UpdateLightPacket};
+use crate::packets::play::client::block::{
+    AcknowledgeBlockChange, BlockAction, BlockEntityData, BlockUpdate, UpdateSectionBlocks,
+};
+use crate::packets::play::client::chat::{
+    DisguisedChatMessage, PlayerChatMessage, SystemChatMessage,
+};
+use crate::packets::play::client::chunk::{
+    ChunkDataAndLight, SetCenterChunk, UnloadChunk, UpdateLightPacket,
+};
+use crate::packets::play::client::commands::{CommandSuggestionsResponse, DeclareCommands};
+use crate::packets::play::client::container::{
+    CloseContainer, OpenScreen, SetContainerContent, SetContainerProperty, SetContainerSlot,
+};
+use crate::packets::play::client::entity::{
+    RemoveEntities, SetEntityMetadata, SetEntityVelocity, SetEquipment, SetHeadRotation,
+    SpawnEntity, SpawnExperienceOrb, SpawnPlayer, TeleportEntity, UpdateEntityPosition,
+    UpdateEntityPositionAndRotation, UpdateEntityRotation,
+};
+use crate::packets::play::client::hud::{
+    BossBar, ClearTitles, SetActionBarText, SetSubtitleText, SetTabListHeaderAndFooter,
+    SetTitleAnimationTimes, SetTitleText,
+};
 pub use crate::packets::play::client::login::LoginPacket;
-use crate::packets::play::client::player_info::{PlayerInfo, SyncPlayerPosition};
+use crate::packets::play::client::particle::ParticlePacket;
+use crate::packets::play::client::player_info::{
+    PlayerInfoRemove, PlayerInfoUpdate, SyncPlayerPosition,
+};
+use crate::packets::play::client::respawn::{Respawn, SetDefaultSpawnPosition};
+use crate::packets::play::client::scoreboard::{
+    DisplayObjective, UpdateObjectives, UpdateScore, UpdateTeams,
+};
+use crate::packets::play::client::sound::{EntitySoundEffect, SoundEffect, StopSound};
+use crate::packets::play::client::world::{
+    GameEvent, InitializeWorldBorder, SetBorderCenter, SetBorderLerpSize, SetBorderSize,
+    SetBorderWarningDelay, SetBorderWarningDistance, UpdateTime,
+};
 use crate::packets::play::{KeepAlive, PlayPing, PlayPluginMessage};
 use crate::PacketContent;
 
+pub mod block;
+pub mod chat;
 pub mod chunk;
+pub mod commands;
+pub mod container;
+pub mod entity;
+pub mod hud;
 pub mod login;
+pub mod metadata;
+pub mod particle;
 pub mod player_info;
+pub mod respawn;
+pub mod scoreboard;
+pub mod sound;
+pub mod world;
 
 define_group!(ClientBoundPlay {
     Login: LoginPacket,
@@ -25,17 +71,74 @@ define_group!(ClientBoundPlay {
     KeepAlive: KeepAlive,
     Ping: PlayPing,
     SyncPlayerPosition: SyncPlayerPosition,
-    PlayerInfo: PlayerInfo,
+    PlayerInfoUpdate: PlayerInfoUpdate,
+    PlayerInfoRemove: PlayerInfoRemove,
     ChunkData: ChunkDataAndLight,
-    UpdateLight: UpdateLightPacket
+    UpdateLight: UpdateLightPacket,
+    SystemChat: SystemChatMessage,
+    PlayerChat: PlayerChatMessage,
+    DisguisedChat: DisguisedChatMessage,
+    BlockUpdate: BlockUpdate,
+    UpdateSectionBlocks: UpdateSectionBlocks,
+    BlockEntityData: BlockEntityData,
+    BlockAction: BlockAction,
+    AcknowledgeBlockChange: AcknowledgeBlockChange,
+    SpawnEntity: SpawnEntity,
+    SpawnExperienceOrb: SpawnExperienceOrb,
+    SpawnPlayer: SpawnPlayer,
+    UpdateEntityPosition: UpdateEntityPosition,
+    UpdateEntityPositionAndRotation: UpdateEntityPositionAndRotation,
+    UpdateEntityRotation: UpdateEntityRotation,
+    TeleportEntity: TeleportEntity,
+    SetHeadRotation: SetHeadRotation,
+    SetEntityVelocity: SetEntityVelocity,
+    RemoveEntities: RemoveEntities,
+    SetEntityMetadata: SetEntityMetadata,
+    SetEquipment: SetEquipment,
+    OpenScreen: OpenScreen,
+    SetContainerContent: SetContainerContent,
+    SetContainerSlot: SetContainerSlot,
+    SetContainerProperty: SetContainerProperty,
+    CloseContainer: CloseContainer,
+    SelectedSlot: SelectedSlotPacket,
+    DeclareCommands: DeclareCommands,
+    CommandSuggestions: CommandSuggestionsResponse,
+    ResourcePack: ResourcePack,
+    BossBar: BossBar,
+    ClearTitles: ClearTitles,
+    SetActionBarText: SetActionBarText,
+    SetSubtitleText: SetSubtitleText,
+    SetTitleText: SetTitleText,
+    SetTitleAnimationTimes: SetTitleAnimationTimes,
+    SetTabListHeaderAndFooter: SetTabListHeaderAndFooter,
+    UpdateObjectives: UpdateObjectives,
+    DisplayObjective: DisplayObjective,
+    UpdateScore: UpdateScore,
+    UpdateTeams: UpdateTeams,
+    InitializeWorldBorder: InitializeWorldBorder,
+    SetBorderCenter: SetBorderCenter,
+    SetBorderLerpSize: SetBorderLerpSize,
+    SetBorderSize: SetBorderSize,
+    SetBorderWarningDelay: SetBorderWarningDelay,
+    SetBorderWarningDistance: SetBorderWarningDistance,
+    UpdateTime: UpdateTime,
+    GameEvent: GameEvent,
+    SoundEffect: SoundEffect,
+    EntitySoundEffect: EntitySoundEffect,
+    StopSound: StopSound,
+    Particle: ParticlePacket,
+    Respawn: Respawn,
+    SetDefaultSpawnPosition: SetDefaultSpawnPosition,
+    SetCenterChunk: SetCenterChunk,
+    UnloadChunk: UnloadChunk
 });
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PacketContentType)]
-pub struct DisconnectPacket(pub String);
+pub struct DisconnectPacket(pub Component);
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ServerData {
-    pub motd: Option<String>,
+    pub motd: Option<Component>,
     pub icon: Option<String>,
     pub previews_chat: bool,
     pub enforced_secure_chat: bool,
@@ -78,3 +181,31 @@ pub struct AbilitiesPacket {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PacketContentType)]
 pub struct SelectedSlotPacket(pub i32);
+
+/// Asks the client to download and apply a resource pack
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct ResourcePack {
+    pub url: String,
+    /// The SHA-1 of the pack as lowercase hex. The client skips the check if it is empty
+    pub hash: String,
+    /// The client is told it will be disconnected if it declines
+    pub forced: bool,
+    /// Shown in the prompt under the default text
+    pub prompt: Option<Component>,
+}
+
+impl ResourcePack {
+    pub fn new(url: impl Into<String>, sha1: [u8; 20], forced: bool) -> Self {
+        Self {
+            url: url.into(),
+            hash: sha1.iter().map(|byte| format!("{:02x}", byte)).collect(),
+            forced,
+            prompt: None,
+        }
+    }
+
+    pub fn with_prompt(mut self, prompt: impl Into<Component>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+}
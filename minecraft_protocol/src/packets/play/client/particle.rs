@@ -0,0 +1,228 @@
+use std::io;
+use std::io::{Read, Write};
+
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::data::slot::Slot;
+use crate::data::var_int::VarInt;
+use crate::data::{PackedPosition, PacketDataType};
+use crate::PacketContent;
+
+/// A particle type with its extra data
+///
+/// The particle type is an id in the `minecraft:particle_type` registry. The ids of the particles with extra data are the 1.19.3 ids
+#[derive(Debug, Clone, PartialEq)]
+pub struct Particle {
+    pub particle_type: i32,
+    pub data: ParticleData,
+}
+
+impl Particle {
+    pub const BLOCK: i32 = 2;
+    pub const BLOCK_MARKER: i32 = 3;
+    pub const DUST: i32 = 14;
+    pub const DUST_COLOR_TRANSITION: i32 = 15;
+    pub const FALLING_DUST: i32 = 25;
+    pub const SCULK_CHARGE: i32 = 30;
+    pub const ITEM: i32 = 39;
+    pub const VIBRATION: i32 = 40;
+    pub const SHRIEK: i32 = 92;
+
+    /// A particle without extra data
+    pub fn simple(particle_type: i32) -> Self {
+        Self {
+            particle_type,
+            data: ParticleData::None,
+        }
+    }
+}
+
+/// The type id followed by the data
+impl PacketDataType for Particle {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let particle_type = VarInt::read(reader)?.0;
+        Ok(Particle {
+            particle_type,
+            data: ParticleData::read(particle_type, reader)?,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        VarInt(self.particle_type).write(writer)?;
+        self.data.write(writer)
+    }
+}
+
+/// Spawns `count` particles around a position
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct ParticlePacket {
+    pub particle: Particle,
+    /// Renders up to 65536 blocks away instead of 256
+    pub long_distance: bool,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    /// Multiplied by a random gaussian for the position of each particle
+    pub offset_x: f32,
+    pub offset_y: f32,
+    pub offset_z: f32,
+    pub max_speed: f32,
+    /// Zero spawns one particle moving in the direction of the offset
+    pub count: i32,
+}
+
+impl ParticlePacket {
+    /// A single particle without offset or speed
+    pub fn new(particle: Particle, x: f64, y: f64, z: f64) -> Self {
+        Self {
+            particle,
+            long_distance: false,
+            x,
+            y,
+            z,
+            offset_x: 0.0,
+            offset_y: 0.0,
+            offset_z: 0.0,
+            max_speed: 0.0,
+            count: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParticleData {
+    None,
+    /// `block`, `block_marker` and `falling_dust`
+    BlockState(i32),
+    Dust {
+        color: [f32; 3],
+        scale: f32,
+    },
+    DustColorTransition {
+        from: [f32; 3],
+        scale: f32,
+        to: [f32; 3],
+    },
+    Item(Slot),
+    Vibration {
+        source: PositionSource,
+        ticks: i32,
+    },
+    SculkCharge {
+        roll: f32,
+    },
+    Shriek {
+        delay: i32,
+    },
+}
+
+impl ParticleData {
+    /// The data is not prefixed. So the particle type decides what is read
+    pub fn read<Reader: Read>(particle_type: i32, reader: &mut Reader) -> io::Result<Self> {
+        let data = match particle_type {
+            Particle::BLOCK | Particle::BLOCK_MARKER | Particle::FALLING_DUST => {
+                ParticleData::BlockState(VarInt::read(reader)?.0)
+            }
+            Particle::DUST => ParticleData::Dust {
+                color: read_color(reader)?,
+                scale: f32::read(reader)?,
+            },
+            Particle::DUST_COLOR_TRANSITION => ParticleData::DustColorTransition {
+                from: read_color(reader)?,
+                scale: f32::read(reader)?,
+                to: read_color(reader)?,
+            },
+            Particle::ITEM => ParticleData::Item(Slot::read(reader)?),
+            Particle::VIBRATION => ParticleData::Vibration {
+                source: PositionSource::read(reader)?,
+                ticks: VarInt::read(reader)?.0,
+            },
+            Particle::SCULK_CHARGE => ParticleData::SculkCharge {
+                roll: f32::read(reader)?,
+            },
+            Particle::SHRIEK => ParticleData::Shriek {
+                delay: VarInt::read(reader)?.0,
+            },
+            _ => ParticleData::None,
+        };
+        Ok(data)
+    }
+
+    pub fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        match self {
+            ParticleData::None => Ok(()),
+            ParticleData::BlockState(block_state) => VarInt(block_state).write(writer),
+            ParticleData::Dust { color, scale } => {
+                write_color(color, writer)?;
+                scale.write(writer)
+            }
+            ParticleData::DustColorTransition { from, scale, to } => {
+                write_color(from, writer)?;
+                scale.write(writer)?;
+                write_color(to, writer)
+            }
+            ParticleData::Item(slot) => slot.write(writer),
+            ParticleData::Vibration { source, ticks } => {
+                source.write(writer)?;
+                VarInt(ticks).write(writer)
+            }
+            ParticleData::SculkCharge { roll } => roll.write(writer),
+            ParticleData::Shriek { delay } => VarInt(delay).write(writer),
+        }
+    }
+}
+
+fn read_color<Reader: Read>(reader: &mut Reader) -> io::Result<[f32; 3]> {
+    Ok([f32::read(reader)?, f32::read(reader)?, f32::read(reader)?])
+}
+
+fn write_color<Writer: Write>(color: [f32; 3], writer: &mut Writer) -> io::Result<()> {
+    color.into_iter().try_for_each(|value| value.write(writer))
+}
+
+/// Where a vibration travels to
+#[derive(Debug, Clone, PartialEq)]
+pub enum PositionSource {
+    Block(PackedPosition),
+    Entity { entity_id: i32, eye_height: f32 },
+}
+
+/// The source type as an identifier followed by the position
+impl PacketDataType for PositionSource {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        match String::read(reader)?.as_str() {
+            "minecraft:block" => Ok(PositionSource::Block(PackedPosition::read(reader)?)),
+            "minecraft:entity" => Ok(PositionSource::Entity {
+                entity_id: VarInt::read(reader)?.0,
+                eye_height: f32::read(reader)?,
+            }),
+            source => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Unknown position source {}", source),
+            )),
+        }
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        match self {
+            PositionSource::Block(position) => {
+                "minecraft:block".write(writer)?;
+                position.write(writer)
+            }
+            PositionSource::Entity {
+                entity_id,
+                eye_height,
+            } => {
+                "minecraft:entity".write(writer)?;
+                VarInt(entity_id).write(writer)?;
+                eye_height.write(writer)
+            }
+        }
+    }
+}
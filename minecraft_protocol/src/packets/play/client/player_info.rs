@@ -1,34 +1,88 @@
 use bitflags::bitflags;
+use uuid::Uuid;
 
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::chat::Component;
 use crate::data::var_int::VarInt;
 use crate::packets::login::Property;
+use crate::packets::play::client::login::GameMode;
+use crate::packets::play::server::chat::PlayerSession;
 use crate::PacketContent;
 
-pub enum Action {
-    AddPlayer {
-        name: String,
-        properties: Vec<Property>,
-        gamemode: u8,
-        ping: i32,
-        display_name: Option<String>,
-    },
-    UpdateGamemode,
-    UpdateLatency,
-    UpdateDisplayName,
-    RemovePlayer,
+bitflags! {
+ /// Which fields of the entries are sent
+ #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+ pub struct PlayerInfoActions: u8 {
+        const ADD_PLAYER = 0b0000_0001;
+        const INITIALIZE_CHAT = 0b0000_0010;
+        const UPDATE_GAME_MODE = 0b0000_0100;
+        const UPDATE_LISTED = 0b0000_1000;
+        const UPDATE_LATENCY = 0b0001_0000;
+        const UPDATE_DISPLAY_NAME = 0b0010_0000;
+    }
 }
-#[derive(Debug, Clone, PartialEq)]
-pub struct PlayerInfo {
+
+/// Adds players to the player list or updates them
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct PlayerInfoUpdate {
+    pub actions: PlayerInfoActions,
     pub players: Vec<PlayerInfoEntry>,
 }
-#[derive(Debug, Clone, PartialEq)]
 
+impl PlayerInfoUpdate {
+    /// Adds the players with everything the tab list shows
+    pub fn add_players(players: Vec<PlayerInfoEntry>) -> Self {
+        let mut actions = PlayerInfoActions::ADD_PLAYER
+            | PlayerInfoActions::UPDATE_GAME_MODE
+            | PlayerInfoActions::UPDATE_LISTED
+            | PlayerInfoActions::UPDATE_LATENCY
+            | PlayerInfoActions::UPDATE_DISPLAY_NAME;
+        if players.iter().any(|player| player.chat_session.is_some()) {
+            actions |= PlayerInfoActions::INITIALIZE_CHAT;
+        }
+        Self { actions, players }
+    }
+}
+
+/// A player in the player list. Only the fields of the packet actions are sent
+#[derive(Debug, Clone, PartialEq)]
 pub struct PlayerInfoEntry {
-    pub uuid: String,
+    pub uuid: Uuid,
     pub name: String,
+    /// Such as the skin textures
+    pub properties: Vec<Property>,
+    /// None if the player has no chat session
+    pub chat_session: Option<PlayerSession>,
+    pub game_mode: GameMode,
+    /// If the player is shown in the tab list
+    pub listed: bool,
+    /// In milliseconds
+    pub latency: i32,
+    /// None shows the name
+    pub display_name: Option<Component>,
 }
 
-impl PacketContent for PlayerInfo {}
+impl PlayerInfoEntry {
+    pub fn new(uuid: Uuid, name: impl Into<String>) -> Self {
+        Self {
+            uuid,
+            name: name.into(),
+            properties: Vec::new(),
+            chat_session: None,
+            game_mode: GameMode::Survival,
+            listed: true,
+            latency: 0,
+            display_name: None,
+        }
+    }
+}
+
+/// Removes players from the player list
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct PlayerInfoRemove {
+    pub players: Vec<Uuid>,
+}
 
 bitflags! {
  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,62 @@
+use bitflags::bitflags;
+
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::data::PackedPosition;
+use crate::packets::play::client::login::GameMode;
+use crate::PacketContent;
+
+bitflags! {
+     /// 1.19.3 and older only send if everything is kept
+     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+     pub struct RespawnDataKept: u8 {
+        const KEEP_ATTRIBUTES = 0b0000_0001;
+        const KEEP_METADATA = 0b0000_0010;
+    }
+}
+
+/// Sent after death and to change dimension. The client drops its chunks and entities
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct Respawn {
+    pub dimension_type: String,
+    pub dimension_name: String,
+    pub hashed_seed: [u8; 8],
+    pub game_mode: GameMode,
+    /// `-1` if there is none
+    pub previous_game_mode: i8,
+    pub is_debug: bool,
+    pub is_flat: bool,
+    /// Empty after death. Everything when changing dimension
+    pub data_kept: RespawnDataKept,
+    /// The dimension name and the packed position
+    pub death_location: Option<(String, i64)>,
+}
+
+impl Respawn {
+    /// A dimension change that keeps the player data
+    pub fn change_dimension(
+        dimension_type: impl Into<String>,
+        dimension_name: impl Into<String>,
+        hashed_seed: [u8; 8],
+        game_mode: GameMode,
+    ) -> Self {
+        Self {
+            dimension_type: dimension_type.into(),
+            dimension_name: dimension_name.into(),
+            hashed_seed,
+            game_mode,
+            previous_game_mode: -1,
+            is_debug: false,
+            is_flat: false,
+            data_kept: RespawnDataKept::all(),
+            death_location: None,
+        }
+    }
+}
+
+/// Where compasses point and where the player spawns without a bed
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetDefaultSpawnPosition {
+    pub position: PackedPosition,
+    pub angle: f32,
+}
@@ -0,0 +1,212 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::chat::{Component, NamedColor};
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::PacketContent;
+
+/// The longest objective and team name
+pub const MAX_NAME_LENGTH: usize = 16;
+/// The longest entity name in a score or team. Player names or uuids
+pub const MAX_ENTITY_NAME_LENGTH: usize = 40;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PacketContentType, PacketEnum,
+)]
+#[repr(i32)]
+#[error("Invalid Objective Render Type {0}")]
+#[packet_type(VarInt)]
+pub enum ObjectiveRenderType {
+    Integer = 0,
+    Hearts = 1,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObjectiveAction {
+    Create {
+        display_name: Component,
+        render_type: ObjectiveRenderType,
+    },
+    Remove,
+    Update {
+        display_name: Component,
+        render_type: ObjectiveRenderType,
+    },
+}
+
+impl ObjectiveAction {
+    pub fn mode(&self) -> i8 {
+        match self {
+            ObjectiveAction::Create { .. } => 0,
+            ObjectiveAction::Remove => 1,
+            ObjectiveAction::Update { .. } => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UpdateObjectives {
+    pub name: String,
+    pub action: ObjectiveAction,
+}
+
+/// Shows an objective in a slot. An empty name clears the slot
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct DisplayObjective {
+    pub position: i8,
+    pub name: String,
+}
+
+impl DisplayObjective {
+    pub const LIST: i8 = 0;
+    pub const SIDEBAR: i8 = 1;
+    pub const BELOW_NAME: i8 = 2;
+
+    /// The sidebar shown to the players in a team of the color
+    pub fn team_sidebar(color: NamedColor) -> i8 {
+        3 + color as i8
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreAction {
+    Update(i32),
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct UpdateScore {
+    pub entity_name: String,
+    /// Empty removes the entity from every objective
+    pub objective: String,
+    pub action: ScoreAction,
+}
+
+bitflags! {
+     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+     pub struct TeamFlags: u8 {
+        const FRIENDLY_FIRE = 0b0000_0001;
+        const SEE_INVISIBLE_TEAMMATES = 0b0000_0010;
+    }
+}
+
+/// Sent as a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameTagVisibility {
+    #[default]
+    Always,
+    HideForOtherTeams,
+    HideForOwnTeam,
+    Never,
+}
+
+impl NameTagVisibility {
+    pub fn name(&self) -> &'static str {
+        match self {
+            NameTagVisibility::Always => "always",
+            NameTagVisibility::HideForOtherTeams => "hideForOtherTeams",
+            NameTagVisibility::HideForOwnTeam => "hideForOwnTeam",
+            NameTagVisibility::Never => "never",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "always" => Some(NameTagVisibility::Always),
+            "hideForOtherTeams" => Some(NameTagVisibility::HideForOtherTeams),
+            "hideForOwnTeam" => Some(NameTagVisibility::HideForOwnTeam),
+            "never" => Some(NameTagVisibility::Never),
+            _ => None,
+        }
+    }
+}
+
+/// Sent as a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CollisionRule {
+    #[default]
+    Always,
+    PushOtherTeams,
+    PushOwnTeam,
+    Never,
+}
+
+impl CollisionRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CollisionRule::Always => "always",
+            CollisionRule::PushOtherTeams => "pushOtherTeams",
+            CollisionRule::PushOwnTeam => "pushOwnTeam",
+            CollisionRule::Never => "never",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "always" => Some(CollisionRule::Always),
+            "pushOtherTeams" => Some(CollisionRule::PushOtherTeams),
+            "pushOwnTeam" => Some(CollisionRule::PushOwnTeam),
+            "never" => Some(CollisionRule::Never),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamInfo {
+    pub display_name: Component,
+    pub flags: TeamFlags,
+    pub name_tag_visibility: NameTagVisibility,
+    pub collision_rule: CollisionRule,
+    /// The color of the member names. None is sent as reset
+    pub color: Option<NamedColor>,
+    pub prefix: Component,
+    pub suffix: Component,
+}
+
+impl TeamInfo {
+    pub fn new(display_name: impl Into<Component>) -> Self {
+        Self {
+            display_name: display_name.into(),
+            flags: TeamFlags::empty(),
+            name_tag_visibility: NameTagVisibility::default(),
+            collision_rule: CollisionRule::default(),
+            color: None,
+            prefix: Component::text(""),
+            suffix: Component::text(""),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TeamAction {
+    Create {
+        info: TeamInfo,
+        entities: Vec<String>,
+    },
+    Remove,
+    UpdateInfo(TeamInfo),
+    AddEntities(Vec<String>),
+    RemoveEntities(Vec<String>),
+}
+
+impl TeamAction {
+    pub fn mode(&self) -> i8 {
+        match self {
+            TeamAction::Create { .. } => 0,
+            TeamAction::Remove => 1,
+            TeamAction::UpdateInfo(_) => 2,
+            TeamAction::AddEntities(_) => 3,
+            TeamAction::RemoveEntities(_) => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UpdateTeams {
+    pub name: String,
+    pub action: TeamAction,
+}
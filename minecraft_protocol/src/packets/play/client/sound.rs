@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::PacketContent;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PacketContentType, PacketEnum,
+)]
+#[repr(i32)]
+#[error("Invalid Sound Category {0}")]
+#[packet_type(VarInt)]
+pub enum SoundCategory {
+    Master = 0,
+    Music = 1,
+    Record = 2,
+    Weather = 3,
+    Block = 4,
+    Hostile = 5,
+    Neutral = 6,
+    Player = 7,
+    Ambient = 8,
+    Voice = 9,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SoundEvent {
+    /// An id in the `minecraft:sound_event` registry
+    Registered(i32),
+    /// A sound the client knows by name. Such as one from a resource pack
+    Named {
+        name: String,
+        /// The distance the sound can be heard from. None uses the volume
+        fixed_range: Option<f32>,
+    },
+}
+
+impl SoundEvent {
+    pub fn named(name: impl Into<String>) -> Self {
+        SoundEvent::Named {
+            name: name.into(),
+            fixed_range: None,
+        }
+    }
+}
+
+/// Plays a sound at a position
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SoundEffect {
+    pub sound: SoundEvent,
+    pub category: SoundCategory,
+    /// Fixed point with 3 fraction bits. See [SoundEffect::new]
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub volume: f32,
+    pub pitch: f32,
+    /// Picks the variant of the sound
+    pub seed: i64,
+}
+
+impl SoundEffect {
+    /// At full volume and normal pitch
+    pub fn new(sound: SoundEvent, category: SoundCategory, x: f64, y: f64, z: f64) -> Self {
+        Self {
+            sound,
+            category,
+            x: (x * 8.0) as i32,
+            y: (y * 8.0) as i32,
+            z: (z * 8.0) as i32,
+            volume: 1.0,
+            pitch: 1.0,
+            seed: 0,
+        }
+    }
+
+    pub fn position(&self) -> (f64, f64, f64) {
+        (
+            self.x as f64 / 8.0,
+            self.y as f64 / 8.0,
+            self.z as f64 / 8.0,
+        )
+    }
+}
+
+/// Plays a sound that follows an entity
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct EntitySoundEffect {
+    pub sound: SoundEvent,
+    pub category: SoundCategory,
+    pub entity_id: i32,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+/// Stops the matching sounds. Nothing set stops every sound
+#[derive(Debug, Clone, PartialEq, Default, PacketContentType)]
+pub struct StopSound {
+    pub category: Option<SoundCategory>,
+    /// The name of the sound. Such as `minecraft:block.note_block.harp`
+    pub sound: Option<String>,
+}
+
+impl StopSound {
+    pub const HAS_CATEGORY: u8 = 0b01;
+    pub const HAS_SOUND: u8 = 0b10;
+
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.category.is_some() {
+            flags |= Self::HAS_CATEGORY;
+        }
+        if self.sound.is_some() {
+            flags |= Self::HAS_SOUND;
+        }
+        flags
+    }
+}
@@ -0,0 +1,166 @@
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::packets::play::client::login::GameMode;
+use crate::PacketContent;
+
+/// The default teleport boundary of nether portals
+pub const PORTAL_TELEPORT_BOUNDARY: i32 = 29999984;
+
+/// Sent on join and when the player changes dimension
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct InitializeWorldBorder {
+    pub x: f64,
+    pub z: f64,
+    pub old_diameter: f64,
+    /// Equal to the old diameter if the border is not moving
+    pub new_diameter: f64,
+    /// Milliseconds until the new diameter is reached
+    pub speed: i64,
+    pub portal_teleport_boundary: i32,
+    pub warning_blocks: i32,
+    /// In seconds
+    pub warning_time: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetBorderCenter {
+    pub x: f64,
+    pub z: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetBorderLerpSize {
+    pub old_diameter: f64,
+    pub new_diameter: f64,
+    /// In milliseconds
+    pub speed: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetBorderSize {
+    pub diameter: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct SetBorderWarningDelay {
+    /// In seconds
+    pub warning_time: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct SetBorderWarningDistance {
+    pub warning_blocks: i32,
+}
+
+/// In ticks
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct UpdateTime {
+    pub world_age: i64,
+    /// Negative if the daylight cycle is stopped
+    pub time_of_day: i64,
+}
+
+impl UpdateTime {
+    pub fn new(world_age: i64, time_of_day: i64, daylight_cycle: bool) -> Self {
+        let time_of_day = time_of_day.abs();
+        Self {
+            world_age,
+            time_of_day: if daylight_cycle {
+                time_of_day
+            } else {
+                -time_of_day
+            },
+        }
+    }
+
+    pub fn daylight_cycle(&self) -> bool {
+        self.time_of_day >= 0
+    }
+}
+
+/// The value of [GameEvent::Demo]
+pub mod demo {
+    pub const WELCOME: i32 = 0;
+    pub const MOVEMENT_CONTROLS: i32 = 101;
+    pub const JUMP_CONTROL: i32 = 102;
+    pub const INVENTORY_CONTROL: i32 = 103;
+    pub const DEMO_OVER: i32 = 104;
+}
+
+/// Sent as an event id and a float value
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub enum GameEvent {
+    NoRespawnBlockAvailable,
+    /// Followed by the rain level. Some documentation has the rain ids swapped
+    StartRaining,
+    StopRaining,
+    ChangeGameMode(GameMode),
+    WinGame {
+        /// False if the player already saw the credits
+        roll_credits: bool,
+    },
+    Demo(i32),
+    /// The sound when an arrow of the player hits another player
+    ArrowHitPlayer,
+    /// From 0 to 1
+    RainLevelChange(f32),
+    /// From 0 to 1
+    ThunderLevelChange(f32),
+    PufferfishSting,
+    ElderGuardianEffect,
+    /// The `doImmediateRespawn` game rule
+    EnableRespawnScreen(bool),
+}
+
+impl GameEvent {
+    pub fn event_id(&self) -> u8 {
+        match self {
+            GameEvent::NoRespawnBlockAvailable => 0,
+            GameEvent::StartRaining => 1,
+            GameEvent::StopRaining => 2,
+            GameEvent::ChangeGameMode(_) => 3,
+            GameEvent::WinGame { .. } => 4,
+            GameEvent::Demo(_) => 5,
+            GameEvent::ArrowHitPlayer => 6,
+            GameEvent::RainLevelChange(_) => 7,
+            GameEvent::ThunderLevelChange(_) => 8,
+            GameEvent::PufferfishSting => 9,
+            GameEvent::ElderGuardianEffect => 10,
+            GameEvent::EnableRespawnScreen(_) => 11,
+        }
+    }
+
+    pub fn value(&self) -> f32 {
+        match self {
+            GameEvent::ChangeGameMode(game_mode) => game_mode.clone() as u8 as f32,
+            GameEvent::WinGame { roll_credits } => *roll_credits as u8 as f32,
+            GameEvent::Demo(value) => *value as f32,
+            GameEvent::RainLevelChange(level) | GameEvent::ThunderLevelChange(level) => *level,
+            // 1 respawns immediately
+            GameEvent::EnableRespawnScreen(enabled) => !*enabled as u8 as f32,
+            _ => 0.0,
+        }
+    }
+
+    /// None if the event id is unknown
+    pub fn from_parts(event_id: u8, value: f32) -> Option<Self> {
+        let event = match event_id {
+            0 => GameEvent::NoRespawnBlockAvailable,
+            1 => GameEvent::StartRaining,
+            2 => GameEvent::StopRaining,
+            3 => GameEvent::ChangeGameMode(GameMode::try_from(value as u8).ok()?),
+            4 => GameEvent::WinGame {
+                roll_credits: value != 0.0,
+            },
+            5 => GameEvent::Demo(value as i32),
+            6 => GameEvent::ArrowHitPlayer,
+            7 => GameEvent::RainLevelChange(value),
+            8 => GameEvent::ThunderLevelChange(value),
+            9 => GameEvent::PufferfishSting,
+            10 => GameEvent::ElderGuardianEffect,
+            11 => GameEvent::EnableRespawnScreen(value == 0.0),
+            _ => return None,
+        };
+        Some(event)
+    }
+}
@@ -1,9 +1,12 @@
 use std::borrow::Cow;
+use std::io;
+use std::io::{Read, Write};
 
 use serde::{Deserialize, Serialize};
 
 use minecraft_protocol_macros::PacketContentType;
 
+use crate::data::PacketDataType;
 use crate::PacketContent;
 
 pub mod client;
@@ -34,10 +37,44 @@ pub struct PlayPluginMessage {
 impl PacketContent for PlayPluginMessage {}
 
 impl PlayPluginMessage {
+    /// The brand is sent as a string. With its length prefix
     pub fn server_brand(brand: impl Into<String>) -> Self {
+        let mut data = Vec::new();
+        brand
+            .into()
+            .write(&mut data)
+            .expect("Writing to a Vec does not fail");
         Self {
             id: Cow::Borrowed("minecraft:brand"),
-            data: brand.into().into_bytes(),
+            data,
         }
     }
 }
+
+/// A SHA256withRSA signature made with the chat session key of the sender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MessageSignature(pub [u8; MessageSignature::LENGTH]);
+
+impl MessageSignature {
+    pub const LENGTH: usize = 256;
+
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok().map(MessageSignature)
+    }
+}
+
+/// Always 256 bytes. Without a length prefix
+impl PacketDataType for MessageSignature {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let mut signature = [0; MessageSignature::LENGTH];
+        reader.read_exact(&mut signature)?;
+        Ok(MessageSignature(signature))
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        writer.write_all(&self.0)
+    }
+}
@@ -0,0 +1,132 @@
+use std::io;
+use std::io::{Read, Write};
+
+use uuid::Uuid;
+
+use minecraft_protocol_macros::PacketContentType;
+
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::packets::play::MessageSignature;
+use crate::PacketContent;
+
+/// Which of the last 20 messages sent to the client it has seen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LastSeenUpdate {
+    /// The number of messages received since the last update
+    pub offset: i32,
+    /// Bit `n` is set if the `n`th tracked message was seen. Sent as a 20 bit fixed bit set
+    pub acknowledged: u32,
+}
+
+impl LastSeenUpdate {
+    /// The number of messages the client tracks
+    pub const LAST_SEEN_COUNT: usize = 20;
+}
+
+impl PacketDataType for LastSeenUpdate {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let offset = VarInt::read(reader)?.0;
+        let mut acknowledged = [0; 4];
+        reader.read_exact(&mut acknowledged[..3])?;
+        Ok(LastSeenUpdate {
+            offset,
+            acknowledged: u32::from_le_bytes(acknowledged),
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        VarInt(self.offset).write(writer)?;
+        writer.write_all(&self.acknowledged.to_le_bytes()[..3])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct ChatMessage {
+    pub message: String,
+    /// Milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub salt: i64,
+    /// None if the player has no chat session
+    pub signature: Option<MessageSignature>,
+    pub last_seen: LastSeenUpdate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgumentSignature {
+    pub name: String,
+    pub signature: MessageSignature,
+}
+
+impl PacketDataType for ArgumentSignature {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(ArgumentSignature {
+            name: String::read(reader)?,
+            signature: MessageSignature::read(reader)?,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        self.name.write(writer)?;
+        self.signature.write(writer)
+    }
+}
+
+/// A command typed into the chat. Without the `/`
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct ChatCommand {
+    pub command: String,
+    pub timestamp: i64,
+    pub salt: i64,
+    /// Signatures of the message arguments of the command
+    pub argument_signatures: Vec<ArgumentSignature>,
+    pub last_seen: LastSeenUpdate,
+}
+
+/// Sent when the client has received messages without sending a chat message
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct MessageAcknowledgment {
+    pub offset: i32,
+}
+
+/// The key the player signs messages with. Signed by Mojang
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfilePublicKey {
+    /// Milliseconds since the unix epoch
+    pub expires_at: i64,
+    /// X.509 DER encoded RSA key
+    pub key: Vec<u8>,
+    pub key_signature: Vec<u8>,
+}
+
+impl PacketDataType for ProfilePublicKey {
+    fn read<Reader: Read>(reader: &mut Reader) -> io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(ProfilePublicKey {
+            expires_at: i64::read(reader)?,
+            key: Vec::read(reader)?,
+            key_signature: Vec::read(reader)?,
+        })
+    }
+
+    fn write<Writer: Write>(self, writer: &mut Writer) -> io::Result<()> {
+        self.expires_at.write(writer)?;
+        self.key.write(writer)?;
+        self.key_signature.write(writer)
+    }
+}
+
+/// Starts a new chat session. Sent after joining and when the key is refreshed
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct PlayerSession {
+    pub session_id: Uuid,
+    pub public_key: ProfilePublicKey,
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::data::slot::Slot;
+use crate::data::var_int::VarInt;
+use crate::data::PacketDataType;
+use crate::PacketContent;
+
+/// How the player clicked. Decides what the button means
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(i32)]
+#[error("Invalid Click Mode {0}")]
+#[packet_type(VarInt)]
+pub enum ClickMode {
+    Pickup = 0,
+    /// Shift click
+    QuickMove = 1,
+    /// A number key or the offhand key
+    Swap = 2,
+    /// Middle click in creative
+    Clone = 3,
+    Throw = 4,
+    /// Dragging across slots
+    QuickCraft = 5,
+    /// Double click
+    PickupAll = 6,
+}
+
+/// A slot the client changed and what it thinks is in it now
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangedSlot {
+    pub slot: i16,
+    pub item: Slot,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct ClickContainer {
+    pub window_id: u8,
+    /// The last state id the server sent
+    pub state_id: i32,
+    /// `-999` is outside the window
+    pub slot: i16,
+    pub button: i8,
+    pub mode: ClickMode,
+    pub changed_slots: Vec<ChangedSlot>,
+    /// The item on the cursor after the click
+    pub carried_item: Slot,
+}
+
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct ServerBoundCloseContainer {
+    pub window_id: u8,
+}
+
+/// Only sent in creative mode. `-1` drops the item
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetCreativeModeSlot {
+    pub slot: i16,
+    pub item: Slot,
+}
+
+/// The hotbar slot the player selected. From 0 to 8
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct SetHeldItem {
+    pub slot: i16,
+}
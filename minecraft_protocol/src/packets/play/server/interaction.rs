@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+
+use minecraft_protocol_macros::{PacketContentType, PacketEnum};
+
+use crate::data::var_int::VarInt;
+use crate::data::{PackedPosition, PacketDataType};
+use crate::packets::play::client::metadata::Direction;
+use crate::PacketContent;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketContentType, PacketEnum,
+)]
+#[repr(i32)]
+#[error("Invalid Hand {0}")]
+#[packet_type(VarInt)]
+pub enum Hand {
+    MainHand = 0,
+    OffHand = 1,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(i32)]
+#[error("Invalid Player Action Status {0}")]
+#[packet_type(VarInt)]
+pub enum PlayerActionStatus {
+    StartedDigging = 0,
+    CancelledDigging = 1,
+    FinishedDigging = 2,
+    DropItemStack = 3,
+    DropItem = 4,
+    /// Shooting a bow or finishing eating
+    ReleaseUseItem = 5,
+    SwapItemInHand = 6,
+}
+
+/// Digging and the item actions. The position and face are zero for the item actions
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct PlayerAction {
+    pub status: PlayerActionStatus,
+    pub position: PackedPosition,
+    /// Sent as a byte
+    pub face: Direction,
+    /// Acknowledged with [AcknowledgeBlockChange](crate::packets::play::client::block::AcknowledgeBlockChange)
+    pub sequence: i32,
+}
+
+/// Placing a block or using an item on a block
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct UseItemOn {
+    pub hand: Hand,
+    pub position: PackedPosition,
+    pub face: Direction,
+    /// Where on the block face was clicked. From 0 to 1
+    pub cursor_x: f32,
+    pub cursor_y: f32,
+    pub cursor_z: f32,
+    /// The player's head is inside the block
+    pub inside_block: bool,
+    pub sequence: i32,
+}
+
+/// Using the item in a hand without a target block
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct UseItem {
+    pub hand: Hand,
+    pub sequence: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct SwingArm {
+    pub hand: Hand,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InteractAction {
+    Interact(Hand),
+    Attack,
+    /// Sent before [InteractAction::Interact]. The target is relative to the entity
+    InteractAt {
+        x: f32,
+        y: f32,
+        z: f32,
+        hand: Hand,
+    },
+}
+
+impl InteractAction {
+    pub fn action_id(&self) -> i32 {
+        match self {
+            InteractAction::Interact(_) => 0,
+            InteractAction::Attack => 1,
+            InteractAction::InteractAt { .. } => 2,
+        }
+    }
+}
+
+/// Right or left clicking an entity
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct Interact {
+    pub entity_id: i32,
+    pub action: InteractAction,
+    pub sneaking: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, PacketEnum)]
+#[repr(i32)]
+#[error("Invalid Player Command {0}")]
+#[packet_type(VarInt)]
+pub enum PlayerCommandAction {
+    StartSneaking = 0,
+    StopSneaking = 1,
+    LeaveBed = 2,
+    StartSprinting = 3,
+    StopSprinting = 4,
+    StartHorseJump = 5,
+    StopHorseJump = 6,
+    OpenVehicleInventory = 7,
+    StartFlyingWithElytra = 8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PacketContentType)]
+pub struct PlayerCommand {
+    pub entity_id: i32,
+    pub action: PlayerCommandAction,
+    /// From 0 to 100. Only used by [PlayerCommandAction::StartHorseJump]
+    pub jump_boost: i32,
+}
+
+impl PlayerCommand {
+    pub const MAX_JUMP_BOOST: i32 = 100;
+
+    pub fn new(entity_id: i32, action: PlayerCommandAction) -> Self {
+        Self {
+            entity_id,
+            action,
+            jump_boost: 0,
+        }
+    }
+}
@@ -6,16 +6,45 @@ use minecraft_protocol_macros::{PacketContentType, PacketEnum};
 use crate::data::var_int::VarInt;
 use crate::data::PacketDataType;
 use crate::packets::define_group;
+use crate::packets::play::server::chat::{
+    ChatCommand, ChatMessage, MessageAcknowledgment, PlayerSession,
+};
+use crate::packets::play::server::container::{
+    ClickContainer, ServerBoundCloseContainer, SetCreativeModeSlot, SetHeldItem,
+};
+use crate::packets::play::server::interaction::{
+    Interact, PlayerAction, PlayerCommand, SwingArm, UseItem, UseItemOn,
+};
 use crate::packets::play::{KeepAlive, PlayPing, PlayPluginMessage};
 use crate::PacketContent;
 
+pub mod chat;
+pub mod container;
+pub mod interaction;
+
 define_group!(ServerBoundPlay {
     PlayerMove: ServerBoundMove,
     KeepAlive: KeepAlive,
     Ping: PlayPing,
     ClientInformation: ClientInformation,
     PluginMessage: PlayPluginMessage,
-    ConfirmTeleport: ConfirmTeleport
+    ConfirmTeleport: ConfirmTeleport,
+    ChatMessage: ChatMessage,
+    ChatCommand: ChatCommand,
+    MessageAcknowledgment: MessageAcknowledgment,
+    PlayerSession: PlayerSession,
+    ClickContainer: ClickContainer,
+    CloseContainer: ServerBoundCloseContainer,
+    SetCreativeModeSlot: SetCreativeModeSlot,
+    SetHeldItem: SetHeldItem,
+    CommandSuggestionsRequest: CommandSuggestionsRequest,
+    ResourcePackStatus: ResourcePackStatus,
+    PlayerAction: PlayerAction,
+    UseItemOn: UseItemOn,
+    UseItem: UseItem,
+    SwingArm: SwingArm,
+    Interact: Interact,
+    PlayerCommand: PlayerCommand
 });
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PacketContentType)]
 pub enum ServerBoundMove {
@@ -49,6 +78,29 @@ impl From<VarInt> for ConfirmTeleport {
     }
 }
 
+/// Sent when the client needs suggestions for an argument with the `minecraft:ask_server` suggestions type
+#[derive(Debug, Clone, PartialEq, PacketContentType)]
+pub struct CommandSuggestionsRequest {
+    /// Echoed back in the response
+    pub transaction_id: i32,
+    /// Everything typed so far. Including the leading `/`
+    pub text: String,
+}
+
+/// The answer to a resource pack. Accepted is followed by loaded or failed
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PacketContentType, PacketEnum,
+)]
+#[repr(i32)]
+#[error("Invalid Resource Pack Status {0}")]
+#[packet_type(VarInt)]
+pub enum ResourcePackStatus {
+    SuccessfullyLoaded = 0,
+    Declined = 1,
+    FailedDownload = 2,
+    Accepted = 3,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PacketContentType, PacketEnum)]
 #[repr(i32)]
 #[error("Invalid Main Hand {0}")]
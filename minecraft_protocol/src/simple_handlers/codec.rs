@@ -0,0 +1,119 @@
+//! [tokio_util::codec] adapters for the packet readers and writers.
+//!
+//! This allows a connection to be driven as a `Framed<TcpStream, PacketCodec<_, _>>`
+//! instead of pumping the bytes into [PacketReader::get_read_buffer] by hand.
+//!
+//! ```rust,no_run
+//! use futures_util::StreamExt;
+//! use tokio::net::TcpStream;
+//! use tokio_util::codec::Framed;
+//!
+//! use minecraft_protocol::java::handshake::HandShakeIO;
+//! use minecraft_protocol::java::v_761::login;
+//! use minecraft_protocol::simple_handlers::codec::OptionalEncryptionCodec;
+//!
+//! # async fn example(socket: TcpStream) -> anyhow::Result<()> {
+//! let mut framed = Framed::new(
+//!     socket,
+//!     OptionalEncryptionCodec::<HandShakeIO, login::ClientIO>::default(),
+//! );
+//! let _handshake = framed.next().await;
+//! // Any bytes already received for the login stage are kept
+//! let framed = framed.map_codec(|codec| codec.swap_io::<login::ServerIO, login::ClientIO>());
+//! # Ok(())
+//! # }
+//! ```
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+#[cfg(feature = "encryption")]
+use crate::simple_handlers::{OptionalEncryptionReader, OptionalEncryptionWriter};
+use crate::simple_handlers::SwapPacketIO;
+use crate::{
+    CompressionSettings, PacketIO, PacketReadError, PacketReader, PacketWriteError, PacketWriter,
+};
+#[cfg(feature = "encryption")]
+use crate::{Decryptor, Encryptor};
+
+/// A Codec for a connection that might become encrypted. This is what a server will use
+#[cfg(feature = "encryption")]
+pub type OptionalEncryptionCodec<ReadIO, WriteIO> =
+    PacketCodec<OptionalEncryptionReader<ReadIO>, OptionalEncryptionWriter<WriteIO>>;
+
+/// Wraps a [PacketReader] and a [PacketWriter] into a [Decoder] and [Encoder]
+///
+/// The reader works directly on the Framed read buffer. Bytes that are left over after a packet
+/// stay in that buffer, so swapping the stage with [PacketCodec::swap_io] will never lose any data.
+/// The reader keeps track of how much of that buffer it has already decrypted.
+#[derive(Debug, Clone, Default)]
+pub struct PacketCodec<Reader, Writer> {
+    pub reader: Reader,
+    pub writer: Writer,
+}
+
+impl<Reader, Writer> PacketCodec<Reader, Writer> {
+    pub fn new(reader: Reader, writer: Writer) -> Self {
+        Self { reader, writer }
+    }
+
+    /// Moves the reader and writer to new [PacketIO]s. Keeping the buffered bytes, compression and cipher state.
+    pub fn swap_io<ReadIO: PacketIO, WriteIO: PacketIO>(
+        self,
+    ) -> PacketCodec<Reader::Output, Writer::Output>
+    where
+        Reader: SwapPacketIO<ReadIO>,
+        Writer: SwapPacketIO<WriteIO>,
+    {
+        PacketCodec {
+            reader: self.reader.swap_io(),
+            writer: self.writer.swap_io(),
+        }
+    }
+}
+
+impl<Reader: PacketReader, Writer: PacketWriter> PacketCodec<Reader, Writer> {
+    /// Sets the compression for both directions
+    pub fn set_compression(&mut self, compression: CompressionSettings) {
+        self.reader.set_compression(compression);
+        self.writer.set_compression(compression);
+    }
+    /// Enables encryption for both directions.
+    ///
+    /// Any bytes that are already buffered will be decrypted on the next read
+    #[cfg(feature = "encryption")]
+    pub fn enable_encryption(&mut self, encryptor: Encryptor, decryptor: Decryptor) {
+        self.reader.set_decryptor(decryptor);
+        self.writer.set_encryptor(encryptor);
+    }
+}
+
+impl<Reader, Writer> Decoder for PacketCodec<Reader, Writer>
+where
+    Reader: PacketReader<ReadBuffer = BytesMut>,
+{
+    type Item = Reader::PacketIn;
+    type Error = PacketReadError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let buffer = self.reader.get_read_buffer();
+        buffer.unsplit(src.split());
+        let packet = self.reader.attempt_packet_read();
+        // Hand the left over bytes back. So Framed knows there might be another packet buffered.
+        src.unsplit(self.reader.get_read_buffer().split());
+        packet
+    }
+}
+
+impl<Reader, Writer> Encoder<Writer::PacketOut> for PacketCodec<Reader, Writer>
+where
+    Writer: PacketWriter<Buffer = Vec<u8>>,
+{
+    type Error = PacketWriteError;
+
+    fn encode(&mut self, item: Writer::PacketOut, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.writer.write_packet(item)?;
+        dst.extend_from_slice(self.writer.get_buffer());
+        self.writer.force_buffer_clear();
+        Ok(())
+    }
+}
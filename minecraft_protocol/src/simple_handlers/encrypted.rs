@@ -2,9 +2,11 @@ use std::fmt::Debug;
 use std::io::Write;
 
 use bytes::BytesMut;
-use cfb_mode::cipher::AsyncStreamCipher;
+use cfb8::cipher::inout::InOutBuf;
+use cfb8::cipher::{BlockDecryptMut, BlockEncryptMut};
 
-use crate::simple_handlers::{InternalPacketReader, InternalPacketWriter};
+use crate::limits::DecodeLimits;
+use crate::simple_handlers::{InternalPacketReader, InternalPacketWriter, SwapPacketIO};
 use crate::{
     CompressionSettings, Decryptor, Encryptor, PacketHandler, PacketIO, PacketLength,
     PacketReadError, PacketReader, PacketWriteError, PacketWriter,
@@ -18,6 +20,7 @@ pub struct EncryptedPacketReader<IO: PacketIO> {
     pub compression: CompressionSettings,
     pub decryptor: Decryptor,
     pub last_decrypted_at: usize,
+    pub limits: DecodeLimits,
 }
 
 impl<IO: PacketIO + Debug> EncryptedPacketReader<IO> {
@@ -29,13 +32,33 @@ impl<IO: PacketIO + Debug> EncryptedPacketReader<IO> {
             compression: Default::default(),
             decryptor,
             last_decrypted_at: 0,
+            limits: DecodeLimits::default(),
         }
     }
     /// Decrypts the area of the buffer that has not been decrypted yet.
+    ///
+    /// CFB8 is a stream. So every byte is decrypted exactly once with the same decryptor
     fn decrypt(&mut self) {
         let decrypt_buffer = &mut self.buffer.as_mut()[self.last_decrypted_at..];
-        self.decryptor.clone().decrypt(decrypt_buffer);
-        self.last_decrypted_at = decrypt_buffer.len();
+        let (blocks, _) = InOutBuf::from(decrypt_buffer).into_chunks();
+        self.decryptor.decrypt_blocks_inout_mut(blocks);
+        self.last_decrypted_at = self.buffer.len();
+    }
+}
+
+impl<IO: PacketIO, NewIO: PacketIO> SwapPacketIO<NewIO> for EncryptedPacketReader<IO> {
+    type Output = EncryptedPacketReader<NewIO>;
+
+    fn swap_io(self) -> Self::Output {
+        EncryptedPacketReader {
+            phantom: std::marker::PhantomData,
+            buffer: self.buffer,
+            packet_len: self.packet_len,
+            compression: self.compression,
+            decryptor: self.decryptor,
+            last_decrypted_at: self.last_decrypted_at,
+            limits: self.limits,
+        }
     }
 }
 impl<IO: PacketIO + Debug> PacketHandler for EncryptedPacketReader<IO> {
@@ -64,9 +87,20 @@ impl<IO: PacketIO + Debug> PacketReader for EncryptedPacketReader<IO> {
         &self.packet_len
     }
 
+    fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+    }
+
+    fn decode_limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
     fn attempt_packet_read(&mut self) -> Result<Option<Self::PacketIn>, PacketReadError> {
         self.decrypt();
-        self.attempt_read::<IO>()
+        let packet = self.attempt_read::<IO>();
+        // Everything left in the buffer has already been decrypted
+        self.last_decrypted_at = self.buffer.len();
+        packet
     }
 
     fn get_read_buffer(&mut self) -> &mut Self::ReadBuffer {
@@ -100,6 +134,24 @@ impl<IO: PacketIO + Debug> EncryptedPacketWriter<IO> {
             encryptor,
         }
     }
+    /// Encrypts the pending buffer from `start`. The cipher state carries over to the next packet
+    fn encrypt(&mut self, start: usize) {
+        let (blocks, _) = InOutBuf::from(&mut self.pending_buffer[start..]).into_chunks();
+        self.encryptor.encrypt_blocks_inout_mut(blocks);
+    }
+}
+
+impl<IO: PacketIO, NewIO: PacketIO> SwapPacketIO<NewIO> for EncryptedPacketWriter<IO> {
+    type Output = EncryptedPacketWriter<NewIO>;
+
+    fn swap_io(self) -> Self::Output {
+        EncryptedPacketWriter {
+            pending_buffer: self.pending_buffer,
+            compression: self.compression,
+            encryptor: self.encryptor,
+            phantom: std::marker::PhantomData,
+        }
+    }
 }
 
 impl<IO: PacketIO + Debug> InternalPacketWriter<IO> for EncryptedPacketWriter<IO> {}
@@ -129,11 +181,13 @@ impl<IO: PacketIO + Debug> PacketWriter for EncryptedPacketWriter<IO> {
     }
 
     fn write_packet(&mut self, packet: impl Into<Self::PacketOut>) -> Result<(), PacketWriteError> {
+        // Packets already in the pending buffer have been encrypted
+        let start = self.pending_buffer.len();
         // Call the internal send packet function
         self.internal_write(packet.into())?;
         // Push the pending buffer into the framed buffer
 
-        self.encryptor.clone().encrypt(&mut self.pending_buffer);
+        self.encrypt(start);
 
         Ok(())
     }
@@ -143,11 +197,12 @@ impl<IO: PacketIO + Debug> PacketWriter for EncryptedPacketWriter<IO> {
         packet: impl Into<Self::PacketOut>,
         writer: &mut W,
     ) -> Result<(), PacketWriteError> {
+        let start = self.pending_buffer.len();
         // Call the internal send packet function
         self.internal_write(packet.into())?;
         // Push the pending buffer into the framed buffer
 
-        self.encryptor.clone().encrypt(&mut self.pending_buffer);
+        self.encrypt(start);
 
         writer.write_all(&self.pending_buffer)?;
         writer.flush()?;
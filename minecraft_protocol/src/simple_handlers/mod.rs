@@ -1,69 +1,97 @@
-use std::io::{BufRead, Cursor, Write};
+use std::io::{BufRead, Cursor, Read, Write};
 use std::mem;
 
 use bytes::{Buf, BytesMut};
-use flate2::bufread::GzDecoder;
+use flate2::bufread::ZlibDecoder;
+use flate2::write::ZlibEncoder;
 
+#[cfg(feature = "codec")]
+pub use codec::PacketCodec;
 #[cfg(feature = "encryption")]
 pub use encrypted::{EncryptedPacketReader, EncryptedPacketWriter};
 pub use no_encryption::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
 #[cfg(feature = "encryption")]
 pub use optional_encryption::{OptionalEncryptionReader, OptionalEncryptionWriter};
 
-use crate::data::var_int::VarInt;
-use crate::data::{var_int, PacketDataType};
+use crate::data::var_int;
 use crate::{
     CompressionSettings, PacketIO, PacketLength, PacketReadError, PacketReader, PacketWriteError,
     PacketWriter,
 };
 
+#[cfg(feature = "codec")]
+pub mod codec;
 #[cfg(feature = "encryption")]
 mod encrypted;
 
 mod no_encryption;
 #[cfg(feature = "encryption")]
 pub mod optional_encryption;
+pub mod recording;
+
+/// Moves a handler over to a different [PacketIO] without losing any state.
+///
+/// Used to move a connection between stages (Handshake -> Login -> Play).
+/// Buffered bytes, compression and cipher state are carried over to the new handler.
+pub trait SwapPacketIO<NewIO: PacketIO> {
+    type Output;
+
+    fn swap_io(self) -> Self::Output;
+}
 
 pub(crate) trait InternalPacketWriter<IO: PacketIO>: PacketWriter<Buffer = Vec<u8>> {
+    /// Writes the packet to the end of the buffer.
+    ///
+    /// Follows the vanilla rules for compression.
+    /// - No Compression: \[Packet Length] \[Packet ID + Data]
+    /// - Compression: \[Packet Length] \[Data Length] \[Packet ID + Data]
+    ///
+    /// With compression the Packet ID + Data is only compressed if it is at least the threshold.
+    /// Otherwise the Data Length is 0 and the data is sent as is.
     fn internal_write(&mut self, packet: IO::Type) -> Result<(), PacketWriteError> {
-        let mut header = [0u8; 6];
-        self.get_buffer().write_all(&header)?;
-        IO::handle_write(packet.into(), &mut self.get_buffer())?;
-        let len_as_i32 = self.get_buffer()[6..].len() as i32;
-
-        let header_len = if let CompressionSettings::Zlib {
-            threshold,
-            compression_level,
-        } = &self.get_compression()
-        {
-            if *threshold >= len_as_i32 {
-                let compressed = Vec::with_capacity(self.get_buffer().len());
-                let mut compressor = flate2::write::ZlibEncoder::new(
-                    compressed,
-                    flate2::Compression::new(*compression_level),
+        let compression = self.get_compression();
+        let buffer = self.get_buffer();
+        // The buffer might still contain packets that have not been sent yet
+        let start = buffer.len();
+        IO::handle_write(packet, buffer)?;
+        let data_len = buffer.len() - start;
+        // Packet Length + Data Length
+        let mut header = [0u8; 10];
+
+        let header_len = match compression {
+            CompressionSettings::Zlib {
+                threshold,
+                compression_level,
+            } if threshold >= 0 && data_len >= threshold as usize => {
+                let mut compressor = ZlibEncoder::new(
+                    Vec::with_capacity(data_len),
+                    flate2::Compression::new(compression_level),
                 );
-                compressor.write_all(&mut self.get_buffer())?;
+                compressor.write_all(&buffer[start..])?;
                 let compressed = compressor.finish()?;
 
-                let mut header_len =
-                    var_int::inline::write(compressed.len() as i32, &mut header.as_mut())?;
-                header_len = header_len
-                    + var_int::inline::write(
-                        self.get_buffer().len() as i32,
-                        &mut header[header_len..].as_mut(),
-                    )?;
-                self.get_buffer().clear();
-                self.get_buffer().write_all(&header[..header_len])?;
-                self.get_buffer().write_all(&compressed)?;
+                let data_len_size = var_int::inline::get_size(data_len as i32) as usize;
+                let mut header_len = var_int::inline::write(
+                    (compressed.len() + data_len_size) as i32,
+                    &mut header.as_mut(),
+                )?;
+                header_len +=
+                    var_int::inline::write(data_len as i32, &mut header[header_len..].as_mut())?;
+                buffer.truncate(start);
+                buffer.extend_from_slice(&header[..header_len]);
+                buffer.extend_from_slice(&compressed);
                 return Ok(());
-            } else {
-                var_int::inline::write(len_as_i32, &mut header.as_mut())? + 1
             }
-        } else {
-            var_int::inline::write(len_as_i32, &mut header.as_mut())?
+            CompressionSettings::Zlib { .. } => {
+                // A Data Length of 0 marks the packet as uncompressed
+                let header_len =
+                    var_int::inline::write(data_len as i32 + 1, &mut header.as_mut())?;
+                header[header_len] = 0;
+                header_len + 1
+            }
+            _ => var_int::inline::write(data_len as i32, &mut header.as_mut())?,
         };
-        self.get_buffer()
-            .splice(0..6, header[..header_len].iter().cloned());
+        buffer.splice(start..start, header[..header_len].iter().cloned());
 
         Ok(())
     }
@@ -97,12 +125,19 @@ pub(crate) trait InternalPacketReader: PacketReader<ReadBuffer = BytesMut> {
                 let packet_len_value = var_int::inline::read_with_iterations(&mut cursor);
                 // If ok set the packet length and return
                 if let Ok((len, iterations)) = packet_len_value {
+                    // Checked before anything is reserved for the packet
+                    self.decode_limits().check_packet_length(len)?;
                     self.set_packet_length(PacketLength::LengthRead {
                         length: len,
                         iterations: iterations as u8,
                     });
 
                     (len, iterations as usize)
+                } else if self.get_read_buffer_ref().len() >= 4 {
+                    // Otherwise a client could keep the length unfinished while the buffer grows
+                    return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                        "Packet length does not fit in 4 bytes"
+                    )));
                 } else {
                     // The packet length is incomplete return and wait for more data
                     return Ok(None);
@@ -119,23 +154,41 @@ pub(crate) trait InternalPacketReader: PacketReader<ReadBuffer = BytesMut> {
             }
             return Ok(None);
         }
-        // Check if compression is enabled. If so, decompress the packet.
-        match self.get_compression() {
-            CompressionSettings::Zlib { .. } => {
-                let mut current_packet = self.get_read_buffer().split_to(packet_len_total).reader();
-
-                let decompressed_size = VarInt::read(&mut current_packet)?;
-                if decompressed_size.0 != 0 {
-                    let mut decompressor = GzDecoder::new(current_packet);
-                    let id = var_int::inline::read(&mut decompressor)?.0;
-                    self.set_packet_length(PacketLength::Incomplete);
+        let limits = self.decode_limits();
+        // Check if compression is enabled. If so, the Data Length follows the Packet Length
+        if let CompressionSettings::Zlib { threshold, .. } = self.get_compression() {
+            let mut current_packet = self.get_read_buffer().split_to(packet_len_total).reader();
+            current_packet.consume(iterations);
+            let (data_len, data_len_size) =
+                var_int::inline::read_with_iterations(&mut current_packet)?;
+            self.set_packet_length(PacketLength::Incomplete);
 
-                    let packet =
-                        IO::handle_read(id, decompressed_size.0 as usize, &mut decompressor)?;
-                    return Ok(Some(packet));
-                }
+            if data_len == 0 {
+                // The packet was below the threshold and is not compressed
+                let id = var_int::inline::read(&mut current_packet)?.0;
+                let packet = limits.scope(|| {
+                    IO::handle_read(
+                        id,
+                        packet_len as usize - data_len_size as usize,
+                        &mut current_packet,
+                    )
+                })?;
+                return Ok(Some(packet));
+            }
+            if data_len < threshold {
+                return Err(PacketReadError::InvalidData(anyhow::anyhow!(
+                    "Badly compressed packet. Size of {} is below the threshold of {}",
+                    data_len,
+                    threshold
+                )));
             }
-            _ => {}
+            let data_len = limits.check_decompressed_size(data_len)?;
+            // Never inflate past the Data Length that was checked
+            let mut decompressor = ZlibDecoder::new(current_packet).take(data_len as u64);
+            let id = var_int::inline::read(&mut decompressor)?.0;
+
+            let packet = limits.scope(|| IO::handle_read(id, data_len, &mut decompressor))?;
+            return Ok(Some(packet));
         }
 
         // Take the amount of bytes we need from the buffer and create a self
@@ -147,7 +200,8 @@ pub(crate) trait InternalPacketReader: PacketReader<ReadBuffer = BytesMut> {
         self.set_packet_length(PacketLength::Incomplete);
 
         // Read Packet
-        let packet = IO::handle_read(id, packet_len as usize, &mut current_packet)?;
+        let packet =
+            limits.scope(|| IO::handle_read(id, packet_len as usize, &mut current_packet))?;
         // Clear Packet Length
         let mut current_packet = current_packet.into_inner();
         {
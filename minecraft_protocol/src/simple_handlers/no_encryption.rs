@@ -3,7 +3,8 @@ use std::io::Write;
 
 use bytes::BytesMut;
 
-use crate::simple_handlers::{InternalPacketReader, InternalPacketWriter};
+use crate::limits::DecodeLimits;
+use crate::simple_handlers::{InternalPacketReader, InternalPacketWriter, SwapPacketIO};
 use crate::{
     CompressionSettings, PacketHandler, PacketIO, PacketLength, PacketReadError, PacketReader,
     PacketWriteError, PacketWriter,
@@ -15,6 +16,7 @@ pub struct NonEncryptedPacketReader<IO: PacketIO> {
     pub buffer: BytesMut,
     pub packet_len: PacketLength,
     pub compression: CompressionSettings,
+    pub limits: DecodeLimits,
 }
 
 impl<IO: PacketIO> Default for NonEncryptedPacketReader<IO> {
@@ -24,6 +26,21 @@ impl<IO: PacketIO> Default for NonEncryptedPacketReader<IO> {
             buffer: BytesMut::with_capacity(4096),
             packet_len: PacketLength::Incomplete,
             compression: CompressionSettings::default(),
+            limits: DecodeLimits::default(),
+        }
+    }
+}
+
+impl<IO: PacketIO, NewIO: PacketIO> SwapPacketIO<NewIO> for NonEncryptedPacketReader<IO> {
+    type Output = NonEncryptedPacketReader<NewIO>;
+
+    fn swap_io(self) -> Self::Output {
+        NonEncryptedPacketReader {
+            phantom: std::marker::PhantomData,
+            buffer: self.buffer,
+            packet_len: self.packet_len,
+            compression: self.compression,
+            limits: self.limits,
         }
     }
 }
@@ -51,6 +68,14 @@ impl<IO: PacketIO + Debug> PacketReader for NonEncryptedPacketReader<IO> {
         &self.packet_len
     }
 
+    fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        self.limits = limits;
+    }
+
+    fn decode_limits(&self) -> DecodeLimits {
+        self.limits
+    }
+
     fn attempt_packet_read(&mut self) -> Result<Option<Self::PacketIn>, PacketReadError> {
         self.attempt_read::<IO>()
     }
@@ -85,6 +110,18 @@ impl<IO: PacketIO> Default for NonEncryptedPacketWriter<IO> {
     }
 }
 
+impl<IO: PacketIO, NewIO: PacketIO> SwapPacketIO<NewIO> for NonEncryptedPacketWriter<IO> {
+    type Output = NonEncryptedPacketWriter<NewIO>;
+
+    fn swap_io(self) -> Self::Output {
+        NonEncryptedPacketWriter {
+            pending_buffer: self.pending_buffer,
+            compression: self.compression,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
 impl<IO: PacketIO + Debug> InternalPacketWriter<IO> for NonEncryptedPacketWriter<IO> {}
 impl<IO: PacketIO + Debug> PacketHandler for NonEncryptedPacketWriter<IO> {
     fn set_compression(&mut self, compression: CompressionSettings) {
@@ -4,9 +4,10 @@ use std::ptr;
 
 use bytes::BytesMut;
 
+use crate::limits::DecodeLimits;
 use crate::simple_handlers::encrypted::{EncryptedPacketReader, EncryptedPacketWriter};
 use crate::simple_handlers::no_encryption::NonEncryptedPacketReader;
-use crate::simple_handlers::NonEncryptedPacketWriter;
+use crate::simple_handlers::{NonEncryptedPacketWriter, SwapPacketIO};
 use crate::{
     CompressionSettings, Decryptor, Encryptor, PacketHandler, PacketIO, PacketLength,
     PacketReadError, PacketReader, PacketWriteError, PacketWriter,
@@ -23,6 +24,21 @@ impl<IO: PacketIO + Debug> Default for OptionalEncryptionReader<IO> {
         OptionalEncryptionReader::NoEncryption(NonEncryptedPacketReader::<IO>::default())
     }
 }
+impl<IO: PacketIO, NewIO: PacketIO> SwapPacketIO<NewIO> for OptionalEncryptionReader<IO> {
+    type Output = OptionalEncryptionReader<NewIO>;
+
+    fn swap_io(self) -> Self::Output {
+        match self {
+            OptionalEncryptionReader::Encrypted(reader) => {
+                OptionalEncryptionReader::Encrypted(reader.swap_io())
+            }
+            OptionalEncryptionReader::NoEncryption(reader) => {
+                OptionalEncryptionReader::NoEncryption(reader.swap_io())
+            }
+        }
+    }
+}
+
 impl<IO: PacketIO + Debug> PacketHandler for OptionalEncryptionReader<IO> {
     fn set_compression(&mut self, compression: CompressionSettings) {
         match self {
@@ -30,6 +46,12 @@ impl<IO: PacketIO + Debug> PacketHandler for OptionalEncryptionReader<IO> {
             OptionalEncryptionReader::NoEncryption(reader) => reader.set_compression(compression),
         }
     }
+    fn get_compression(&self) -> CompressionSettings {
+        match self {
+            OptionalEncryptionReader::Encrypted(reader) => reader.get_compression(),
+            OptionalEncryptionReader::NoEncryption(reader) => reader.get_compression(),
+        }
+    }
 }
 
 impl<IO: PacketIO + Debug> PacketReader for OptionalEncryptionReader<IO> {
@@ -56,6 +78,7 @@ impl<IO: PacketIO + Debug> PacketReader for OptionalEncryptionReader<IO> {
                         compression: value.compression,
                         decryptor,
                         last_decrypted_at: 0,
+                        limits: value.limits,
                     }),
                 );
             },
@@ -69,6 +92,20 @@ impl<IO: PacketIO + Debug> PacketReader for OptionalEncryptionReader<IO> {
         }
     }
 
+    fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        match self {
+            OptionalEncryptionReader::Encrypted(reader) => reader.set_decode_limits(limits),
+            OptionalEncryptionReader::NoEncryption(reader) => reader.set_decode_limits(limits),
+        }
+    }
+
+    fn decode_limits(&self) -> DecodeLimits {
+        match self {
+            OptionalEncryptionReader::Encrypted(reader) => reader.decode_limits(),
+            OptionalEncryptionReader::NoEncryption(reader) => reader.decode_limits(),
+        }
+    }
+
     fn minimum_bytes_needed(&self) -> usize {
         match self {
             OptionalEncryptionReader::Encrypted(reader) => reader.minimum_bytes_needed(),
@@ -116,6 +153,21 @@ impl<IO: PacketIO + Debug> Default for OptionalEncryptionWriter<IO> {
         OptionalEncryptionWriter::NoEncryption(NonEncryptedPacketWriter::<IO>::default())
     }
 }
+impl<IO: PacketIO, NewIO: PacketIO> SwapPacketIO<NewIO> for OptionalEncryptionWriter<IO> {
+    type Output = OptionalEncryptionWriter<NewIO>;
+
+    fn swap_io(self) -> Self::Output {
+        match self {
+            OptionalEncryptionWriter::Encrypted(writer) => {
+                OptionalEncryptionWriter::Encrypted(writer.swap_io())
+            }
+            OptionalEncryptionWriter::NoEncryption(writer) => {
+                OptionalEncryptionWriter::NoEncryption(writer.swap_io())
+            }
+        }
+    }
+}
+
 impl<IO: PacketIO + Debug> PacketHandler for OptionalEncryptionWriter<IO> {
     fn set_compression(&mut self, compression: CompressionSettings) {
         match self {
@@ -123,6 +175,12 @@ impl<IO: PacketIO + Debug> PacketHandler for OptionalEncryptionWriter<IO> {
             OptionalEncryptionWriter::NoEncryption(writer) => writer.set_compression(compression),
         }
     }
+    fn get_compression(&self) -> CompressionSettings {
+        match self {
+            OptionalEncryptionWriter::Encrypted(writer) => writer.get_compression(),
+            OptionalEncryptionWriter::NoEncryption(writer) => writer.get_compression(),
+        }
+    }
 }
 
 impl<IO: PacketIO + Debug> PacketWriter for OptionalEncryptionWriter<IO> {
@@ -0,0 +1,505 @@
+//! Records every packet that passes through a [PacketReader] or [PacketWriter] to a capture file.
+//!
+//! Packets are recorded after decryption and decompression. So a capture of an encrypted
+//! connection can still be decoded with the `packet_capture` binary.
+//! Read packets are recorded before they are decoded. Packets that fail to decode are in the capture too.
+//!
+//! One capture can hold many connections. Use [Recorder::new_connection] for each connection.
+//!
+//! ```rust,no_run
+//! use minecraft_protocol::java::v_761::login;
+//! use minecraft_protocol::simple_handlers::recording::{Recorder, RecordingReader};
+//! use minecraft_protocol::simple_handlers::NonEncryptedPacketReader;
+//! use minecraft_protocol::{Bound, Stage};
+//!
+//! # fn example() -> std::io::Result<()> {
+//! let recorder = Recorder::create("login.mcpcap")?;
+//! let reader = RecordingReader::<login::ServerIO, _>::new(
+//!     NonEncryptedPacketReader::<login::ServerIO>::default(),
+//!     recorder,
+//!     Bound::ServerBound,
+//!     Stage::Login,
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::{Debug, Formatter};
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::data::var_int::VarInt;
+use crate::data::{var_int, PacketDataType};
+use crate::limits::DecodeLimits;
+use crate::simple_handlers::SwapPacketIO;
+use crate::{
+    Bound, CompressionSettings, PacketContent, PacketHandler, PacketIO, PacketLength,
+    PacketReadError, PacketReader, PacketWriteError, PacketWriter, Stage,
+};
+#[cfg(feature = "encryption")]
+use crate::{Decryptor, Encryptor};
+
+/// The first bytes of every capture file
+pub const CAPTURE_MAGIC: [u8; 6] = *b"MCPCAP";
+/// Increased if the layout of [CaptureRecord] changes
+pub const CAPTURE_FORMAT_VERSION: u8 = 2;
+
+/// A single packet in a capture file
+///
+/// Stored as `[Timestamp u64] [Connection u64] [Bound u8] [Stage u8] [Packet ID VarInt] [Data Length VarInt] [Data]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureRecord {
+    /// Milliseconds since the unix epoch
+    pub timestamp: u64,
+    /// Tells the connections of a capture apart. See [Recorder::new_connection]
+    pub connection: u64,
+    pub bound: Bound,
+    pub stage: Stage,
+    pub packet_id: i32,
+    /// The packet without the packet id
+    pub data: Vec<u8>,
+}
+
+impl CaptureRecord {
+    pub fn new(connection: u64, bound: Bound, stage: Stage, packet_id: i32, data: Vec<u8>) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default();
+        Self {
+            timestamp,
+            connection,
+            bound,
+            stage,
+            packet_id,
+            data,
+        }
+    }
+
+    pub fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        self.timestamp.write(w)?;
+        self.connection.write(w)?;
+        let bound: u8 = match self.bound {
+            Bound::ServerBound => 0,
+            Bound::ClientBound => 1,
+        };
+        bound.write(w)?;
+        let stage: u8 = match self.stage {
+            Stage::Handshake => 0,
+            Stage::Status => 1,
+            Stage::Login => 2,
+            Stage::Play => 3,
+        };
+        stage.write(w)?;
+        VarInt(self.packet_id).write(w)?;
+        VarInt(self.data.len() as i32).write(w)?;
+        w.write_all(&self.data)
+    }
+
+    pub fn read<R: Read>(r: &mut R) -> io::Result<Self> {
+        let timestamp = u64::read(r)?;
+        let connection = u64::read(r)?;
+        let bound = match u8::read(r)? {
+            0 => Bound::ServerBound,
+            1 => Bound::ClientBound,
+            other => return Err(invalid_data(format!("Invalid bound {}", other))),
+        };
+        let stage = match u8::read(r)? {
+            0 => Stage::Handshake,
+            1 => Stage::Status,
+            2 => Stage::Login,
+            3 => Stage::Play,
+            other => return Err(invalid_data(format!("Invalid stage {}", other))),
+        };
+        let packet_id = VarInt::read(r)?.0;
+        let len = VarInt::read(r)?.0;
+        if len < 0 {
+            return Err(invalid_data(format!("Invalid data length {}", len)));
+        }
+        let mut data = Vec::new();
+        r.take(len as u64).read_to_end(&mut data)?;
+        if data.len() != len as usize {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(Self {
+            timestamp,
+            connection,
+            bound,
+            stage,
+            packet_id,
+            data,
+        })
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Appends records to a capture. Clones write to the same capture with the same connection id.
+///
+/// Each record is written with a single write. A crash will not leave the capture half written
+#[derive(Clone)]
+pub struct Recorder {
+    output: Arc<Mutex<Box<dyn Write + Send>>>,
+    connection: u64,
+    next_connection: Arc<AtomicU64>,
+}
+
+impl Debug for Recorder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recorder")
+            .field("connection", &self.connection)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Recorder {
+    /// Writes the capture header to the output. Records with the connection id 0
+    pub fn new(mut output: impl Write + Send + 'static) -> io::Result<Self> {
+        output.write_all(&CAPTURE_MAGIC)?;
+        output.write_all(&[CAPTURE_FORMAT_VERSION])?;
+        output.flush()?;
+        Ok(Self {
+            output: Arc::new(Mutex::new(Box::new(output))),
+            connection: 0,
+            next_connection: Arc::new(AtomicU64::new(1)),
+        })
+    }
+
+    /// Writes to the same capture with a connection id that no other recorder of the capture has
+    pub fn new_connection(&self) -> Self {
+        Self {
+            output: self.output.clone(),
+            connection: self.next_connection.fetch_add(1, Ordering::Relaxed),
+            next_connection: self.next_connection.clone(),
+        }
+    }
+
+    pub fn connection(&self) -> u64 {
+        self.connection
+    }
+
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(File::create(path)?)
+    }
+
+    pub fn record(&self, record: &CaptureRecord) -> io::Result<()> {
+        let mut buffer = Vec::with_capacity(record.data.len() + 16);
+        record.write(&mut buffer)?;
+        // A poisoned lock only means another thread panicked while holding it
+        let mut output = self.output.lock().unwrap_or_else(|err| err.into_inner());
+        output.write_all(&buffer)?;
+        output.flush()
+    }
+}
+
+/// Reads the records of a capture in order
+#[derive(Debug)]
+pub struct CaptureReader<R> {
+    reader: R,
+}
+
+impl CaptureReader<BufReader<File>> {
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> CaptureReader<R> {
+    /// Checks the capture header
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut header = [0u8; 7];
+        reader.read_exact(&mut header)?;
+        if header[..6] != CAPTURE_MAGIC {
+            return Err(invalid_data("Not a packet capture".to_string()));
+        }
+        if header[6] != CAPTURE_FORMAT_VERSION {
+            return Err(invalid_data(format!(
+                "Unsupported capture version {}",
+                header[6]
+            )));
+        }
+        Ok(Self { reader })
+    }
+}
+
+impl<R: BufRead> Iterator for CaptureReader<R> {
+    type Item = io::Result<CaptureRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.fill_buf() {
+            Ok([]) => None,
+            Ok(_) => Some(CaptureRecord::read(&mut self.reader)),
+            Err(err) => Some(Err(err)),
+        }
+    }
+}
+
+/// A packet that has already been encoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawPacket {
+    pub packet_id: i32,
+    /// The packet without the packet id
+    pub data: Vec<u8>,
+}
+
+impl PacketContent for RawPacket {}
+
+/// Does not decode or encode the packets. Used by the recorders and to write packets from a capture
+#[derive(Debug)]
+pub struct RawIO;
+
+impl PacketIO for RawIO {
+    type Type = RawPacket;
+
+    fn handle_read<R: Read>(
+        packed_id: i32,
+        _len: usize,
+        reader: &mut R,
+    ) -> Result<Self::Type, PacketReadError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Ok(RawPacket {
+            packet_id: packed_id,
+            data,
+        })
+    }
+
+    fn handle_write<W: Write>(packet: Self::Type, writer: &mut W) -> Result<(), PacketWriteError> {
+        var_int::inline::write(packet.packet_id, writer)?;
+        writer.write_all(&packet.data)?;
+        Ok(())
+    }
+}
+
+/// Records every packet read by the inner reader
+#[derive(Debug)]
+pub struct RecordingReader<IO: PacketIO, R> {
+    pub inner: R,
+    pub recorder: Recorder,
+    /// The bound of the packets being read
+    pub bound: Bound,
+    pub stage: Stage,
+    phantom: PhantomData<IO>,
+}
+
+impl<IO: PacketIO, R> RecordingReader<IO, R> {
+    /// Moves the inner reader over to [RawIO]. Packets are decoded after they are recorded
+    pub fn new<Inner: SwapPacketIO<RawIO, Output = R>>(
+        inner: Inner,
+        recorder: Recorder,
+        bound: Bound,
+        stage: Stage,
+    ) -> Self {
+        Self {
+            inner: inner.swap_io(),
+            recorder,
+            bound,
+            stage,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Moves to the next stage. The inner reader does not change
+    pub fn swap_io<NewIO: PacketIO>(self, stage: Stage) -> RecordingReader<NewIO, R> {
+        RecordingReader {
+            inner: self.inner,
+            recorder: self.recorder,
+            bound: self.bound,
+            stage,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<IO, R> PacketHandler for RecordingReader<IO, R>
+where
+    IO: PacketIO + Debug,
+    R: PacketReader<PacketIn = RawPacket>,
+{
+    fn set_compression(&mut self, compression: CompressionSettings) {
+        self.inner.set_compression(compression);
+    }
+    fn get_compression(&self) -> CompressionSettings {
+        self.inner.get_compression()
+    }
+}
+
+impl<IO, R> PacketReader for RecordingReader<IO, R>
+where
+    IO: PacketIO + Debug,
+    R: PacketReader<PacketIn = RawPacket>,
+{
+    type PacketIn = IO::Type;
+    type ReadBuffer = R::ReadBuffer;
+
+    #[cfg(feature = "encryption")]
+    fn set_decryptor(&mut self, decryptor: Decryptor) {
+        self.inner.set_decryptor(decryptor);
+    }
+
+    fn packet_len(&self) -> &PacketLength {
+        self.inner.packet_len()
+    }
+
+    fn set_decode_limits(&mut self, limits: DecodeLimits) {
+        self.inner.set_decode_limits(limits);
+    }
+
+    fn decode_limits(&self) -> DecodeLimits {
+        self.inner.decode_limits()
+    }
+
+    fn minimum_bytes_needed(&self) -> usize {
+        self.inner.minimum_bytes_needed()
+    }
+
+    fn attempt_packet_read(&mut self) -> Result<Option<Self::PacketIn>, PacketReadError> {
+        let Some(raw) = self.inner.attempt_packet_read()? else {
+            return Ok(None);
+        };
+        let record = CaptureRecord::new(
+            self.recorder.connection(),
+            self.bound,
+            self.stage,
+            raw.packet_id,
+            raw.data,
+        );
+        self.recorder.record(&record)?;
+        let packet = self.inner.decode_limits().scope(|| {
+            IO::handle_read(
+                record.packet_id,
+                record.data.len(),
+                &mut record.data.as_slice(),
+            )
+        })?;
+        Ok(Some(packet))
+    }
+
+    fn get_read_buffer(&mut self) -> &mut Self::ReadBuffer {
+        self.inner.get_read_buffer()
+    }
+
+    fn get_read_buffer_ref(&self) -> &Self::ReadBuffer {
+        self.inner.get_read_buffer_ref()
+    }
+
+    fn force_buffer_clear(&mut self) {
+        self.inner.force_buffer_clear();
+    }
+}
+
+/// Records every packet written by the inner writer
+#[derive(Debug)]
+pub struct RecordingWriter<IO: PacketIO, W> {
+    pub inner: W,
+    pub recorder: Recorder,
+    /// The bound of the packets being written
+    pub bound: Bound,
+    pub stage: Stage,
+    phantom: PhantomData<IO>,
+}
+
+impl<IO: PacketIO, W> RecordingWriter<IO, W> {
+    /// Moves the inner writer over to [RawIO]. Packets are encoded before they reach it
+    pub fn new<Inner: SwapPacketIO<RawIO, Output = W>>(
+        inner: Inner,
+        recorder: Recorder,
+        bound: Bound,
+        stage: Stage,
+    ) -> Self {
+        Self {
+            inner: inner.swap_io(),
+            recorder,
+            bound,
+            stage,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Moves to the next stage. The inner writer does not change
+    pub fn swap_io<NewIO: PacketIO>(self, stage: Stage) -> RecordingWriter<NewIO, W> {
+        RecordingWriter {
+            inner: self.inner,
+            recorder: self.recorder,
+            bound: self.bound,
+            stage,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Encodes the packet and records it
+    fn capture(&self, packet: IO::Type) -> Result<RawPacket, PacketWriteError> {
+        let mut bytes = Vec::new();
+        IO::handle_write(packet, &mut bytes)?;
+        let mut reader = bytes.as_slice();
+        let packet_id = var_int::inline::read(&mut reader)?.0;
+        let record = CaptureRecord::new(
+            self.recorder.connection(),
+            self.bound,
+            self.stage,
+            packet_id,
+            reader.to_vec(),
+        );
+        self.recorder.record(&record)?;
+        Ok(RawPacket {
+            packet_id,
+            data: record.data,
+        })
+    }
+}
+
+impl<IO, W> PacketHandler for RecordingWriter<IO, W>
+where
+    IO: PacketIO + Debug,
+    W: PacketWriter<PacketOut = RawPacket>,
+{
+    fn set_compression(&mut self, compression: CompressionSettings) {
+        self.inner.set_compression(compression);
+    }
+    fn get_compression(&self) -> CompressionSettings {
+        self.inner.get_compression()
+    }
+}
+
+impl<IO, W> PacketWriter for RecordingWriter<IO, W>
+where
+    IO: PacketIO + Debug,
+    W: PacketWriter<PacketOut = RawPacket>,
+{
+    type Buffer = W::Buffer;
+    type PacketOut = IO::Type;
+
+    fn force_buffer_clear(&mut self) {
+        self.inner.force_buffer_clear();
+    }
+
+    fn get_buffer(&mut self) -> &mut Self::Buffer {
+        self.inner.get_buffer()
+    }
+
+    #[cfg(feature = "encryption")]
+    fn set_encryptor(&mut self, encryptor: Encryptor) {
+        self.inner.set_encryptor(encryptor);
+    }
+
+    fn write_packet(&mut self, packet: impl Into<Self::PacketOut>) -> Result<(), PacketWriteError> {
+        let packet = self.capture(packet.into())?;
+        self.inner.write_packet(packet)
+    }
+
+    fn send_packet<Output: Write>(
+        &mut self,
+        packet: impl Into<Self::PacketOut>,
+        writer: &mut Output,
+    ) -> Result<(), PacketWriteError> {
+        let packet = self.capture(packet.into())?;
+        self.inner.send_packet(packet, writer)
+    }
+}
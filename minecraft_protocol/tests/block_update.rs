@@ -0,0 +1,67 @@
+use minecraft_protocol::data::var_long::VarLong;
+use minecraft_protocol::data::{PackedPosition, PacketDataType};
+use minecraft_protocol::java::v_761::play::client::block::ClientBoundUpdateSectionBlocksImpl;
+use minecraft_protocol::packets::play::client::block::{
+    BlockUpdate, SectionBlock, SectionPosition, UpdateSectionBlocks,
+};
+use minecraft_protocol::Packet;
+
+#[test]
+pub fn packed_position() {
+    let position = PackedPosition::new((18357644, 831, -20882616));
+    assert_eq!(position.0, 0x4607632C15B4833F);
+    assert_eq!(
+        position.position::<(i32, i32, i32)>(),
+        (18357644, 831, -20882616)
+    );
+
+    let update = BlockUpdate::new((-1, -64, -1), &9);
+    assert_eq!(update.location.position::<(i64, i64, i64)>(), (-1, -64, -1));
+    assert_eq!(update.block_state, 9);
+}
+
+#[test]
+pub fn var_long() {
+    for (value, bytes) in [
+        (0i64, vec![0x00]),
+        (255, vec![0xff, 0x01]),
+        (
+            i64::MAX,
+            vec![0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f],
+        ),
+        (
+            -1,
+            vec![0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+        ),
+    ] {
+        let mut written = Vec::new();
+        VarLong(value).write(&mut written).unwrap();
+        assert_eq!(written, bytes);
+        assert_eq!(VarLong::read(&mut bytes.as_slice()).unwrap().0, value);
+    }
+    assert!(VarLong::read(&mut [0xff; 11].as_slice()).is_err());
+}
+
+#[test]
+pub fn update_section_blocks() {
+    let packet = UpdateSectionBlocks {
+        section: SectionPosition { x: -2, y: -4, z: 3 },
+        suppress_light_updates: true,
+        blocks: vec![SectionBlock::new(1, 2, 3, &1)],
+    };
+    let mut bytes = Vec::new();
+    ClientBoundUpdateSectionBlocksImpl::write(packet.clone(), &mut bytes).unwrap();
+
+    let mut expected = vec![0x3F];
+    let section = (-2i64 & 0x3FFFFF) << 42 | 3 << 20 | (-4i64 & 0xFFFFF);
+    expected.extend_from_slice(&section.to_be_bytes());
+    expected.push(1);
+    expected.push(1);
+    VarLong(1 << 12 | 1 << 8 | 3 << 4 | 2)
+        .write(&mut expected)
+        .unwrap();
+    assert_eq!(bytes, expected);
+
+    let read = ClientBoundUpdateSectionBlocksImpl::read(&mut &bytes[1..]).unwrap();
+    assert_eq!(read, packet);
+}
@@ -0,0 +1,142 @@
+use serde_json::json;
+use uuid::Uuid;
+
+use minecraft_protocol::chat::{
+    ClickEvent, Color, Component, Content, HoverEntity, HoverEvent, NamedColor,
+};
+use minecraft_protocol::data::PacketDataType;
+
+#[test]
+pub fn serialize() {
+    let component = Component::text("Hello ")
+        .color(NamedColor::Gold)
+        .append(
+            Component::translatable("chat.type.text", vec!["Notch".into(), "hi".into()])
+                .fallback("<%s> %s")
+                .color(Color::Rgb(0x12AB34))
+                .click_event(ClickEvent::ChangePage(2)),
+        )
+        .append(Component::keybind("key.jump").hover_text("Jump"))
+        .append(Component::score("Notch", "kills"))
+        .append(Component::selector("@a"));
+    let json = serde_json::to_value(&component).unwrap();
+    assert_eq!(
+        json,
+        json!({
+            "text": "Hello ",
+            "color": "gold",
+            "extra": [
+                {
+                    "translate": "chat.type.text",
+                    "with": [{"text": "Notch"}, {"text": "hi"}],
+                    "fallback": "<%s> %s",
+                    "color": "#12AB34",
+                    "clickEvent": {"action": "change_page", "value": "2"}
+                },
+                {
+                    "keybind": "key.jump",
+                    "hoverEvent": {"action": "show_text", "contents": {"text": "Jump"}}
+                },
+                {"score": {"name": "Notch", "objective": "kills"}},
+                {"selector": "@a"}
+            ]
+        })
+    );
+    let read: Component = serde_json::from_value(json).unwrap();
+    assert_eq!(read, component);
+}
+
+#[test]
+pub fn deserialize_shorthands() {
+    let component: Component =
+        serde_json::from_value(json!(["a", {"text": "b", "bold": true}, 3, true])).unwrap();
+    assert_eq!(
+        component,
+        Component::text("a")
+            .append(Component::text("b").bold(true))
+            .append("3")
+            .append("true")
+    );
+
+    let entity: Component = serde_json::from_value(json!({
+        "text": "",
+        "hoverEvent": {
+            "action": "show_entity",
+            "contents": {"type": "minecraft:pig", "id": "069a79f4-44e9-4726-a5be-fca90e38aaf5"}
+        }
+    }))
+    .unwrap();
+    assert_eq!(
+        entity.style.hover_event,
+        Some(HoverEvent::ShowEntity(HoverEntity {
+            entity_type: "minecraft:pig".to_string(),
+            id: Uuid::parse_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap(),
+            name: None,
+        }))
+    );
+
+    assert!(serde_json::from_value::<Component>(json!({"color": "red"})).is_err());
+    assert!(serde_json::from_value::<Component>(json!({"text": "", "color": "pink"})).is_err());
+    assert!(serde_json::from_value::<Component>(json!([])).is_err());
+}
+
+#[test]
+pub fn legacy() {
+    let component = Component::from_legacy("§6Gold §lbold§r plain §x");
+    assert_eq!(
+        component.children,
+        vec![
+            Component::text("Gold ").color(NamedColor::Gold),
+            Component::text("bold").color(NamedColor::Gold).bold(true),
+            Component::text(" plain §x"),
+        ]
+    );
+    assert_eq!(component.to_legacy(), "§6Gold §lbold§r plain §x");
+    assert_eq!(component.to_plain_text(), "Gold bold plain §x");
+
+    assert_eq!(Component::from_legacy("plain"), Component::text("plain"));
+    assert_eq!(
+        Component::from_legacy_with("&cred", '&'),
+        Component::from_legacy("§cred")
+    );
+    // RGB colors are rounded and the children inherit the color
+    let component = Component::text("a")
+        .color(Color::Rgb(0xFE5050))
+        .append(Component::text("b").italic(true))
+        .append(Component::text("c").color(NamedColor::Red));
+    assert_eq!(component.to_legacy(), "§ca§ob§cc");
+}
+
+#[test]
+pub fn plain_text_translation() {
+    let component = Component::translatable("%2$s %s%% %s", vec!["a".into(), "b".into()]);
+    assert_eq!(component.to_plain_text(), "b a% b");
+    let Content::Translatable { key, .. } = &component.content else {
+        panic!("Expected a translatable component");
+    };
+    assert_eq!(key, "%2$s %s%% %s");
+}
+
+#[test]
+pub fn packet_data_type() {
+    let component = Component::text("Hi").color(NamedColor::Aqua);
+    let mut buffer = Vec::new();
+    component.clone().write(&mut buffer).unwrap();
+    assert_eq!(
+        String::read(&mut buffer.as_slice()).unwrap(),
+        r#"{"text":"Hi","color":"aqua"}"#
+    );
+    assert_eq!(Component::read(&mut buffer.as_slice()).unwrap(), component);
+
+    // Longer than a string but within the component limit
+    let long = Component::text("x".repeat(100_000));
+    let mut buffer = Vec::new();
+    long.clone().write(&mut buffer).unwrap();
+    String::read(&mut buffer.as_slice()).unwrap_err();
+    assert_eq!(Component::read(&mut buffer.as_slice()).unwrap(), long);
+
+    let mut buffer = Vec::new();
+    Component::text("x".repeat(300_000))
+        .write(&mut buffer)
+        .unwrap_err();
+}
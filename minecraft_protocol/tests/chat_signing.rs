@@ -0,0 +1,291 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rsa::pkcs8::EncodePublicKey;
+use rsa::{PaddingScheme, RsaPrivateKey};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use minecraft_protocol::chat::Component;
+use minecraft_protocol::java::v_761::play;
+use minecraft_protocol::java::v_761::play::server::chat::MAX_MESSAGE_LENGTH;
+use minecraft_protocol::java::v_761::processor::chat::{
+    message_payload, profile_key_payload, LastSeenTracker, PlayerChat, ServicesKeys,
+    SignedChatError,
+};
+use minecraft_protocol::packets::play::client::chat::{ChatTypeBinding, PackedSignature};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::packets::play::server::chat::{
+    ChatCommand, ChatMessage, LastSeenUpdate, MessageAcknowledgment, PlayerSession,
+    ProfilePublicKey,
+};
+use minecraft_protocol::packets::play::server::ServerBoundPlay;
+use minecraft_protocol::packets::play::MessageSignature;
+use minecraft_protocol::{PacketIO, PacketReadError};
+
+const PLAYER: Uuid = Uuid::from_u128(0x069a79f4_44e9_4726_a5be_fca90e38aaf5);
+const SESSION: Uuid = Uuid::from_u128(0x1234);
+
+struct Keys {
+    mojang: RsaPrivateKey,
+    /// Message signatures are always 256 bytes so the player key is 2048 bits
+    player: RsaPrivateKey,
+}
+
+fn keys() -> Keys {
+    let mut rng = rand::thread_rng();
+    Keys {
+        mojang: RsaPrivateKey::new(&mut rng, 1024).unwrap(),
+        player: RsaPrivateKey::new(&mut rng, 2048).unwrap(),
+    }
+}
+
+fn millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH).unwrap().as_millis() as i64
+}
+
+fn services_keys(keys: &Keys) -> ServicesKeys {
+    let der = keys.mojang.to_public_key().to_public_key_der().unwrap();
+    ServicesKeys::from_der(&[der.as_bytes()]).unwrap()
+}
+
+fn player_session(keys: &Keys, expires_at: i64) -> PlayerSession {
+    let mut public_key = ProfilePublicKey {
+        expires_at,
+        key: keys
+            .player
+            .to_public_key()
+            .to_public_key_der()
+            .unwrap()
+            .as_bytes()
+            .to_vec(),
+        key_signature: vec![],
+    };
+    public_key.key_signature = keys
+        .mojang
+        .sign(
+            PaddingScheme::new_pkcs1v15_sign::<Sha1>(),
+            &Sha1::digest(profile_key_payload(PLAYER, &public_key)),
+        )
+        .unwrap();
+    PlayerSession {
+        session_id: SESSION,
+        public_key,
+    }
+}
+
+fn signed_message(
+    keys: &Keys,
+    index: i32,
+    message: &str,
+    timestamp: i64,
+    last_seen: &[MessageSignature],
+    update: LastSeenUpdate,
+) -> ChatMessage {
+    let payload = message_payload(PLAYER, SESSION, index, 7, timestamp, message, last_seen);
+    let signature = keys
+        .player
+        .sign(
+            PaddingScheme::new_pkcs1v15_sign::<Sha256>(),
+            &Sha256::digest(payload),
+        )
+        .unwrap();
+    ChatMessage {
+        message: message.to_string(),
+        timestamp,
+        salt: 7,
+        signature: MessageSignature::from_slice(&signature),
+        last_seen: update,
+    }
+}
+
+fn binding() -> ChatTypeBinding {
+    ChatTypeBinding {
+        chat_type: 0,
+        name: Component::text("Notch"),
+        target_name: None,
+    }
+}
+
+#[test]
+pub fn secure_chat() {
+    let keys = keys();
+    let services = services_keys(&keys);
+    let now = SystemTime::now();
+    let expires_at = millis(now + Duration::from_secs(3600));
+
+    let mut chat = PlayerChat::new(PLAYER, true);
+    let message = signed_message(&keys, 0, "hi", millis(now), &[], LastSeenUpdate::default());
+    assert!(matches!(
+        chat.handle_message(message.clone()),
+        Err(SignedChatError::MissingSession)
+    ));
+
+    // Signed by another key
+    let mut forged = player_session(&keys, expires_at);
+    forged.public_key.expires_at += 1;
+    assert!(matches!(
+        chat.handle_session(forged, &services, now),
+        Err(SignedChatError::InvalidKeySignature)
+    ));
+    assert!(matches!(
+        chat.handle_session(player_session(&keys, millis(now) - 1), &services, now),
+        Err(SignedChatError::ExpiredKey)
+    ));
+    chat.handle_session(player_session(&keys, expires_at), &services, now)
+        .unwrap();
+
+    let received = chat.handle_message(message).unwrap();
+    assert_eq!(received.index, 0);
+    let ClientBoundPlay::PlayerChat(packet) = received.packet(binding()) else {
+        panic!("Expected a player chat message");
+    };
+    assert_eq!(packet.message, "hi");
+    assert_eq!(packet.signature, received.signature);
+
+    // The client saw its own message and signs the next one with it
+    let first = received.signature.unwrap();
+    chat.track_sent(first).unwrap();
+    let update = LastSeenUpdate {
+        offset: 1,
+        acknowledged: 1 << 19,
+    };
+    let second = signed_message(&keys, 1, "again", millis(now) + 5, &[first], update);
+    let received = chat.handle_message(second).unwrap();
+    assert_eq!(received.index, 1);
+    assert_eq!(received.last_seen, vec![first]);
+    let ClientBoundPlay::PlayerChat(packet) = received.packet(binding()) else {
+        panic!("Expected a player chat message");
+    };
+    assert_eq!(packet.previous_messages, vec![PackedSignature::Full(first)]);
+
+    // Signed for an index that was already used
+    let replay = signed_message(&keys, 1, "again", millis(now) + 6, &[first], update);
+    let replay = ChatMessage {
+        last_seen: LastSeenUpdate {
+            offset: 0,
+            acknowledged: 1 << 19,
+        },
+        ..replay
+    };
+    assert!(matches!(
+        chat.handle_message(replay),
+        Err(SignedChatError::InvalidSignature)
+    ));
+    let old = signed_message(&keys, 2, "old", millis(now), &[], LastSeenUpdate::default());
+    assert!(matches!(
+        chat.handle_message(old),
+        Err(SignedChatError::OutOfOrder)
+    ));
+}
+
+#[test]
+pub fn unsigned_mode() {
+    let mut chat = PlayerChat::new(PLAYER, false);
+    let received = chat
+        .handle_message(ChatMessage {
+            message: "hello".to_string(),
+            timestamp: 1,
+            salt: 0,
+            signature: Some(MessageSignature([1; 256])),
+            last_seen: LastSeenUpdate::default(),
+        })
+        .unwrap();
+    assert_eq!(received.signature, None);
+    let ClientBoundPlay::DisguisedChat(packet) = received.packet(binding()) else {
+        panic!("Expected a disguised chat message");
+    };
+    assert_eq!(packet.message, Component::text("hello"));
+
+    // Commands without signed arguments do not need a session
+    let mut chat = PlayerChat::new(PLAYER, true);
+    let mut command = ChatCommand {
+        command: "time set day".to_string(),
+        timestamp: 1,
+        salt: 0,
+        argument_signatures: vec![],
+        last_seen: LastSeenUpdate::default(),
+    };
+    chat.handle_command(&command).unwrap();
+    command.argument_signatures.push(
+        minecraft_protocol::packets::play::server::chat::ArgumentSignature {
+            name: "message".to_string(),
+            signature: MessageSignature([0; 256]),
+        },
+    );
+    assert!(matches!(
+        chat.handle_command(&command),
+        Err(SignedChatError::MissingSession)
+    ));
+}
+
+#[test]
+pub fn last_seen_tracker() {
+    let signatures: Vec<MessageSignature> = (0..3u8).map(|i| MessageSignature([i; 256])).collect();
+    let mut tracker = LastSeenTracker::default();
+    for signature in &signatures {
+        tracker.add_pending(*signature);
+    }
+    // Sending the same signature twice in a row is only tracked once
+    tracker.add_pending(signatures[2]);
+    assert_eq!(tracker.tracked_count(), 23);
+
+    assert!(tracker.apply_offset(4).is_err());
+    let last_seen = tracker
+        .apply_update(LastSeenUpdate {
+            offset: 2,
+            acknowledged: 1 << 18 | 1 << 19,
+        })
+        .unwrap();
+    assert_eq!(last_seen, vec![signatures[0], signatures[1]]);
+    assert_eq!(tracker.tracked_count(), 21);
+
+    // An acknowledged message can not be unacknowledged
+    assert!(tracker
+        .apply_update(LastSeenUpdate {
+            offset: 0,
+            acknowledged: 1 << 19,
+        })
+        .is_err());
+    // More bits than tracked messages
+    let mut tracker = LastSeenTracker::default();
+    assert!(tracker
+        .apply_update(LastSeenUpdate {
+            offset: 0,
+            acknowledged: 1 << 20,
+        })
+        .is_err());
+    let mut chat = PlayerChat::new(PLAYER, true);
+    chat.track_sent(signatures[0]).unwrap();
+    chat.handle_acknowledgment(MessageAcknowledgment { offset: 1 })
+        .unwrap();
+}
+
+/// The limit is in characters. Not in bytes
+#[test]
+pub fn message_length() {
+    let packet = |message: String| {
+        ServerBoundPlay::ChatMessage(ChatMessage {
+            message,
+            timestamp: 0,
+            salt: 0,
+            signature: None,
+            last_seen: LastSeenUpdate {
+                offset: 0,
+                acknowledged: 0,
+            },
+        })
+    };
+    let read = |packet: ServerBoundPlay| {
+        let mut buffer = Vec::new();
+        play::ServerIO::handle_write(packet, &mut buffer).unwrap();
+        let mut reader = &buffer[1..];
+        play::ServerIO::handle_read(buffer[0] as i32, reader.len(), &mut reader)
+    };
+    let longest = packet("語".repeat(MAX_MESSAGE_LENGTH));
+    assert_eq!(read(longest.clone()).unwrap(), longest);
+    assert!(matches!(
+        read(packet("語".repeat(MAX_MESSAGE_LENGTH + 1))),
+        Err(PacketReadError::InvalidData(_))
+    ));
+}
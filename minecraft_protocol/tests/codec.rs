@@ -0,0 +1,160 @@
+use cfb8::cipher::KeyIvInit;
+use futures_util::{SinkExt, StreamExt};
+use tokio_util::codec::Framed;
+
+use minecraft_protocol::data::var_int::VarInt;
+use minecraft_protocol::java::handshake::{HandShake, HandShakeIO, NextState};
+use minecraft_protocol::java::status::{
+    ClientBoundStatusIO, ClientBoundStatusPacket, ServerBoundStatusIO, ServerBoundStatusPacket,
+};
+use minecraft_protocol::simple_handlers::codec::OptionalEncryptionCodec;
+use minecraft_protocol::simple_handlers::{
+    EncryptedPacketWriter, NonEncryptedPacketReader, NonEncryptedPacketWriter, PacketCodec,
+};
+use minecraft_protocol::{
+    AsyncStreamCipher, CompressionSettings, Decryptor, Encryptor, PacketHandler, PacketWriter,
+};
+
+fn handshake() -> HandShake {
+    HandShake {
+        protocol_version: VarInt(761),
+        server_address: "127.0.0.1".to_string(),
+        server_port: 25565,
+        next_state: NextState::Status,
+    }
+}
+
+/// The client sends the handshake and the status request in the same write.
+/// The server must keep the bytes of the status request when it swaps stages.
+#[tokio::test]
+pub async fn swap_stage_keeps_buffered_bytes() -> anyhow::Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let mut client = Framed::new(
+        client,
+        PacketCodec::new(
+            NonEncryptedPacketReader::<ClientBoundStatusIO>::default(),
+            NonEncryptedPacketWriter::<HandShakeIO>::default(),
+        ),
+    );
+    let mut server = Framed::new(
+        server,
+        OptionalEncryptionCodec::<HandShakeIO, ClientBoundStatusIO>::default(),
+    );
+
+    client.feed(handshake()).await?;
+    let mut client =
+        client.map_codec(|codec| codec.swap_io::<ClientBoundStatusIO, ServerBoundStatusIO>());
+    client.feed(ServerBoundStatusPacket::Request).await?;
+    client.send(ServerBoundStatusPacket::Ping(42)).await?;
+
+    let received = server.next().await.expect("Stream closed")?;
+    assert_eq!(received, handshake());
+
+    let mut server =
+        server.map_codec(|codec| codec.swap_io::<ServerBoundStatusIO, ClientBoundStatusIO>());
+    let request = server.next().await.expect("Stream closed")?;
+    assert_eq!(request, ServerBoundStatusPacket::Request);
+    let ping = server.next().await.expect("Stream closed")?;
+    assert_eq!(ping, ServerBoundStatusPacket::Ping(42));
+
+    server.send(ClientBoundStatusPacket::Ping(42)).await?;
+    let pong = client.next().await.expect("Stream closed")?;
+    assert_eq!(pong, ClientBoundStatusPacket::Ping(42));
+    Ok(())
+}
+
+#[test]
+pub fn swap_stage_keeps_compression() {
+    let compression = CompressionSettings::Zlib {
+        threshold: 256,
+        compression_level: 6,
+    };
+    let mut codec = OptionalEncryptionCodec::<HandShakeIO, ClientBoundStatusIO>::default();
+    codec.set_compression(compression);
+    let codec = codec.swap_io::<ServerBoundStatusIO, ClientBoundStatusIO>();
+    assert_eq!(codec.reader.get_compression(), compression);
+    assert_eq!(codec.writer.get_compression(), compression);
+}
+
+/// Bytes that arrive after the packet that enables encryption are still encrypted.
+/// They have to be decrypted once the decryptor is set.
+#[tokio::test]
+pub async fn enable_encryption_mid_stream() -> anyhow::Result<()> {
+    let secret = [7u8; 16];
+    let (client, server) = tokio::io::duplex(1024);
+    let mut client = Framed::new(
+        client,
+        OptionalEncryptionCodec::<ClientBoundStatusIO, ServerBoundStatusIO>::default(),
+    );
+    let mut server = Framed::new(
+        server,
+        OptionalEncryptionCodec::<ServerBoundStatusIO, ClientBoundStatusIO>::default(),
+    );
+
+    client.feed(ServerBoundStatusPacket::Request).await?;
+    client.codec_mut().enable_encryption(
+        Encryptor::new_from_slices(&secret, &secret)?,
+        Decryptor::new_from_slices(&secret, &secret)?,
+    );
+    client.send(ServerBoundStatusPacket::Ping(7)).await?;
+
+    let request = server.next().await.expect("Stream closed")?;
+    assert_eq!(request, ServerBoundStatusPacket::Request);
+    server.codec_mut().enable_encryption(
+        Encryptor::new_from_slices(&secret, &secret)?,
+        Decryptor::new_from_slices(&secret, &secret)?,
+    );
+    let ping = server.next().await.expect("Stream closed")?;
+    assert_eq!(ping, ServerBoundStatusPacket::Ping(7));
+    Ok(())
+}
+
+/// The cipher runs over the whole connection. It must not restart for every packet
+#[tokio::test]
+pub async fn encryption_carries_over_between_packets() -> anyhow::Result<()> {
+    let secret = [9u8; 16];
+    // Two packets have to be one continuous CFB8 stream
+    let mut plain = NonEncryptedPacketWriter::<ServerBoundStatusIO>::default();
+    let mut writer = EncryptedPacketWriter::<ServerBoundStatusIO>::new(Encryptor::new_from_slices(
+        &secret, &secret,
+    )?);
+    for ping in [1, 2] {
+        plain.write_packet(ServerBoundStatusPacket::Ping(ping))?;
+        writer.write_packet(ServerBoundStatusPacket::Ping(ping))?;
+    }
+    let mut expected = plain.pending_buffer;
+    Encryptor::new_from_slices(&secret, &secret)?.encrypt(&mut expected);
+    assert_eq!(writer.pending_buffer, expected);
+
+    let (client, server) = tokio::io::duplex(1024);
+    let mut client = Framed::new(
+        client,
+        OptionalEncryptionCodec::<ClientBoundStatusIO, ServerBoundStatusIO>::default(),
+    );
+    let mut server = Framed::new(
+        server,
+        OptionalEncryptionCodec::<ServerBoundStatusIO, ClientBoundStatusIO>::default(),
+    );
+    client.codec_mut().enable_encryption(
+        Encryptor::new_from_slices(&secret, &secret)?,
+        Decryptor::new_from_slices(&secret, &secret)?,
+    );
+    server.codec_mut().enable_encryption(
+        Encryptor::new_from_slices(&secret, &secret)?,
+        Decryptor::new_from_slices(&secret, &secret)?,
+    );
+
+    client.send(ServerBoundStatusPacket::Ping(1)).await?;
+    client.send(ServerBoundStatusPacket::Ping(2)).await?;
+    for expected in [1, 2] {
+        let ping = server.next().await.expect("Stream closed")?;
+        assert_eq!(ping, ServerBoundStatusPacket::Ping(expected));
+    }
+    server.send(ClientBoundStatusPacket::Ping(3)).await?;
+    server.send(ClientBoundStatusPacket::Ping(4)).await?;
+    for expected in [3, 4] {
+        let pong = client.next().await.expect("Stream closed")?;
+        assert_eq!(pong, ClientBoundStatusPacket::Ping(expected));
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use minecraft_protocol::data::PacketDataType;
+use minecraft_protocol::java::v_761::play::client::commands::ClientBoundDeclareCommandsImpl;
+use minecraft_protocol::java::v_761::play::server::{
+    CommandSuggestionsRequestImpl, MAX_COMMAND_LENGTH,
+};
+use minecraft_protocol::packets::play::client::commands::{
+    ArgumentParser, CommandNodeKind, CommandTreeNode, DeclareCommands,
+};
+use minecraft_protocol::packets::play::server::CommandSuggestionsRequest;
+use minecraft_protocol::{Packet, PacketReadError};
+
+#[test]
+pub fn declare_commands() {
+    let commands = DeclareCommands::from_tree(vec![
+        CommandTreeNode::literal("tp").then(
+            CommandTreeNode::argument(
+                "target",
+                ArgumentParser::Entity {
+                    single: true,
+                    players_only: false,
+                },
+            )
+            .executes(),
+        ),
+        CommandTreeNode::literal("run").redirect(Vec::<String>::new()),
+    ])
+    .unwrap();
+    assert_eq!(commands.nodes[0].kind, CommandNodeKind::Root);
+    assert_eq!(commands.nodes[0].children, vec![1, 3]);
+    assert_eq!(commands.nodes[3].redirect, Some(0));
+
+    let mut bytes = Vec::new();
+    ClientBoundDeclareCommandsImpl::write(commands.clone(), &mut bytes).unwrap();
+    let mut expected = vec![0x0E, 4, 0, 2, 1, 3, 1, 1, 2, 2];
+    expected.extend_from_slice(b"tp");
+    expected.extend_from_slice(&[6, 0, 6]);
+    expected.extend_from_slice(b"target");
+    expected.extend_from_slice(&[6, 1, 9, 0, 0, 3]);
+    expected.extend_from_slice(b"run");
+    expected.push(0);
+    assert_eq!(bytes, expected);
+    assert_eq!(
+        ClientBoundDeclareCommandsImpl::read(&mut &bytes[1..]).unwrap(),
+        commands
+    );
+}
+
+#[test]
+pub fn unknown_redirect() {
+    let error = DeclareCommands::from_tree(vec![
+        CommandTreeNode::literal("tp"),
+        CommandTreeNode::literal("teleport").redirect(["tp", "target"]),
+    ])
+    .unwrap_err();
+    assert_eq!(error.0, "tp target");
+}
+
+#[test]
+pub fn parser_properties() {
+    let mut bytes = Vec::new();
+    ArgumentParser::Integer {
+        min: None,
+        max: Some(64),
+    }
+    .write(&mut bytes)
+    .unwrap();
+    assert_eq!(bytes, vec![3, 0x02, 0, 0, 0, 64]);
+
+    assert_eq!(
+        ArgumentParser::read(&mut [8u8].as_slice()).unwrap(),
+        ArgumentParser::Simple(ArgumentParser::BLOCK_POS)
+    );
+    assert!(ArgumentParser::read(&mut [99u8].as_slice()).is_err());
+}
+
+/// The limit is in characters. Not in bytes
+#[test]
+pub fn suggestions_request_length() {
+    let read = |text: String| {
+        let mut buffer = Vec::new();
+        CommandSuggestionsRequestImpl::write(
+            CommandSuggestionsRequest {
+                transaction_id: 1,
+                text,
+            },
+            &mut buffer,
+        )
+        .unwrap();
+        CommandSuggestionsRequestImpl::read(&mut &buffer[1..])
+    };
+    let longest = "語".repeat(MAX_COMMAND_LENGTH);
+    assert_eq!(read(longest.clone()).unwrap().text, longest);
+    assert!(matches!(
+        read("語".repeat(MAX_COMMAND_LENGTH + 1)),
+        Err(PacketReadError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,54 @@
+use minecraft_protocol::data::slot::Slot;
+use minecraft_protocol::java::v_761::play::client::container::{
+    ClientBoundSelectedSlotImpl, ClientBoundSetContainerSlotImpl,
+};
+use minecraft_protocol::java::v_761::play::server::container::ClickContainerImpl;
+use minecraft_protocol::packets::play::client::container::SetContainerSlot;
+use minecraft_protocol::packets::play::client::SelectedSlotPacket;
+use minecraft_protocol::packets::play::server::container::{
+    ChangedSlot, ClickContainer, ClickMode,
+};
+use minecraft_protocol::Packet;
+
+#[test]
+pub fn cursor() {
+    let packet = SetContainerSlot::cursor(2, Slot::new(1, 3));
+    assert!(packet.is_cursor());
+    let mut bytes = Vec::new();
+    ClientBoundSetContainerSlotImpl::write(packet.clone(), &mut bytes).unwrap();
+    assert_eq!(bytes, vec![0x12, 0xFF, 2, 0xFF, 0xFF, 1, 1, 3, 0]);
+    let read = ClientBoundSetContainerSlotImpl::read(&mut &bytes[1..]).unwrap();
+    assert!(read.is_cursor());
+    assert_eq!(read, packet);
+}
+
+#[test]
+pub fn click_container() {
+    let packet = ClickContainer {
+        window_id: 1,
+        state_id: 5,
+        slot: 0,
+        button: 0,
+        mode: ClickMode::QuickMove,
+        changed_slots: vec![ChangedSlot {
+            slot: 36,
+            item: Slot::new(1, 1),
+        }],
+        carried_item: Slot::EMPTY,
+    };
+    let mut bytes = Vec::new();
+    ClickContainerImpl::write(packet.clone(), &mut bytes).unwrap();
+    assert_eq!(bytes, vec![0x0A, 1, 5, 0, 0, 0, 1, 1, 0, 36, 1, 1, 1, 0, 0]);
+    assert_eq!(ClickContainerImpl::read(&mut &bytes[1..]).unwrap(), packet);
+
+    // More changed slots than the client ever sends
+    assert!(ClickContainerImpl::read(&mut [1, 5, 0, 0, 0, 1, 0xFF, 0x01].as_slice()).is_err());
+}
+
+#[test]
+pub fn selected_slot() {
+    let mut bytes = Vec::new();
+    ClientBoundSelectedSlotImpl::write(SelectedSlotPacket(4), &mut bytes).unwrap();
+    assert_eq!(bytes, vec![0x49, 4]);
+    assert!(ClientBoundSelectedSlotImpl::write(SelectedSlotPacket(300), &mut Vec::new()).is_err());
+}
@@ -0,0 +1,256 @@
+//! Compares `#[derive(Packet)]` with the hand-written packets and the packets derived with `content = ..`
+use nbt::Blob;
+
+use minecraft_protocol::chat::{Component, NamedColor};
+use minecraft_protocol::data::var_int::{self, VarInt};
+use minecraft_protocol::data::{NBTOrByteArray, PacketDataType};
+use minecraft_protocol::java::v_761::play::client::login::ClientBoundLoginPacketImpl;
+use minecraft_protocol::java::v_761::play::client::{
+    ClientBoundDisconnectPacketImpl, ClientBoundKeepAliveImpl, ClientBoundPluginMessageImpl,
+    ClientBoundServerDataImpl,
+};
+use minecraft_protocol::limits::{self, DecodeLimits, LimitExceeded};
+use minecraft_protocol::packets::play::client::login::GameMode;
+use minecraft_protocol::packets::play::client::{DisconnectPacket, LoginPacket, ServerData};
+use minecraft_protocol::packets::play::{KeepAlive, PlayPluginMessage};
+use minecraft_protocol::{
+    Bound, Packet, PacketContent, PacketReadError, PacketWriteError, Protocol, Stage,
+};
+use minecraft_protocol_macros::{Packet, PacketContentType};
+
+#[derive(Debug, Clone, PartialEq, Packet, PacketContentType)]
+#[packet(id = 0x1F, bound = ClientBound, stage = Play, protocol = 761)]
+struct DerivedKeepAlive(i64);
+
+#[derive(Debug, Clone, PartialEq, Packet, PacketContentType)]
+#[packet(id = 0x17, bound = ClientBound, stage = Play, protocol = 761)]
+struct DerivedDisconnect(Component);
+
+#[derive(Debug, Clone, PartialEq, Packet, PacketContentType)]
+#[packet(id = 0x15, bound = ClientBound, stage = Play, protocol = 761)]
+struct DerivedPluginMessage {
+    channel: String,
+    #[rest]
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Packet, PacketContentType)]
+#[packet(id = 0x41, bound = ClientBound, stage = Play, protocol = 761)]
+struct DerivedServerData {
+    #[optional]
+    motd: Option<Component>,
+    #[optional]
+    icon: Option<String>,
+    enforced_secure_chat: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Packet, PacketContentType)]
+#[packet(id = 0x24, bound = ClientBound, stage = Play, protocol = 761)]
+struct DerivedLogin {
+    id: i32,
+    is_hardcore: bool,
+    game_mode: GameMode,
+    previous_game_mode: i8,
+    #[prefixed_array]
+    dimension_names: Vec<String>,
+    #[nbt]
+    registry_codec: Blob,
+    dimension_type: String,
+    dimension_name: String,
+    hashed_seed: i64,
+    #[varint]
+    max_players: i32,
+    #[varint]
+    view_distance: i32,
+    #[varint]
+    simulation_distance: i32,
+    reduced_debug_info: bool,
+    enable_respawn_screen: bool,
+    is_debug: bool,
+    is_flat: bool,
+    #[optional]
+    death_location: Option<String>,
+}
+
+/// Uses every encoding that no hand-written packet uses
+#[derive(Debug, Clone, PartialEq, Packet, PacketContentType)]
+#[packet(id = 0x100, bound = ServerBound, stage = Login, protocol = 762)]
+struct Encodings {
+    #[optional]
+    #[prefixed_array]
+    #[varint]
+    ids: Option<Vec<i32>>,
+    #[skip]
+    cached: Option<String>,
+    #[prefixed_array]
+    #[nbt]
+    blobs: Vec<Blob>,
+}
+
+fn bytes<P: Packet>(content: P::Content) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    P::write(content, &mut buffer).unwrap();
+    buffer
+}
+
+/// Writes both packets, compares the bytes and reads the derived packet back
+fn assert_identical<Derived, HandWritten>(derived: Derived, hand_written: HandWritten::Content)
+where
+    Derived: Packet<Content = Derived> + Clone + PartialEq,
+    HandWritten: Packet,
+{
+    let derived_bytes = bytes::<Derived>(derived.clone());
+    assert_eq!(derived_bytes, bytes::<HandWritten>(hand_written));
+    assert_eq!(Derived::packet_id(), HandWritten::packet_id());
+    let mut reader = &derived_bytes[var_int::inline::get_size(Derived::packet_id()) as usize..];
+    assert_eq!(Derived::read(&mut reader).unwrap(), derived);
+    assert!(reader.is_empty());
+}
+
+#[test]
+pub fn keep_alive() {
+    assert_identical::<_, ClientBoundKeepAliveImpl>(DerivedKeepAlive(-7), KeepAlive(-7));
+}
+
+#[test]
+pub fn disconnect() {
+    let reason = Component::text("Bye").color(NamedColor::Red);
+    assert_identical::<_, ClientBoundDisconnectPacketImpl>(
+        DerivedDisconnect(reason.clone()),
+        DisconnectPacket(reason),
+    );
+}
+
+#[test]
+pub fn plugin_message() {
+    assert_identical::<_, ClientBoundPluginMessageImpl>(
+        DerivedPluginMessage {
+            channel: "minecraft:brand".to_string(),
+            data: b"\x07vanilla".to_vec(),
+        },
+        PlayPluginMessage {
+            id: "minecraft:brand".into(),
+            data: b"\x07vanilla".to_vec(),
+        },
+    );
+}
+
+#[test]
+pub fn server_data() {
+    assert_identical::<_, ClientBoundServerDataImpl>(
+        DerivedServerData {
+            motd: Some(Component::text("Hello")),
+            icon: None,
+            enforced_secure_chat: true,
+        },
+        ServerData {
+            motd: Some(Component::text("Hello")),
+            icon: None,
+            previews_chat: false,
+            enforced_secure_chat: true,
+        },
+    );
+}
+
+#[test]
+pub fn login() {
+    let mut registry_codec = Blob::new();
+    registry_codec
+        .insert("minecraft:dimension_type", "overworld")
+        .unwrap();
+    let hashed_seed = LoginPacket::hash_seed(42);
+    let derived = DerivedLogin {
+        id: 1,
+        is_hardcore: false,
+        game_mode: GameMode::Creative,
+        previous_game_mode: -1,
+        dimension_names: vec!["minecraft:overworld".to_string()],
+        registry_codec: registry_codec.clone(),
+        dimension_type: "minecraft:overworld".to_string(),
+        dimension_name: "minecraft:overworld".to_string(),
+        hashed_seed: i64::from_be_bytes(hashed_seed),
+        max_players: 20,
+        view_distance: 10,
+        simulation_distance: 8,
+        reduced_debug_info: false,
+        enable_respawn_screen: true,
+        is_debug: false,
+        is_flat: true,
+        death_location: None,
+    };
+    let hand_written = LoginPacket {
+        id: 1,
+        is_hardcore: false,
+        game_mode: GameMode::Creative,
+        previous_game_mode: -1,
+        dimension_names: vec!["minecraft:overworld".to_string()],
+        registry_codec: NBTOrByteArray::NBT(registry_codec),
+        dimension_type: "minecraft:overworld".to_string(),
+        dimension_name: "minecraft:overworld".to_string(),
+        hashed_seed,
+        max_players: VarInt(20),
+        view_distance: VarInt(10),
+        simulation_distance: VarInt(8),
+        reduced_debug_info: false,
+        enable_respawn_screen: true,
+        is_debug: false,
+        is_flat: true,
+        death_location: None,
+    };
+    assert_identical::<_, ClientBoundLoginPacketImpl>(derived, hand_written);
+}
+
+#[test]
+pub fn encodings() {
+    let mut blob = Blob::new();
+    blob.insert("name", "value").unwrap();
+    let packet = Encodings {
+        ids: Some(vec![1, 300, -1]),
+        cached: Some("not sent".to_string()),
+        blobs: vec![blob.clone()],
+    };
+    let written = bytes::<Encodings>(packet);
+
+    let mut expected = vec![0x80, 0x02, 1, 3];
+    for id in [1, 300, -1] {
+        VarInt(id).write(&mut expected).unwrap();
+    }
+    expected.push(1);
+    blob.clone().write(&mut expected).unwrap();
+    assert_eq!(written, expected);
+
+    let read = Encodings::read(&mut &written[2..]).unwrap();
+    assert_eq!(
+        read,
+        Encodings {
+            ids: Some(vec![1, 300, -1]),
+            cached: None,
+            blobs: vec![blob],
+        }
+    );
+    assert_eq!(Encodings::bound(), Bound::ServerBound);
+    assert_eq!(Encodings::stage(), Stage::Login);
+    assert_eq!(Encodings::protocol(), Protocol::Java(762));
+}
+
+#[test]
+pub fn prefixed_array_limit() {
+    let limits = DecodeLimits {
+        max_array_length: 2,
+        ..Default::default()
+    };
+    // No ids and 3 blobs
+    let error = limits
+        .scope(|| Encodings::read(&mut &[0, 3][..]))
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        PacketReadError::LimitExceeded(LimitExceeded::ArrayLength { length: 3, max: 2 })
+    ));
+    // A negative length
+    let error = Encodings::read(&mut &[0, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F][..]).unwrap_err();
+    assert!(matches!(
+        error,
+        PacketReadError::LimitExceeded(LimitExceeded::ArrayLength { length: -1, .. })
+    ));
+}
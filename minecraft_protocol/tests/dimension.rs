@@ -0,0 +1,143 @@
+use minecraft_protocol::data::PackedPosition;
+use minecraft_protocol::java::v_761::play::ClientIO;
+use minecraft_protocol::java::v_761::processor::dimension::{
+    chunks_in_view, chunks_to_unload, DimensionChange,
+};
+use minecraft_protocol::java::v_762;
+use minecraft_protocol::packets::play::client::chunk::{SetCenterChunk, UnloadChunk};
+use minecraft_protocol::packets::play::client::login::GameMode;
+use minecraft_protocol::packets::play::client::player_info::{
+    SyncPlayerPosition, SyncPlayerPositionFlags,
+};
+use minecraft_protocol::packets::play::client::respawn::{
+    Respawn, RespawnDataKept, SetDefaultSpawnPosition,
+};
+use minecraft_protocol::packets::play::client::{AbilitiesPacket, AbilityFlags, ClientBoundPlay};
+use minecraft_protocol::simple_handlers::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
+use minecraft_protocol::{PacketReader, PacketWriter};
+
+fn respawn() -> Respawn {
+    Respawn::change_dimension(
+        "minecraft:the_nether",
+        "minecraft:the_nether",
+        [0; 8],
+        GameMode::Survival,
+    )
+}
+
+#[test]
+pub fn dimension_change() {
+    let change = DimensionChange {
+        respawn: respawn(),
+        abilities: AbilitiesPacket {
+            flags: AbilityFlags::empty(),
+            flying_speed: 0.05,
+            walking_speed: 0.1,
+        },
+        position: SyncPlayerPosition {
+            x: -0.5,
+            y: 64.0,
+            z: 31.0,
+            yaw: 0.0,
+            pitch: 0.0,
+            flags: SyncPlayerPositionFlags::empty(),
+            teleport_id: 1.into(),
+            dismount_vehicle: false,
+        },
+        spawn_position: SetDefaultSpawnPosition {
+            position: PackedPosition::default(),
+            angle: 0.0,
+        },
+        view_distance: 2,
+    };
+    assert_eq!(
+        change.center_chunk(),
+        SetCenterChunk {
+            chunk_x: -1,
+            chunk_z: 1
+        }
+    );
+
+    let mut writer = NonEncryptedPacketWriter::<ClientIO>::default();
+    let mut out = Vec::new();
+    let mut requested = Vec::new();
+    change
+        .send(&mut writer, &mut out, |x, z| {
+            requested.push((x, z));
+            None
+        })
+        .unwrap();
+    assert_eq!(requested.len(), 25);
+    assert_eq!(requested[0], (-1, 1));
+
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&out);
+    assert_eq!(
+        reader.attempt_packet_read().unwrap(),
+        Some(ClientBoundPlay::Respawn(respawn()))
+    );
+    assert!(matches!(
+        reader.attempt_packet_read().unwrap(),
+        Some(ClientBoundPlay::Abilities(_))
+    ));
+}
+
+#[test]
+pub fn data_kept_flags() {
+    // 1.19.4 keeps the flags. Older versions only all or nothing
+    let mut respawn = respawn();
+    respawn.data_kept = RespawnDataKept::KEEP_METADATA;
+    let mut writer = NonEncryptedPacketWriter::<v_762::play::ClientIO>::default();
+    let mut out = Vec::new();
+    writer.send_packet(respawn.clone(), &mut out).unwrap();
+    let mut reader = NonEncryptedPacketReader::<v_762::play::ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&out);
+    assert_eq!(
+        reader.attempt_packet_read().unwrap(),
+        Some(ClientBoundPlay::Respawn(respawn.clone()))
+    );
+
+    let mut writer = NonEncryptedPacketWriter::<ClientIO>::default();
+    let mut out = Vec::new();
+    writer.send_packet(respawn, &mut out).unwrap();
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&out);
+    match reader.attempt_packet_read().unwrap() {
+        Some(ClientBoundPlay::Respawn(respawn)) => {
+            assert_eq!(respawn.data_kept, RespawnDataKept::empty())
+        }
+        packet => panic!("Expected a respawn. Got {:?}", packet),
+    }
+}
+
+#[test]
+pub fn unload_on_move() {
+    let old = SetCenterChunk {
+        chunk_x: 0,
+        chunk_z: 0,
+    };
+    assert_eq!(chunks_in_view(old, 0), vec![(0, 0)]);
+    let new = SetCenterChunk {
+        chunk_x: 1,
+        chunk_z: 0,
+    };
+    let mut unloaded = chunks_to_unload(old, new, 1);
+    unloaded.sort_by_key(|chunk| chunk.chunk_z);
+    assert_eq!(
+        unloaded,
+        vec![
+            UnloadChunk {
+                chunk_x: -1,
+                chunk_z: -1
+            },
+            UnloadChunk {
+                chunk_x: -1,
+                chunk_z: 0
+            },
+            UnloadChunk {
+                chunk_x: -1,
+                chunk_z: 1
+            },
+        ]
+    );
+}
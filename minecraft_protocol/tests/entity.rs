@@ -0,0 +1,123 @@
+use minecraft_protocol::data::slot::Slot;
+use minecraft_protocol::data::PacketDataType;
+use minecraft_protocol::java::v_761::play::client::entity::{
+    ClientBoundSetEntityMetadataImpl, ClientBoundSetEquipmentImpl,
+};
+use minecraft_protocol::packets::play::client::entity::{
+    Angle, EntityLocation, EntityMovement, EquipmentSlot, SetEntityMetadata, SetEquipment, Velocity,
+};
+use minecraft_protocol::packets::play::client::metadata::{
+    EntityMetadataValue, MetadataEntry, Pose,
+};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::Packet;
+
+fn location(x: f64, y: f64, z: f64, yaw: f32) -> EntityLocation {
+    EntityLocation {
+        x,
+        y,
+        z,
+        yaw,
+        pitch: 0.0,
+    }
+}
+
+#[test]
+pub fn movement() {
+    let mut movement = EntityMovement::new(7, location(0.0, 64.0, 0.0, 0.0));
+    assert_eq!(movement.move_to(location(0.0, 64.0, 0.0, 0.0), true), None);
+
+    let Some(ClientBoundPlay::UpdateEntityPosition(update)) =
+        movement.move_to(location(1.5, 64.0, -0.25, 0.0), true)
+    else {
+        panic!("Expected a position update");
+    };
+    assert_eq!(
+        (
+            update.entity_id,
+            update.delta_x,
+            update.delta_y,
+            update.delta_z
+        ),
+        (7, 6144, 0, -1024)
+    );
+
+    let Some(ClientBoundPlay::UpdateEntityRotation(rotation)) =
+        movement.move_to(location(1.5, 64.0, -0.25, 90.0), false)
+    else {
+        panic!("Expected a rotation update");
+    };
+    assert_eq!(rotation.yaw, Angle(64));
+
+    let Some(ClientBoundPlay::UpdateEntityPositionAndRotation(update)) =
+        movement.move_to(location(1.5, 65.0, -0.25, -90.0), false)
+    else {
+        panic!("Expected a position and rotation update");
+    };
+    assert_eq!((update.delta_y, update.yaw), (4096, Angle(192)));
+
+    // 8 blocks does not fit in a short
+    let Some(ClientBoundPlay::TeleportEntity(teleport)) =
+        movement.move_to(location(9.5, 65.0, -0.25, -90.0), false)
+    else {
+        panic!("Expected a teleport");
+    };
+    assert_eq!((teleport.x, teleport.y, teleport.z), (9.5, 65.0, -0.25));
+    assert!(matches!(
+        movement.move_to(location(9.0, 65.0, -0.25, -90.0), false),
+        Some(ClientBoundPlay::UpdateEntityPosition(_))
+    ));
+}
+
+#[test]
+pub fn velocity() {
+    assert_eq!(
+        Velocity::from_blocks_per_tick(0.5, -10.0, 0.0),
+        Velocity {
+            x: 4000,
+            y: -31200,
+            z: 0
+        }
+    );
+}
+
+#[test]
+pub fn metadata() {
+    let packet = SetEntityMetadata {
+        entity_id: 1,
+        metadata: vec![
+            MetadataEntry::new(0, 0x02i8),
+            MetadataEntry::new(6, Pose::Crouching),
+            MetadataEntry::new(8, EntityMetadataValue::OptionalVarInt(Some(0))),
+        ],
+    };
+    let mut bytes = Vec::new();
+    ClientBoundSetEntityMetadataImpl::write(packet.clone(), &mut bytes).unwrap();
+    assert_eq!(bytes, vec![0x4E, 1, 0, 0, 0x02, 6, 19, 5, 8, 18, 1, 0xFF]);
+    let read = ClientBoundSetEntityMetadataImpl::read(&mut &bytes[1..]).unwrap();
+    assert_eq!(read, packet);
+
+    assert!(ClientBoundSetEntityMetadataImpl::read(&mut [1, 0, 99, 0].as_slice()).is_err());
+}
+
+#[test]
+pub fn equipment() {
+    let packet = SetEquipment {
+        entity_id: 3,
+        equipment: vec![
+            (EquipmentSlot::MainHand, Slot::new(1, 64)),
+            (EquipmentSlot::Helmet, Slot::EMPTY),
+        ],
+    };
+    let mut bytes = Vec::new();
+    ClientBoundSetEquipmentImpl::write(packet.clone(), &mut bytes).unwrap();
+    assert_eq!(bytes, vec![0x51, 3, 0x80, 1, 1, 64, 0, 5, 0]);
+    assert_eq!(
+        ClientBoundSetEquipmentImpl::read(&mut &bytes[1..]).unwrap(),
+        packet
+    );
+
+    let mut slot = Vec::new();
+    Slot::new(1, 1).write(&mut slot).unwrap();
+    assert_eq!(Slot::read(&mut slot.as_slice()).unwrap(), Slot::new(1, 1));
+}
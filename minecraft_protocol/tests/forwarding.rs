@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use minecraft_protocol::java::handshake::HandShakeIO;
+use minecraft_protocol::java::v_761::login;
+use minecraft_protocol::java::v_761::processor::server::{
+    handle, ForwardingMode, LoginError, LoginState, OfflineAuthenticator, ServerClient,
+};
+use minecraft_protocol::packets::login::server_bound::ServerBoundLoginStart;
+use minecraft_protocol::packets::login::{ClientBoundLogin, Property, ServerBoundLogin};
+use minecraft_protocol::simple_handlers::NonEncryptedPacketReader;
+use minecraft_protocol::{PacketIO, PacketReader};
+
+/// Login Plugin Response as Velocity sends it. Message id 7, signed with the secret `forwarding-secret`
+const VELOCITY_RESPONSE: &str = "590207012d0aecc340bdcfce39c2a33e4045a9cf13b5b7d86b0ea244b8da676a26dc003d01093132372e302e302e31069a79f444e94726a5befca90e38aaf5054e6f74636801087465787475726573046533303d010463326c6e";
+/// Handshake as BungeeCord sends it with ip_forward enabled
+const BUNGEE_HANDSHAKE: &str = "7a00f90573706c61792e6578616d706c652e636f6d003132372e302e302e31003036396137396634343465393437323661356265666361393065333861616635005b7b226e616d65223a227465787475726573222c2276616c7565223a226533303d222c227369676e6174757265223a2263326c6e227d5d63dd02";
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn read_packet<IO: PacketIO + std::fmt::Debug>(hex: &str) -> IO::Type {
+    let mut reader = NonEncryptedPacketReader::<IO>::default();
+    reader.get_read_buffer().extend_from_slice(&decode_hex(hex));
+    reader
+        .attempt_packet_read()
+        .unwrap()
+        .expect("Packet is incomplete")
+}
+
+fn server_client(forwarding: ForwardingMode) -> ServerClient {
+    let key = rsa::RsaPrivateKey::new(&mut rand::thread_rng(), 512).unwrap();
+    ServerClient {
+        server_id: String::new(),
+        authenticator: Arc::new(OfflineAuthenticator),
+        key,
+        key_encoded: vec![],
+        forwarding,
+    }
+}
+
+fn login_start() -> ServerBoundLogin {
+    ServerBoundLogin::LoginStart(ServerBoundLoginStart {
+        name: "Notch".to_string(),
+        uuid: None,
+    })
+}
+
+fn expected_properties() -> Vec<Property> {
+    vec![Property {
+        name: "textures".to_string(),
+        value: "e30=".to_string(),
+        signature: Some("c2ln".to_string()),
+    }]
+}
+
+#[tokio::test]
+pub async fn velocity() -> anyhow::Result<()> {
+    let client = server_client(ForwardingMode::Velocity {
+        secret: b"forwarding-secret".to_vec(),
+    });
+    let mut state = LoginState::default();
+    let request = handle(&client, login_start(), &mut state).await?;
+    let Some(ClientBoundLogin::LoginPluginRequest(request)) = request else {
+        panic!("Expected a login plugin request");
+    };
+    assert_eq!(request.channel, "velocity:player_info");
+    assert_eq!(request.data, vec![1]);
+    assert!(matches!(state, LoginState::VelocityRequested { .. }));
+
+    // The recorded response answers message 7
+    let mut state = LoginState::VelocityRequested { message_id: 7 };
+    let response = read_packet::<login::ServerIO>(VELOCITY_RESPONSE);
+    assert!(handle(&client, response, &mut state).await?.is_none());
+    let LoginState::Completed { cipher, data } = state else {
+        panic!("Login was not completed");
+    };
+    assert!(cipher.is_none());
+    assert_eq!(data.name, "Notch");
+    assert_eq!(
+        Uuid::parse_str(&data.id)?,
+        Uuid::parse_str("069a79f4-44e9-4726-a5be-fca90e38aaf5")?
+    );
+    assert_eq!(data.properties, expected_properties());
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn velocity_wrong_secret() -> anyhow::Result<()> {
+    let client = server_client(ForwardingMode::Velocity {
+        secret: b"not-the-secret".to_vec(),
+    });
+    let mut state = LoginState::VelocityRequested { message_id: 7 };
+    let response = read_packet::<login::ServerIO>(VELOCITY_RESPONSE);
+    let result = handle(&client, response, &mut state).await;
+    assert!(matches!(result, Err(LoginError::Forwarding(_))));
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn bungee_cord() -> anyhow::Result<()> {
+    let client = server_client(ForwardingMode::BungeeCord);
+    let handshake = read_packet::<HandShakeIO>(BUNGEE_HANDSHAKE);
+    let mut state = LoginState::from_handshake(&client, &handshake)?;
+    let LoginState::BungeeCordForwarded(forwarding) = &state else {
+        panic!("Expected BungeeCord player info");
+    };
+    assert_eq!(forwarding.host, "play.example.com");
+    assert_eq!(forwarding.address, "127.0.0.1");
+
+    assert!(handle(&client, login_start(), &mut state).await?.is_none());
+    let LoginState::Completed { cipher, data } = state else {
+        panic!("Login was not completed");
+    };
+    assert!(cipher.is_none());
+    assert_eq!(data.name, "Notch");
+    assert_eq!(data.id, "069a79f444e94726a5befca90e38aaf5");
+    assert_eq!(data.properties, expected_properties());
+    Ok(())
+}
+
+#[test]
+pub fn bungee_cord_without_forwarding() {
+    let client = server_client(ForwardingMode::BungeeCord);
+    let mut handshake = read_packet::<HandShakeIO>(BUNGEE_HANDSHAKE);
+    handshake.server_address = "play.example.com".to_string();
+    assert!(matches!(
+        LoginState::from_handshake(&client, &handshake),
+        Err(LoginError::Forwarding(_))
+    ));
+}
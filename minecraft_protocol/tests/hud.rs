@@ -0,0 +1,36 @@
+use minecraft_protocol::java::v_761::play::ClientIO;
+use minecraft_protocol::packets::play::client::hud::{BossBar, BossBarAction, BossBarFlags};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::simple_handlers::NonEncryptedPacketReader;
+use minecraft_protocol::PacketReader;
+use uuid::Uuid;
+
+fn read(data: &[u8]) -> Option<ClientBoundPlay> {
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(data);
+    reader.attempt_packet_read().unwrap()
+}
+
+#[test]
+pub fn boss_bar() {
+    let uuid = Uuid::from_u128(1);
+    // Length 19, id 0x0A, uuid, action 5, darken sky and fog
+    let mut data = vec![19, 0x0A];
+    data.extend_from_slice(uuid.as_bytes());
+    data.extend_from_slice(&[5, 0b101]);
+    assert_eq!(
+        read(&data),
+        Some(ClientBoundPlay::BossBar(BossBar {
+            uuid,
+            action: BossBarAction::UpdateFlags(BossBarFlags::DARKEN_SKY | BossBarFlags::CREATE_FOG),
+        }))
+    );
+
+    // Unknown action 6
+    let mut data = vec![18, 0x0A];
+    data.extend_from_slice(uuid.as_bytes());
+    data.push(6);
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&data);
+    assert!(reader.attempt_packet_read().is_err());
+}
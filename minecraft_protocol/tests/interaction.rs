@@ -0,0 +1,72 @@
+use minecraft_protocol::data::PackedPosition;
+use minecraft_protocol::java::v_761::play::ServerIO;
+use minecraft_protocol::packets::play::client::metadata::Direction;
+use minecraft_protocol::packets::play::server::interaction::{
+    Hand, PlayerAction, PlayerActionStatus, UseItem,
+};
+use minecraft_protocol::packets::play::server::ServerBoundPlay;
+use minecraft_protocol::simple_handlers::NonEncryptedPacketReader;
+use minecraft_protocol::{PacketReadError, PacketReader};
+
+fn read(data: &[u8]) -> Result<Option<ServerBoundPlay>, PacketReadError> {
+    let mut reader = NonEncryptedPacketReader::<ServerIO>::default();
+    reader.get_read_buffer().extend_from_slice(data);
+    reader.attempt_packet_read()
+}
+
+#[test]
+pub fn player_action() {
+    // Length 12, id 0x1C, finished digging at 0 0 0 on the top face, sequence 7
+    let mut data = vec![12, 0x1C, 2];
+    data.extend_from_slice(&0u64.to_be_bytes());
+    data.extend_from_slice(&[1, 7]);
+    assert_eq!(
+        read(&data).unwrap(),
+        Some(ServerBoundPlay::PlayerAction(PlayerAction {
+            status: PlayerActionStatus::FinishedDigging,
+            position: PackedPosition(0),
+            face: Direction::Up,
+            sequence: 7,
+        }))
+    );
+
+    // Face 6 does not exist
+    data[11] = 6;
+    assert!(matches!(read(&data), Err(PacketReadError::InvalidData(_))));
+}
+
+#[test]
+pub fn use_item() {
+    // Length 3, id 0x32, off hand, sequence 1
+    assert_eq!(
+        read(&[3, 0x32, 1, 1]).unwrap(),
+        Some(ServerBoundPlay::UseItem(UseItem {
+            hand: Hand::OffHand,
+            sequence: 1,
+        }))
+    );
+    assert!(matches!(
+        read(&[3, 0x32, 2, 1]),
+        Err(PacketReadError::InvalidData(_))
+    ));
+    // Sequence -1
+    assert!(matches!(
+        read(&[7, 0x32, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F]),
+        Err(PacketReadError::InvalidData(_))
+    ));
+}
+
+#[test]
+pub fn invalid_actions() {
+    // Length 4, id 0x1D, entity 1, start horse jump
+    assert!(read(&[4, 0x1D, 1, 5, 100]).unwrap().is_some());
+    assert!(matches!(
+        read(&[5, 0x1D, 1, 5, 0xE5, 0x00]),
+        Err(PacketReadError::InvalidData(_))
+    ));
+    // Interact action 3 does not exist
+    assert!(matches!(
+        read(&[4, 0x0F, 1, 3, 0]),
+        Err(PacketReadError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,90 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use minecraft_protocol::java::v_761::play::ClientIO;
+use minecraft_protocol::java::v_761::processor::keep_alive::{
+    Clock, KeepAliveError, KeepAliveManager, KEEP_ALIVE_INTERVAL,
+};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::packets::play::KeepAlive;
+use minecraft_protocol::simple_handlers::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
+use minecraft_protocol::PacketReader;
+
+#[derive(Clone)]
+struct MockClock(Rc<Cell<Instant>>);
+
+impl MockClock {
+    fn advance(&self, duration: Duration) {
+        self.0.set(self.0.get() + duration);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.0.get()
+    }
+}
+
+fn sent_id(out: &[u8]) -> i64 {
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(out);
+    match reader.attempt_packet_read().unwrap() {
+        Some(ClientBoundPlay::KeepAlive(KeepAlive(id))) => id,
+        packet => panic!("Expected a keep alive. Got {:?}", packet),
+    }
+}
+
+#[test]
+pub fn keep_alive() {
+    let clock = MockClock(Rc::new(Cell::new(Instant::now())));
+    let mut manager = KeepAliveManager::new(clock.clone());
+    let mut writer = NonEncryptedPacketWriter::<ClientIO>::default();
+    let mut out = Vec::new();
+
+    manager.tick(&mut writer, &mut out).unwrap();
+    assert!(out.is_empty());
+
+    clock.advance(KEEP_ALIVE_INTERVAL);
+    manager.tick(&mut writer, &mut out).unwrap();
+    let id = sent_id(&out);
+    assert_eq!(manager.pending_id(), Some(id));
+
+    clock.advance(Duration::from_millis(100));
+    manager.handle_response(KeepAlive(id)).unwrap();
+    assert_eq!(manager.latency_millis(), 100);
+    assert!(matches!(
+        manager.handle_response(KeepAlive(id)),
+        Err(KeepAliveError::UnknownId(_))
+    ));
+
+    out.clear();
+    clock.advance(KEEP_ALIVE_INTERVAL);
+    manager.tick(&mut writer, &mut out).unwrap();
+    clock.advance(Duration::from_millis(500));
+    manager.handle_response(KeepAlive(sent_id(&out))).unwrap();
+    // (100 * 3 + 500) / 4
+    assert_eq!(manager.latency_millis(), 200);
+}
+
+#[test]
+pub fn timeout() {
+    let clock = MockClock(Rc::new(Cell::new(Instant::now())));
+    let mut manager = KeepAliveManager::new(clock.clone());
+    let mut writer = NonEncryptedPacketWriter::<ClientIO>::default();
+    let mut out = Vec::new();
+
+    clock.advance(KEEP_ALIVE_INTERVAL);
+    manager.tick(&mut writer, &mut out).unwrap();
+    clock.advance(Duration::from_secs(14));
+    manager.tick(&mut writer, &mut out).unwrap();
+
+    // 30 seconds since the connection started without an answer
+    clock.advance(Duration::from_secs(1));
+    let error = manager.tick(&mut writer, &mut out).unwrap_err();
+    assert!(matches!(error, KeepAliveError::Timeout));
+    assert_eq!(
+        error.disconnect_reason().to_plain_text(),
+        "disconnect.timeout"
+    );
+}
@@ -0,0 +1,117 @@
+use minecraft_protocol::java::v_761::login::ServerIO;
+use minecraft_protocol::java::v_761::play;
+use minecraft_protocol::limits::{DecodeLimits, LimitExceeded};
+use minecraft_protocol::packets::login::server_bound::ServerBoundLoginStart;
+use minecraft_protocol::packets::login::ServerBoundLogin;
+use minecraft_protocol::simple_handlers::NonEncryptedPacketReader;
+use minecraft_protocol::{CompressionSettings, PacketHandler, PacketReadError, PacketReader};
+
+fn reader(data: &[u8]) -> NonEncryptedPacketReader<ServerIO> {
+    let mut reader = NonEncryptedPacketReader::<ServerIO>::default();
+    reader.get_read_buffer().extend_from_slice(data);
+    reader
+}
+
+#[test]
+pub fn packet_length() {
+    // 2^21 + 1
+    let mut reader = reader(&[0x81, 0x80, 0x80, 0x01]);
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::LimitExceeded(
+            LimitExceeded::PacketLength { .. }
+        ))
+    ));
+    // Nothing was reserved for the claimed length
+    assert!(reader.get_read_buffer().capacity() < DecodeLimits::MAX_PACKET_LENGTH);
+
+    // A length that never ends
+    let mut reader = self::reader(&[0xFF; 4]);
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::InvalidData(_))
+    ));
+}
+
+#[test]
+pub fn decompressed_size() {
+    // Data Length of 2^23 + 1
+    let mut reader = reader(&[5, 0x81, 0x80, 0x80, 0x04, 0x00]);
+    reader.set_compression(CompressionSettings::Zlib {
+        threshold: 256,
+        compression_level: 6,
+    });
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::LimitExceeded(
+            LimitExceeded::DecompressedSize { .. }
+        ))
+    ));
+}
+
+#[test]
+pub fn configured_limits() {
+    let limits = DecodeLimits {
+        max_string_length: 4,
+        max_array_length: 2,
+        ..Default::default()
+    };
+    // Login start for Alex then for Steve
+    let mut reader = reader(&[7, 0x00, 4, b'A', b'l', b'e', b'x', 0]);
+    reader.set_decode_limits(limits);
+    assert_eq!(
+        reader.attempt_packet_read().unwrap(),
+        Some(ServerBoundLogin::LoginStart(ServerBoundLoginStart {
+            name: "Alex".to_string(),
+            uuid: None,
+        }))
+    );
+    reader
+        .get_read_buffer()
+        .extend_from_slice(&[8, 0x00, 5, b'S', b't', b'e', b'v', b'e', 0]);
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::LimitExceeded(
+            LimitExceeded::StringLength { .. }
+        ))
+    ));
+
+    // Encryption response with a 3 byte shared secret
+    let mut reader = self::reader(&[7, 0x01, 3, 1, 2, 3, 1, 4]);
+    reader.set_decode_limits(limits);
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::LimitExceeded(LimitExceeded::ArrayLength {
+            length: 3,
+            max: 2
+        }))
+    ));
+    // Hand written packets use the same limit. Remove entities with 3 ids
+    let mut reader = NonEncryptedPacketReader::<play::ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&[5, 0x3A, 3, 1, 2, 3]);
+    reader.set_decode_limits(limits);
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::LimitExceeded(LimitExceeded::ArrayLength {
+            length: 3,
+            max: 2
+        }))
+    ));
+    // The limits only apply inside the reader
+    assert_eq!(DecodeLimits::current(), DecodeLimits::default());
+}
+
+/// Found by the play_client_bound fuzz target
+#[test]
+pub fn unknown_position_flags() {
+    let mut data = vec![0, 0x38];
+    data.extend_from_slice(&[0; 8 * 3 + 4 * 2]);
+    data.extend_from_slice(&[0xFF, 1, 0]);
+    data[0] = (data.len() - 1) as u8;
+    let mut reader = NonEncryptedPacketReader::<play::ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&data);
+    assert!(matches!(
+        reader.attempt_packet_read(),
+        Err(PacketReadError::InvalidData(_))
+    ));
+}
@@ -0,0 +1,207 @@
+use std::sync::{Arc, Mutex};
+
+use cfb8::cipher::KeyIvInit;
+use futures_util::{SinkExt, StreamExt};
+use rsa::pkcs8::{DecodePublicKey, EncodePublicKey};
+use rsa::{PaddingScheme, PublicKey, RsaPrivateKey, RsaPublicKey};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio_util::codec::Framed;
+use uuid::Uuid;
+
+use minecraft_protocol::java::v_761::login;
+use minecraft_protocol::java::v_761::processor::server::{
+    handle, server_hash, Authenticator, ForwardingMode, HttpAuthenticator, LoginError, LoginState,
+    OfflineAuthenticator, ServerClient,
+};
+use minecraft_protocol::packets::login::client_bound::LoginSuccess;
+use minecraft_protocol::packets::login::server_bound::{
+    ServerBoundEncryptionResponse, ServerBoundLoginStart,
+};
+use minecraft_protocol::packets::login::{ClientBoundLogin, ServerBoundLogin};
+use minecraft_protocol::simple_handlers::codec::OptionalEncryptionCodec;
+use minecraft_protocol::{Decryptor, Encryptor};
+
+/// A session server that only implements `hasJoined`.
+///
+/// Every player has joined except `NotJoined`. Returns the url of the endpoint and the server hashes it received.
+async fn stub_session_server() -> anyhow::Result<(String, Arc<Mutex<Vec<String>>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!(
+        "http://{}/session/minecraft/hasJoined",
+        listener.local_addr()?
+    );
+    let hashes = Arc::new(Mutex::new(Vec::new()));
+    let received = hashes.clone();
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = socket.read(&mut buf).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buf[..read]);
+            }
+            let request = String::from_utf8(request).unwrap();
+            let path = request.split(' ').nth(1).unwrap();
+            let query = path.split_once('?').unwrap().1;
+            let param = |name: &str| {
+                query
+                    .split('&')
+                    .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                    .unwrap()
+                    .to_string()
+            };
+            let username = param("username");
+            received.lock().unwrap().push(param("serverId"));
+
+            let response = if username == "NotJoined" {
+                "HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n".to_string()
+            } else {
+                let body = format!(
+                    r#"{{"id":"{}","name":"{}","properties":[{{"name":"textures","value":"e30=","signature":"c2ln"}}]}}"#,
+                    Uuid::new_v4().simple(),
+                    username
+                );
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+            };
+            socket.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    Ok((url, hashes))
+}
+
+fn server_client(authenticator: Arc<dyn Authenticator>) -> anyhow::Result<ServerClient> {
+    let key = RsaPrivateKey::new(&mut rand::thread_rng(), 1024)?;
+    let key_encoded = key.to_public_key().to_public_key_der()?.as_bytes().to_vec();
+    Ok(ServerClient {
+        server_id: String::new(),
+        authenticator,
+        key,
+        key_encoded,
+        forwarding: ForwardingMode::None,
+    })
+}
+
+fn login_start(name: &str) -> ServerBoundLogin {
+    ServerBoundLogin::LoginStart(ServerBoundLoginStart {
+        name: name.to_string(),
+        uuid: None,
+    })
+}
+
+/// Runs the full encrypted login over TCP against the stub session server
+#[tokio::test]
+pub async fn encrypted_login() -> anyhow::Result<()> {
+    let (url, hashes) = stub_session_server().await?;
+    let server_client = server_client(Arc::new(HttpAuthenticator::new(url)))?;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let address = listener.local_addr()?;
+    let server = tokio::spawn(async move {
+        let (socket, _) = listener.accept().await?;
+        let mut framed = Framed::new(
+            socket,
+            OptionalEncryptionCodec::<login::ServerIO, login::ClientIO>::default(),
+        );
+        let mut state = LoginState::default();
+        while let Some(packet) = framed.next().await {
+            if let Some(response) = handle(&server_client, packet?, &mut state).await? {
+                framed.send(response).await?;
+            }
+            if let LoginState::Completed { cipher, data } = &state {
+                let (encryptor, decryptor) = cipher.clone().expect("Login was not encrypted");
+                framed.codec_mut().enable_encryption(encryptor, decryptor);
+                framed
+                    .send(ClientBoundLogin::LoginSuccess(LoginSuccess {
+                        uuid: Uuid::parse_str(&data.id)?,
+                        username: data.name.clone(),
+                        properties: data.properties.clone(),
+                    }))
+                    .await?;
+                break;
+            }
+        }
+        anyhow::Ok(())
+    });
+
+    let socket = tokio::net::TcpStream::connect(address).await?;
+    let mut client = Framed::new(
+        socket,
+        OptionalEncryptionCodec::<login::ClientIO, login::ServerIO>::default(),
+    );
+    client.send(login_start("Player")).await?;
+    let ClientBoundLogin::EncryptionRequest(request) =
+        client.next().await.expect("Stream closed")?
+    else {
+        panic!("Expected an encryption request");
+    };
+    let public_key = RsaPublicKey::from_public_key_der(&request.public_key)?;
+    let shared_secret: [u8; 16] = rand::random();
+    let mut rng = rand::thread_rng();
+    client
+        .send(ServerBoundLogin::EncryptionResponse(
+            ServerBoundEncryptionResponse {
+                shared_secret: public_key.encrypt(
+                    &mut rng,
+                    PaddingScheme::new_pkcs1v15_encrypt(),
+                    &shared_secret,
+                )?,
+                verify_token: public_key.encrypt(
+                    &mut rng,
+                    PaddingScheme::new_pkcs1v15_encrypt(),
+                    &request.verify_token,
+                )?,
+            },
+        ))
+        .await?;
+    client.codec_mut().enable_encryption(
+        Encryptor::new_from_slices(&shared_secret, &shared_secret)?,
+        Decryptor::new_from_slices(&shared_secret, &shared_secret)?,
+    );
+    let ClientBoundLogin::LoginSuccess(success) = client.next().await.expect("Stream closed")?
+    else {
+        panic!("Expected login success");
+    };
+    server.await??;
+
+    assert_eq!(success.username, "Player");
+    assert_eq!(success.properties.len(), 1);
+    let expected_hash = server_hash(&request.server_id, &shared_secret, &request.public_key);
+    assert_eq!(*hashes.lock().unwrap(), vec![expected_hash]);
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn not_joined() -> anyhow::Result<()> {
+    let (url, _) = stub_session_server().await?;
+    let result = HttpAuthenticator::new(url)
+        .authenticate("NotJoined", "hash")
+        .await;
+    assert!(matches!(result, Err(LoginError::NotAuthenticated(_))));
+    Ok(())
+}
+
+#[tokio::test]
+pub async fn offline_login() -> anyhow::Result<()> {
+    let server_client = server_client(Arc::new(OfflineAuthenticator))?;
+    let mut state = LoginState::default();
+    let response = handle(&server_client, login_start("Notch"), &mut state).await?;
+    assert!(response.is_none());
+    let LoginState::Completed { cipher, data } = state else {
+        panic!("Offline login should complete without encryption");
+    };
+    assert!(cipher.is_none());
+    assert_eq!(data.name, "Notch");
+    assert_eq!(
+        Uuid::parse_str(&data.id)?,
+        Uuid::parse_str("b50ad385-829d-3141-a216-7e7d7539ba7f")?
+    );
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use uuid::Uuid;
+
+use minecraft_protocol::chat::Component;
+use minecraft_protocol::java::v_761::play::client::player_info::{
+    ClientBoundPlayerInfoRemoveImpl, ClientBoundPlayerInfoUpdateImpl,
+};
+use minecraft_protocol::packets::play::client::login::GameMode;
+use minecraft_protocol::packets::play::client::player_info::{
+    PlayerInfoActions, PlayerInfoEntry, PlayerInfoRemove, PlayerInfoUpdate,
+};
+use minecraft_protocol::Packet;
+
+#[test]
+pub fn add_player() {
+    let uuid = Uuid::from_u128(1);
+    let mut entry = PlayerInfoEntry::new(uuid, "Notch");
+    entry.game_mode = GameMode::Creative;
+    entry.display_name = Some(Component::text("Notch"));
+    let packet = PlayerInfoUpdate::add_players(vec![entry]);
+    assert!(!packet.actions.contains(PlayerInfoActions::INITIALIZE_CHAT));
+
+    let mut bytes = Vec::new();
+    ClientBoundPlayerInfoUpdateImpl::write(packet.clone(), &mut bytes).unwrap();
+    assert_eq!(&bytes[..3], &[0x36, 0b0011_1101, 1]);
+    assert_eq!(
+        ClientBoundPlayerInfoUpdateImpl::read(&mut &bytes[1..]).unwrap(),
+        packet
+    );
+}
+
+#[test]
+pub fn update_latency() {
+    let mut entry = PlayerInfoEntry::new(Uuid::from_u128(2), "");
+    entry.latency = 300;
+    let packet = PlayerInfoUpdate {
+        actions: PlayerInfoActions::UPDATE_LATENCY,
+        players: vec![entry],
+    };
+    let mut bytes = Vec::new();
+    ClientBoundPlayerInfoUpdateImpl::write(packet.clone(), &mut bytes).unwrap();
+    let mut expected = vec![0x36, 0x10, 1];
+    expected.extend_from_slice(&2u128.to_be_bytes());
+    expected.extend_from_slice(&[0xAC, 0x02]);
+    assert_eq!(bytes, expected);
+    assert_eq!(
+        ClientBoundPlayerInfoUpdateImpl::read(&mut &bytes[1..]).unwrap(),
+        packet
+    );
+
+    // Only 6 actions exist
+    assert!(ClientBoundPlayerInfoUpdateImpl::read(&mut [0x40, 0].as_slice()).is_err());
+}
+
+#[test]
+pub fn remove() {
+    let packet = PlayerInfoRemove {
+        players: vec![Uuid::from_u128(1), Uuid::from_u128(2)],
+    };
+    let mut bytes = Vec::new();
+    ClientBoundPlayerInfoRemoveImpl::write(packet.clone(), &mut bytes).unwrap();
+    assert_eq!(bytes.len(), 2 + 32);
+    assert_eq!(
+        ClientBoundPlayerInfoRemoveImpl::read(&mut &bytes[1..]).unwrap(),
+        packet
+    );
+}
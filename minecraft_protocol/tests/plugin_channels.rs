@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+
+use minecraft_protocol::java::v_761::processor::plugin_channels::{
+    is_valid_channel, BrandCodec, ChannelCodec, ChannelRegistry, ClientChannels,
+    PluginMessageError, RegisterCodec, MAX_PAYLOAD_SIZE,
+};
+use minecraft_protocol::packets::play::PlayPluginMessage;
+
+/// A channel a mod could use. A big endian counter
+struct CounterCodec;
+
+impl ChannelCodec for CounterCodec {
+    type Message = u32;
+
+    const CHANNEL: &'static str = "example:counter";
+
+    fn decode(data: &[u8]) -> Result<Self::Message, PluginMessageError> {
+        let bytes: [u8; 4] = data.try_into().map_err(|_| {
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "Expected 4 bytes")
+        })?;
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    fn encode(message: Self::Message) -> Vec<u8> {
+        message.to_be_bytes().to_vec()
+    }
+}
+
+fn message(channel: &'static str, data: impl Into<Vec<u8>>) -> PlayPluginMessage {
+    PlayPluginMessage {
+        id: Cow::Borrowed(channel),
+        data: data.into(),
+    }
+}
+
+#[test]
+pub fn register_and_brand() {
+    let registry = ChannelRegistry::<()>::default();
+    let mut client = ClientChannels::default();
+    registry
+        .handle(
+            &mut client,
+            &mut (),
+            &message("minecraft:register", "example:counter\0example:other"),
+        )
+        .unwrap();
+    assert!(client.supports("example:counter"));
+    assert!(client.message::<CounterCodec>(1).is_some());
+
+    registry
+        .handle(
+            &mut client,
+            &mut (),
+            &message("minecraft:unregister", "example:counter"),
+        )
+        .unwrap();
+    assert!(!client.supports("example:counter"));
+    assert!(client.message::<CounterCodec>(1).is_none());
+    assert!(client.supports("example:other"));
+
+    registry
+        .handle(&mut client, &mut (), &BrandCodec::message("vanilla".into()))
+        .unwrap();
+    assert_eq!(client.brand.as_deref(), Some("vanilla"));
+    assert_eq!(
+        PlayPluginMessage::server_brand("vanilla"),
+        BrandCodec::message("vanilla".into())
+    );
+
+    assert!(matches!(
+        registry.handle(
+            &mut client,
+            &mut (),
+            &message("minecraft:register", "Not A Channel")
+        ),
+        Err(PluginMessageError::InvalidChannel(_))
+    ));
+}
+
+#[test]
+pub fn typed_handler() {
+    let mut registry = ChannelRegistry::<Vec<u32>>::default();
+    registry
+        .subscribe_typed::<CounterCodec>(|received, counter| {
+            received.push(counter);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(
+        RegisterCodec::decode(&registry.register_message().data).unwrap(),
+        vec!["example:counter".to_string()]
+    );
+
+    let mut client = ClientChannels::default();
+    let mut received = Vec::new();
+    registry
+        .handle(&mut client, &mut received, &CounterCodec::message(7))
+        .unwrap();
+    assert_eq!(received, vec![7]);
+
+    assert!(matches!(
+        registry.handle(
+            &mut client,
+            &mut received,
+            &message("example:counter", vec![0; MAX_PAYLOAD_SIZE + 1])
+        ),
+        Err(PluginMessageError::TooLarge(_))
+    ));
+}
+
+#[test]
+pub fn channel_names() {
+    assert!(is_valid_channel("minecraft:brand"));
+    assert!(is_valid_channel("fabric:registry/sync"));
+    assert!(!is_valid_channel("brand"));
+    assert!(!is_valid_channel("Example:channel"));
+    assert!(!is_valid_channel("example:"));
+}
@@ -103,11 +103,72 @@ pub async fn test() -> anyhow::Result<()> {
                     )
                     .await?;
                 }
-                ClientBoundPlay::PlayerInfo(_) => {}
+                ClientBoundPlay::PlayerInfoUpdate(_) => {}
+                ClientBoundPlay::PlayerInfoRemove(_) => {}
                 ClientBoundPlay::ChunkData(data) => {
                     info!("Chunk Data: {} {}", data.chunk_x, data.chunk_z);
                 }
                 ClientBoundPlay::UpdateLight(_) => {}
+                ClientBoundPlay::SystemChat(chat) => {
+                    info!("System Chat: {}", chat.content.to_plain_text());
+                }
+                ClientBoundPlay::PlayerChat(chat) => {
+                    info!("Player Chat: {}", chat.message);
+                }
+                ClientBoundPlay::DisguisedChat(_) => {}
+                ClientBoundPlay::BlockUpdate(_) => {}
+                ClientBoundPlay::UpdateSectionBlocks(_) => {}
+                ClientBoundPlay::BlockEntityData(_) => {}
+                ClientBoundPlay::BlockAction(_) => {}
+                ClientBoundPlay::AcknowledgeBlockChange(_) => {}
+                ClientBoundPlay::SpawnEntity(_) => {}
+                ClientBoundPlay::SpawnExperienceOrb(_) => {}
+                ClientBoundPlay::SpawnPlayer(_) => {}
+                ClientBoundPlay::UpdateEntityPosition(_) => {}
+                ClientBoundPlay::UpdateEntityPositionAndRotation(_) => {}
+                ClientBoundPlay::UpdateEntityRotation(_) => {}
+                ClientBoundPlay::TeleportEntity(_) => {}
+                ClientBoundPlay::SetHeadRotation(_) => {}
+                ClientBoundPlay::SetEntityVelocity(_) => {}
+                ClientBoundPlay::RemoveEntities(_) => {}
+                ClientBoundPlay::SetEntityMetadata(_) => {}
+                ClientBoundPlay::SetEquipment(_) => {}
+                ClientBoundPlay::OpenScreen(_) => {}
+                ClientBoundPlay::SetContainerContent(_) => {}
+                ClientBoundPlay::SetContainerSlot(_) => {}
+                ClientBoundPlay::SetContainerProperty(_) => {}
+                ClientBoundPlay::CloseContainer(_) => {}
+                ClientBoundPlay::SelectedSlot(_) => {}
+                ClientBoundPlay::DeclareCommands(_) => {}
+                ClientBoundPlay::CommandSuggestions(_) => {}
+                ClientBoundPlay::ResourcePack(_) => {}
+                ClientBoundPlay::BossBar(_) => {}
+                ClientBoundPlay::ClearTitles(_) => {}
+                ClientBoundPlay::SetActionBarText(_) => {}
+                ClientBoundPlay::SetSubtitleText(_) => {}
+                ClientBoundPlay::SetTitleText(_) => {}
+                ClientBoundPlay::SetTitleAnimationTimes(_) => {}
+                ClientBoundPlay::SetTabListHeaderAndFooter(_) => {}
+                ClientBoundPlay::UpdateObjectives(_) => {}
+                ClientBoundPlay::DisplayObjective(_) => {}
+                ClientBoundPlay::UpdateScore(_) => {}
+                ClientBoundPlay::UpdateTeams(_) => {}
+                ClientBoundPlay::InitializeWorldBorder(_) => {}
+                ClientBoundPlay::SetBorderCenter(_) => {}
+                ClientBoundPlay::SetBorderLerpSize(_) => {}
+                ClientBoundPlay::SetBorderSize(_) => {}
+                ClientBoundPlay::SetBorderWarningDelay(_) => {}
+                ClientBoundPlay::SetBorderWarningDistance(_) => {}
+                ClientBoundPlay::UpdateTime(_) => {}
+                ClientBoundPlay::GameEvent(_) => {}
+                ClientBoundPlay::SoundEffect(_) => {}
+                ClientBoundPlay::EntitySoundEffect(_) => {}
+                ClientBoundPlay::StopSound(_) => {}
+                ClientBoundPlay::Particle(_) => {}
+                ClientBoundPlay::Respawn(_) => {}
+                ClientBoundPlay::SetDefaultSpawnPosition(_) => {}
+                ClientBoundPlay::SetCenterChunk(_) => {}
+                ClientBoundPlay::UnloadChunk(_) => {}
             }
         }
 
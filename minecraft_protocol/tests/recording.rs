@@ -0,0 +1,133 @@
+use minecraft_protocol::data::PacketDataType;
+use minecraft_protocol::java::v_761::{login, play};
+use minecraft_protocol::packets::login::server_bound::ServerBoundLoginStart;
+use minecraft_protocol::packets::login::ServerBoundLogin;
+use minecraft_protocol::packets::play::server::ServerBoundPlay;
+use minecraft_protocol::packets::play::KeepAlive;
+use minecraft_protocol::simple_handlers::recording::{
+    CaptureReader, CaptureRecord, Recorder, RecordingReader, RecordingWriter,
+};
+use minecraft_protocol::simple_handlers::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
+use minecraft_protocol::{
+    Bound, CompressionSettings, PacketHandler, PacketReadError, PacketReader, PacketWriter, Stage,
+};
+
+const COMPRESSION: CompressionSettings = CompressionSettings::Zlib {
+    threshold: 0,
+    compression_level: 6,
+};
+
+/// Records both ends of a compressed connection and reads the capture back
+#[test]
+pub fn record_and_read_capture() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("recording-{}.mcpcap", std::process::id()));
+    let recorder = Recorder::create(&path)?;
+
+    let mut writer = RecordingWriter::<login::ServerIO, _>::new(
+        NonEncryptedPacketWriter::<login::ServerIO>::default(),
+        recorder.clone(),
+        Bound::ServerBound,
+        Stage::Login,
+    );
+    let mut reader = RecordingReader::<login::ServerIO, _>::new(
+        NonEncryptedPacketReader::<login::ServerIO>::default(),
+        recorder,
+        Bound::ServerBound,
+        Stage::Login,
+    );
+    writer.set_compression(COMPRESSION);
+    reader.set_compression(COMPRESSION);
+
+    let login_start = ServerBoundLogin::LoginStart(ServerBoundLoginStart {
+        name: "Player".to_string(),
+        uuid: None,
+    });
+    let mut wire = Vec::new();
+    writer.send_packet(login_start.clone(), &mut wire)?;
+    reader.get_read_buffer().extend_from_slice(&wire);
+    assert_eq!(reader.attempt_packet_read()?, Some(login_start));
+
+    let mut writer = writer.swap_io::<play::ServerIO>(Stage::Play);
+    let mut reader = reader.swap_io::<play::ServerIO>(Stage::Play);
+    wire.clear();
+    writer.send_packet(ServerBoundPlay::KeepAlive(KeepAlive(42)), &mut wire)?;
+    reader.get_read_buffer().extend_from_slice(&wire);
+    assert_eq!(
+        reader.attempt_packet_read()?,
+        Some(ServerBoundPlay::KeepAlive(KeepAlive(42)))
+    );
+
+    let records = CaptureReader::open(&path)?.collect::<Result<Vec<CaptureRecord>, _>>()?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(records.len(), 4);
+
+    let mut login_data = Vec::new();
+    "Player".write(&mut login_data)?;
+    false.write(&mut login_data)?;
+    // The writer and the reader see the same decompressed bytes
+    for record in &records[..2] {
+        assert_eq!(record.connection, 0);
+        assert_eq!(record.stage, Stage::Login);
+        assert_eq!(record.bound, Bound::ServerBound);
+        assert_eq!(record.packet_id, 0x00);
+        assert_eq!(record.data, login_data);
+    }
+    for record in &records[2..] {
+        assert_eq!(record.stage, Stage::Play);
+        assert_eq!(record.packet_id, 0x11);
+        assert_eq!(record.data, 42i64.to_be_bytes());
+    }
+    Ok(())
+}
+
+#[test]
+pub fn rejects_other_files() {
+    let error = CaptureReader::new(&b"PNG\0\0\0\0\0"[..]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+/// Packets that fail to decode are recorded. Every connection has its own id
+#[test]
+pub fn record_undecodable_packets() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("undecodable-{}.mcpcap", std::process::id()));
+    let recorder = Recorder::create(&path)?;
+    let mut readers = [recorder.new_connection(), recorder.new_connection()].map(|recorder| {
+        RecordingReader::<play::ServerIO, _>::new(
+            NonEncryptedPacketReader::<play::ServerIO>::default(),
+            recorder,
+            Bound::ServerBound,
+            Stage::Play,
+        )
+    });
+    assert_ne!(
+        readers[0].recorder.connection(),
+        readers[1].recorder.connection()
+    );
+
+    // Keep alive with a single byte instead of an i64
+    readers[0]
+        .get_read_buffer()
+        .extend_from_slice(&[2, 0x11, 7]);
+    assert!(matches!(
+        readers[0].attempt_packet_read(),
+        Err(PacketReadError::IoError(_))
+    ));
+    let mut wire = Vec::new();
+    NonEncryptedPacketWriter::<play::ServerIO>::default()
+        .send_packet(ServerBoundPlay::KeepAlive(KeepAlive(1)), &mut wire)?;
+    readers[1].get_read_buffer().extend_from_slice(&wire);
+    assert_eq!(
+        readers[1].attempt_packet_read()?,
+        Some(ServerBoundPlay::KeepAlive(KeepAlive(1)))
+    );
+
+    let records = CaptureReader::open(&path)?.collect::<Result<Vec<CaptureRecord>, _>>()?;
+    std::fs::remove_file(&path)?;
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].connection, readers[0].recorder.connection());
+    assert_eq!(records[0].packet_id, 0x11);
+    assert_eq!(records[0].data, [7]);
+    assert_eq!(records[1].connection, readers[1].recorder.connection());
+    assert_eq!(records[1].data, 1i64.to_be_bytes());
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use minecraft_protocol::java::v_761::play::{ClientIO, ServerIO};
+use minecraft_protocol::java::v_761::processor::resource_pack::{
+    ResourcePackAction, ResourcePackError, ResourcePackPolicy, ResourcePackSession,
+    ResourcePackState,
+};
+use minecraft_protocol::packets::play::client::{ClientBoundPlay, ResourcePack};
+use minecraft_protocol::packets::play::server::{ResourcePackStatus, ServerBoundPlay};
+use minecraft_protocol::simple_handlers::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
+use minecraft_protocol::{PacketReader, PacketWriter};
+use sha1::{Digest, Sha1};
+
+fn pack(forced: bool) -> ResourcePack {
+    let sha1: [u8; 20] = Sha1::digest(b"pack").into();
+    ResourcePack::new("https://example.com/pack.zip", sha1, forced)
+}
+
+#[test]
+pub fn resource_pack_packet() {
+    let pack = pack(true).with_prompt("Required");
+    assert_eq!(pack.hash.len(), 40);
+    assert!(pack.hash.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let mut writer = NonEncryptedPacketWriter::<ClientIO>::default();
+    let mut out = Vec::new();
+    writer.send_packet(pack.clone(), &mut out).unwrap();
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&out);
+    assert_eq!(
+        reader.attempt_packet_read().unwrap(),
+        Some(ClientBoundPlay::ResourcePack(pack))
+    );
+
+    // Length 2, id 0x24, declined
+    let mut reader = NonEncryptedPacketReader::<ServerIO>::default();
+    reader.get_read_buffer().extend_from_slice(&[2, 0x24, 1]);
+    assert_eq!(
+        reader.attempt_packet_read().unwrap(),
+        Some(ServerBoundPlay::ResourcePackStatus(
+            ResourcePackStatus::Declined
+        ))
+    );
+}
+
+#[test]
+pub fn forced_pack_declined() {
+    let mut session = ResourcePackSession::new(pack(true), ResourcePackPolicy::default());
+    assert!(matches!(
+        session.handle_status(ResourcePackStatus::Accepted),
+        Err(ResourcePackError::UnexpectedStatus(..))
+    ));
+    session.start();
+    let action = session.handle_status(ResourcePackStatus::Declined).unwrap();
+    assert_eq!(
+        action,
+        ResourcePackAction::Kick(minecraft_protocol::chat::Component::translatable(
+            "multiplayer.requiredTexturePrompt.disconnect",
+            vec![]
+        ))
+    );
+    assert_eq!(session.state(), ResourcePackState::Declined);
+
+    let mut session = ResourcePackSession::new(pack(false), ResourcePackPolicy::default());
+    session.start();
+    assert_eq!(
+        session.handle_status(ResourcePackStatus::Declined).unwrap(),
+        ResourcePackAction::Done
+    );
+}
+
+#[test]
+pub fn retry_failed_download() {
+    let policy = ResourcePackPolicy {
+        download_retries: 1,
+        kick_on_failure: true,
+        ..Default::default()
+    };
+    let mut session = ResourcePackSession::new(pack(true), policy);
+    session.start();
+    assert_eq!(
+        session
+            .handle_packet(&ServerBoundPlay::ResourcePackStatus(
+                ResourcePackStatus::Accepted
+            ))
+            .unwrap(),
+        ResourcePackAction::Wait
+    );
+    assert_eq!(
+        session
+            .handle_status(ResourcePackStatus::FailedDownload)
+            .unwrap(),
+        ResourcePackAction::Resend(pack(true))
+    );
+    assert_eq!(session.retries(), 1);
+    assert_eq!(session.state(), ResourcePackState::Sent);
+
+    session.handle_status(ResourcePackStatus::Accepted).unwrap();
+    assert!(matches!(
+        session.handle_status(ResourcePackStatus::FailedDownload),
+        Ok(ResourcePackAction::Kick(_))
+    ));
+    assert_eq!(session.state(), ResourcePackState::Failed);
+    assert!(session.state().is_finished());
+}
+
+#[test]
+pub fn loaded() {
+    let mut session = ResourcePackSession::new(pack(true), ResourcePackPolicy::default());
+    session.start();
+    session.handle_status(ResourcePackStatus::Accepted).unwrap();
+    assert_eq!(
+        session
+            .handle_status(ResourcePackStatus::SuccessfullyLoaded)
+            .unwrap(),
+        ResourcePackAction::Done
+    );
+    assert_eq!(session.state(), ResourcePackState::Loaded);
+}
@@ -0,0 +1,1408 @@
+//! Sends every play packet through a writer and back through a reader.
+//!
+//! Each packet is tested with a range of compression thresholds. With and without encryption.
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use cfb8::cipher::KeyIvInit;
+use nbt::Blob;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use minecraft_protocol::chat::{Component, NamedColor};
+use minecraft_protocol::data::slot::Slot;
+use minecraft_protocol::data::var_int::VarInt;
+use minecraft_protocol::data::{NBTOrByteArray, PackedPosition};
+use minecraft_protocol::java::v_761::play::{ClientIO, ServerIO};
+use minecraft_protocol::packets::login::Property;
+use minecraft_protocol::packets::play::client::block::{
+    AcknowledgeBlockChange, BlockAction, BlockEntityData, BlockUpdate, SectionBlock,
+    SectionPosition, UpdateSectionBlocks,
+};
+use minecraft_protocol::packets::play::client::chat::{
+    ChatTypeBinding, DisguisedChatMessage, FilterMask, PackedSignature, PlayerChatMessage,
+    SystemChatMessage,
+};
+use minecraft_protocol::packets::play::client::chunk::{
+    BlockEntity, ChunkDataAndLight, ChunkPacket, LightPacket, SetCenterChunk, UnloadChunk,
+    UpdateLightPacket,
+};
+use minecraft_protocol::packets::play::client::commands::{
+    ArgumentParser, CommandSuggestion, CommandSuggestionsResponse, CommandTreeNode,
+    DeclareCommands, StringBehavior, ASK_SERVER,
+};
+use minecraft_protocol::packets::play::client::container::{
+    OpenScreen, SetContainerContent, SetContainerSlot,
+};
+use minecraft_protocol::packets::play::client::entity::{
+    Angle, EquipmentSlot, RemoveEntities, SetEntityMetadata, SetEquipment, SpawnEntity,
+    TeleportEntity, Velocity,
+};
+use minecraft_protocol::packets::play::client::hud::{
+    BossBar, BossBarAction, BossBarColor, BossBarDivision, BossBarFlags, ClearTitles,
+    SetActionBarText, SetTabListHeaderAndFooter, SetTitleAnimationTimes, SetTitleText,
+};
+use minecraft_protocol::packets::play::client::login::GameMode;
+use minecraft_protocol::packets::play::client::metadata::{
+    Direction, EntityMetadataValue, GlobalPosition, MetadataEntry, Pose,
+};
+use minecraft_protocol::packets::play::client::particle::{
+    Particle, ParticleData, ParticlePacket, PositionSource,
+};
+use minecraft_protocol::packets::play::client::player_info::{
+    PlayerInfoActions, PlayerInfoEntry, PlayerInfoRemove, PlayerInfoUpdate, SyncPlayerPosition,
+    SyncPlayerPositionFlags,
+};
+use minecraft_protocol::packets::play::client::respawn::{
+    Respawn, RespawnDataKept, SetDefaultSpawnPosition,
+};
+use minecraft_protocol::packets::play::client::scoreboard::{
+    CollisionRule, DisplayObjective, NameTagVisibility, ObjectiveAction, ObjectiveRenderType,
+    ScoreAction, TeamAction, TeamFlags, TeamInfo, UpdateObjectives, UpdateScore, UpdateTeams,
+};
+use minecraft_protocol::packets::play::client::sound::{
+    EntitySoundEffect, SoundCategory, SoundEffect, SoundEvent, StopSound,
+};
+use minecraft_protocol::packets::play::client::world::{
+    GameEvent, InitializeWorldBorder, SetBorderLerpSize, UpdateTime,
+};
+use minecraft_protocol::packets::play::client::{
+    AbilitiesPacket, AbilityFlags, ChangeDifficultyPacket, ClientBoundPlay, Difficulty,
+    DisconnectPacket, LoginPacket, ResourcePack, SelectedSlotPacket, ServerData,
+};
+use minecraft_protocol::packets::play::server::chat::{
+    ArgumentSignature, ChatCommand, ChatMessage, LastSeenUpdate, MessageAcknowledgment,
+    PlayerSession, ProfilePublicKey,
+};
+use minecraft_protocol::packets::play::server::container::{
+    ChangedSlot, ClickContainer, ClickMode, SetCreativeModeSlot,
+};
+use minecraft_protocol::packets::play::server::interaction::{
+    Hand, Interact, InteractAction, PlayerAction, PlayerActionStatus, PlayerCommand,
+    PlayerCommandAction, SwingArm, UseItem, UseItemOn,
+};
+use minecraft_protocol::packets::play::server::{
+    ChatMode, ClientInformation, CommandSuggestionsRequest, ConfirmTeleport, MainHand,
+    ResourcePackStatus, ServerBoundMove, ServerBoundPlay, SkinParts,
+};
+use minecraft_protocol::packets::play::{KeepAlive, MessageSignature, PlayPing, PlayPluginMessage};
+use minecraft_protocol::simple_handlers::{
+    EncryptedPacketReader, EncryptedPacketWriter, NonEncryptedPacketReader,
+    NonEncryptedPacketWriter,
+};
+use minecraft_protocol::{
+    CompressionSettings, Decryptor, Encryptor, PacketIO, PacketReader, PacketWriter,
+};
+
+fn compression() -> impl Strategy<Value = CompressionSettings> {
+    prop_oneof![
+        Just(CompressionSettings::None),
+        Just(CompressionSettings::Zlib {
+            threshold: -1,
+            compression_level: 6,
+        }),
+        (0..1024i32, 0..10u32).prop_map(|(threshold, compression_level)| {
+            CompressionSettings::Zlib {
+                threshold,
+                compression_level,
+            }
+        }),
+    ]
+}
+
+/// Writes the packet and feeds it to the reader. Split in two parts if `split` is set.
+fn round_trip<IO: PacketIO + Debug>(
+    packet: IO::Type,
+    compression: CompressionSettings,
+    split: Option<Index>,
+    mut writer: impl PacketWriter<Buffer = Vec<u8>, PacketOut = IO::Type>,
+    mut reader: impl PacketReader<ReadBuffer = bytes::BytesMut, PacketIn = IO::Type>,
+) -> Result<(), TestCaseError>
+where
+    IO::Type: PartialEq,
+{
+    writer.set_compression(compression);
+    reader.set_compression(compression);
+    writer.write_packet(packet.clone()).unwrap();
+    let bytes = writer.get_buffer().clone();
+    let split = split.map(|split| split.index(bytes.len())).unwrap_or(0);
+
+    if split != 0 {
+        reader.get_read_buffer().extend_from_slice(&bytes[..split]);
+        prop_assert!(reader.attempt_packet_read().unwrap().is_none());
+    }
+    reader.get_read_buffer().extend_from_slice(&bytes[split..]);
+    let read = reader.attempt_packet_read().unwrap();
+    prop_assert_eq!(read, Some(packet));
+    prop_assert_eq!(reader.get_read_buffer_ref().len(), 0);
+    Ok(())
+}
+
+fn round_trip_all<IO: PacketIO + Debug>(
+    packet: IO::Type,
+    compression: CompressionSettings,
+    split: Index,
+) -> Result<(), TestCaseError>
+where
+    IO::Type: PartialEq,
+{
+    round_trip::<IO>(
+        packet.clone(),
+        compression,
+        Some(split),
+        NonEncryptedPacketWriter::<IO>::default(),
+        NonEncryptedPacketReader::<IO>::default(),
+    )?;
+    let secret = [3u8; 16];
+    round_trip::<IO>(
+        packet,
+        compression,
+        Some(split),
+        EncryptedPacketWriter::<IO>::new(Encryptor::new_from_slices(&secret, &secret).unwrap()),
+        EncryptedPacketReader::<IO>::new(Decryptor::new_from_slices(&secret, &secret).unwrap()),
+    )
+}
+
+fn string() -> impl Strategy<Value = String> {
+    "[a-zA-Z0-9:_ ]{0,64}"
+}
+fn component() -> impl Strategy<Value = Component> {
+    (string(), string(), any::<bool>(), 0..16usize).prop_map(|(text, child, bold, color)| {
+        Component::text(text)
+            .color(NamedColor::ALL[color])
+            .append(Component::text(child).bold(bold))
+    })
+}
+fn blob() -> impl Strategy<Value = Blob> {
+    (any::<i32>(), string()).prop_map(|(value, name)| {
+        let mut blob = Blob::new();
+        blob.insert("value", value.to_string()).unwrap();
+        blob.insert("name", name).unwrap();
+        blob
+    })
+}
+fn light() -> impl Strategy<Value = LightPacket> {
+    (
+        any::<bool>(),
+        prop::collection::vec(any::<i64>(), 0..4),
+        prop::collection::vec(any::<i64>(), 0..4),
+        prop::collection::vec(any::<i64>(), 0..4),
+        prop::collection::vec(any::<i64>(), 0..4),
+        prop::collection::vec(any::<u8>(), 0..4096),
+        prop::collection::vec(any::<u8>(), 0..4096),
+    )
+        .prop_map(
+            |(
+                trust_edges,
+                sky_light_mask,
+                block_light_mask,
+                empty_sky_light_mask,
+                empty_block_light_mask,
+                sky_light,
+                block_light,
+            )| LightPacket {
+                trust_edges,
+                sky_light_mask,
+                block_light_mask,
+                empty_sky_light_mask,
+                empty_block_light_mask,
+                sky_light,
+                block_light,
+            },
+        )
+}
+
+fn plugin_message() -> impl Strategy<Value = PlayPluginMessage> {
+    (string(), prop::collection::vec(any::<u8>(), 0..2048)).prop_map(|(id, data)| {
+        PlayPluginMessage {
+            id: Cow::Owned(id),
+            data,
+        }
+    })
+}
+
+fn login() -> impl Strategy<Value = LoginPacket> {
+    (
+        (
+            any::<i32>(),
+            any::<bool>(),
+            prop_oneof![
+                Just(GameMode::Survival),
+                Just(GameMode::Creative),
+                Just(GameMode::Adventure),
+                Just(GameMode::Spectator)
+            ],
+            -1..4i8,
+            prop::collection::vec(string(), 0..4),
+            blob(),
+            string(),
+            string(),
+        ),
+        (
+            any::<[u8; 8]>(),
+            any::<i32>(),
+            any::<i32>(),
+            any::<i32>(),
+            any::<[bool; 4]>(),
+        ),
+    )
+        .prop_map(
+            |(
+                (
+                    id,
+                    is_hardcore,
+                    game_mode,
+                    previous_game_mode,
+                    dimension_names,
+                    registry_codec,
+                    dimension_type,
+                    dimension_name,
+                ),
+                (hashed_seed, max_players, view_distance, simulation_distance, flags),
+            )| LoginPacket {
+                id,
+                is_hardcore,
+                game_mode,
+                previous_game_mode,
+                dimension_names,
+                registry_codec: NBTOrByteArray::NBT(registry_codec),
+                dimension_type,
+                dimension_name,
+                hashed_seed,
+                max_players: VarInt(max_players),
+                view_distance: VarInt(view_distance),
+                simulation_distance: VarInt(simulation_distance),
+                reduced_debug_info: flags[0],
+                enable_respawn_screen: flags[1],
+                is_debug: flags[2],
+                is_flat: flags[3],
+                death_location: None,
+            },
+        )
+}
+
+fn chunk_data() -> impl Strategy<Value = ChunkDataAndLight> {
+    let block_entity = (0..16i8, 0..16i8, any::<i16>(), any::<i32>(), blob()).prop_map(
+        |(x, z, y, block_type, data)| BlockEntity {
+            x,
+            z,
+            y,
+            block_type: VarInt(block_type),
+            data: NBTOrByteArray::NBT(data),
+        },
+    );
+    (
+        any::<i32>(),
+        any::<i32>(),
+        blob(),
+        prop::collection::vec(any::<u8>(), 0..8192),
+        prop::collection::vec(block_entity, 0..4),
+        light(),
+    )
+        .prop_map(
+            |(chunk_x, chunk_z, height_map, data, block_entities, light)| ChunkDataAndLight {
+                chunk_x,
+                chunk_z,
+                chunk_data: ChunkPacket {
+                    height_map: NBTOrByteArray::NBT(height_map),
+                    data,
+                    block_entities,
+                },
+                light,
+            },
+        )
+}
+
+/// Every field is set. So it only round trips when all actions are sent
+fn player_info_entry() -> impl Strategy<Value = PlayerInfoEntry> {
+    (
+        any::<u128>(),
+        string(),
+        prop::option::of((string(), string())),
+        prop::option::of((any::<u128>(), any::<i64>())),
+        any::<(bool, i32)>(),
+        prop::option::of(component()),
+    )
+        .prop_map(
+            |(uuid, name, property, session, (listed, latency), display_name)| PlayerInfoEntry {
+                uuid: uuid::Uuid::from_u128(uuid),
+                name,
+                properties: property
+                    .into_iter()
+                    .map(|(value, signature)| Property {
+                        name: "textures".to_string(),
+                        value,
+                        signature: Some(signature),
+                    })
+                    .collect(),
+                chat_session: session.map(|(session_id, expires_at)| PlayerSession {
+                    session_id: uuid::Uuid::from_u128(session_id),
+                    public_key: ProfilePublicKey {
+                        expires_at,
+                        key: vec![1, 2, 3],
+                        key_signature: vec![4, 5],
+                    },
+                }),
+                game_mode: GameMode::Creative,
+                listed,
+                latency,
+                display_name,
+            },
+        )
+}
+
+fn signature() -> impl Strategy<Value = MessageSignature> {
+    prop::collection::vec(any::<u8>(), MessageSignature::LENGTH)
+        .prop_map(|bytes| MessageSignature::from_slice(&bytes).unwrap())
+}
+
+fn chat_type() -> impl Strategy<Value = ChatTypeBinding> {
+    (0..8i32, component(), prop::option::of(component())).prop_map(
+        |(chat_type, name, target_name)| ChatTypeBinding {
+            chat_type,
+            name,
+            target_name,
+        },
+    )
+}
+
+fn player_chat() -> impl Strategy<Value = PlayerChatMessage> {
+    (
+        any::<u128>(),
+        any::<i32>(),
+        prop::option::of(signature()),
+        string(),
+        any::<(i64, i64)>(),
+        prop::collection::vec(
+            prop_oneof![
+                (0..i32::MAX).prop_map(PackedSignature::Id),
+                signature().prop_map(PackedSignature::Full),
+            ],
+            0..4,
+        ),
+        prop::option::of(component()),
+        prop_oneof![
+            Just(FilterMask::PassThrough),
+            Just(FilterMask::FullyFiltered),
+            prop::collection::vec(any::<i64>(), 0..4).prop_map(FilterMask::PartiallyFiltered),
+        ],
+        chat_type(),
+    )
+        .prop_map(
+            |(
+                sender,
+                index,
+                signature,
+                message,
+                (timestamp, salt),
+                previous_messages,
+                unsigned_content,
+                filter,
+                chat_type,
+            )| PlayerChatMessage {
+                sender: uuid::Uuid::from_u128(sender),
+                index,
+                signature,
+                message,
+                timestamp,
+                salt,
+                previous_messages,
+                unsigned_content,
+                filter,
+                chat_type,
+            },
+        )
+}
+
+fn position() -> impl Strategy<Value = PackedPosition> {
+    (
+        -(1 << 25)..(1 << 25),
+        -(1 << 11)..(1 << 11),
+        -(1 << 25)..(1 << 25),
+    )
+        .prop_map(|position: (i32, i32, i32)| PackedPosition::new(position))
+}
+
+fn section_blocks() -> impl Strategy<Value = UpdateSectionBlocks> {
+    (
+        -(1 << 21)..(1 << 21),
+        -(1 << 19)..(1 << 19),
+        -(1 << 21)..(1 << 21),
+        any::<bool>(),
+        prop::collection::vec(
+            (0..1 << 20, 0..16u8, 0..16u8, 0..16u8).prop_map(|(block_state, x, y, z)| {
+                SectionBlock {
+                    block_state,
+                    x,
+                    y,
+                    z,
+                }
+            }),
+            0..64,
+        ),
+    )
+        .prop_map(
+            |(x, y, z, suppress_light_updates, blocks)| UpdateSectionBlocks {
+                section: SectionPosition { x, y, z },
+                suppress_light_updates,
+                blocks,
+            },
+        )
+}
+
+fn slot() -> impl Strategy<Value = Slot> {
+    prop_oneof![
+        Just(Slot::EMPTY),
+        (any::<i32>(), any::<i8>()).prop_map(|(item_id, count)| Slot::new(item_id, count)),
+    ]
+}
+
+fn metadata_value() -> impl Strategy<Value = EntityMetadataValue> {
+    prop_oneof![
+        any::<i8>().prop_map(EntityMetadataValue::Byte),
+        any::<i64>().prop_map(EntityMetadataValue::VarLong),
+        string().prop_map(EntityMetadataValue::String),
+        prop::option::of(component()).prop_map(EntityMetadataValue::OptionalComponent),
+        slot().prop_map(EntityMetadataValue::Slot),
+        any::<(f32, f32, f32)>().prop_map(|(x, y, z)| EntityMetadataValue::Rotations { x, y, z }),
+        prop::option::of(position()).prop_map(EntityMetadataValue::OptionalPosition),
+        Just(EntityMetadataValue::Direction(Direction::West)),
+        prop::option::of(any::<u128>().prop_map(uuid::Uuid::from_u128))
+            .prop_map(EntityMetadataValue::OptionalUuid),
+        prop::option::of(1..i32::MAX).prop_map(EntityMetadataValue::OptionalBlockState),
+        blob().prop_map(EntityMetadataValue::Nbt),
+        (0..1i32 << 20).prop_map(|block_state| {
+            EntityMetadataValue::Particle(Particle {
+                particle_type: Particle::BLOCK,
+                data: ParticleData::BlockState(block_state),
+            })
+        }),
+        prop::option::of(0..i32::MAX).prop_map(EntityMetadataValue::OptionalVarInt),
+        Just(EntityMetadataValue::Pose(Pose::Crouching)),
+        prop::option::of((string(), position()).prop_map(|(dimension, position)| {
+            GlobalPosition {
+                dimension,
+                position,
+            }
+        }))
+        .prop_map(EntityMetadataValue::OptionalGlobalPosition),
+    ]
+}
+
+fn last_seen() -> impl Strategy<Value = LastSeenUpdate> {
+    (any::<i32>(), 0..1u32 << 20).prop_map(|(offset, acknowledged)| LastSeenUpdate {
+        offset,
+        acknowledged,
+    })
+}
+
+fn client_bound() -> impl Strategy<Value = ClientBoundPlay> {
+    prop_oneof![
+        login().prop_map(ClientBoundPlay::Login),
+        component().prop_map(|reason| ClientBoundPlay::Disconnect(DisconnectPacket(reason))),
+        (
+            prop::option::of(component()),
+            prop::option::of(string()),
+            any::<bool>()
+        )
+            .prop_map(|(motd, icon, enforced_secure_chat)| {
+                ClientBoundPlay::ServerData(ServerData {
+                    motd,
+                    icon,
+                    previews_chat: false,
+                    enforced_secure_chat,
+                })
+            }),
+        plugin_message().prop_map(ClientBoundPlay::PluginMessage),
+        (any::<u8>(), any::<f32>(), any::<f32>()).prop_map(|(flags, flying, walking)| {
+            ClientBoundPlay::Abilities(AbilitiesPacket {
+                flags: AbilityFlags::from_bits_truncate(flags),
+                flying_speed: flying,
+                walking_speed: walking,
+            })
+        }),
+        (
+            prop_oneof![
+                Just(Difficulty::Peaceful),
+                Just(Difficulty::Easy),
+                Just(Difficulty::Normal),
+                Just(Difficulty::Hard)
+            ],
+            any::<bool>()
+        )
+            .prop_map(|(difficulty, locked)| {
+                ClientBoundPlay::ChangeDifficulty(ChangeDifficultyPacket { difficulty, locked })
+            }),
+        any::<i64>().prop_map(|v| ClientBoundPlay::KeepAlive(KeepAlive(v))),
+        any::<i32>().prop_map(|v| ClientBoundPlay::Ping(PlayPing(v))),
+        (
+            any::<(f64, f64, f64)>(),
+            any::<(f32, f32)>(),
+            any::<u8>(),
+            any::<i32>(),
+            any::<bool>()
+        )
+            .prop_map(
+                |((x, y, z), (yaw, pitch), flags, teleport_id, dismount_vehicle)| {
+                    ClientBoundPlay::SyncPlayerPosition(SyncPlayerPosition {
+                        x,
+                        y,
+                        z,
+                        yaw,
+                        pitch,
+                        flags: SyncPlayerPositionFlags::from_bits_truncate(flags),
+                        teleport_id: VarInt(teleport_id),
+                        dismount_vehicle,
+                    })
+                }
+            ),
+        chunk_data().prop_map(ClientBoundPlay::ChunkData),
+        (any::<i32>(), any::<i32>(), light()).prop_map(|(chunk_x, chunk_z, light)| {
+            ClientBoundPlay::UpdateLight(UpdateLightPacket {
+                chunk_x,
+                chunk_z,
+                light,
+            })
+        }),
+        (component(), any::<bool>()).prop_map(|(content, overlay)| {
+            ClientBoundPlay::SystemChat(SystemChatMessage { content, overlay })
+        }),
+        player_chat().prop_map(ClientBoundPlay::PlayerChat),
+        (component(), chat_type()).prop_map(|(message, chat_type)| {
+            ClientBoundPlay::DisguisedChat(DisguisedChatMessage { message, chat_type })
+        }),
+        (position(), any::<i32>()).prop_map(|(location, block_state)| {
+            ClientBoundPlay::BlockUpdate(BlockUpdate {
+                location,
+                block_state,
+            })
+        }),
+        section_blocks().prop_map(ClientBoundPlay::UpdateSectionBlocks),
+        (position(), any::<i32>(), prop::option::of(blob())).prop_map(
+            |(location, block_entity_type, data)| {
+                ClientBoundPlay::BlockEntityData(BlockEntityData {
+                    location,
+                    block_entity_type,
+                    data,
+                })
+            }
+        ),
+        (position(), any::<(u8, u8)>(), any::<i32>()).prop_map(
+            |(location, (action_id, action_parameter), block_type)| {
+                ClientBoundPlay::BlockAction(BlockAction {
+                    location,
+                    action_id,
+                    action_parameter,
+                    block_type,
+                })
+            }
+        ),
+        any::<i32>().prop_map(|sequence| {
+            ClientBoundPlay::AcknowledgeBlockChange(AcknowledgeBlockChange(VarInt(sequence)))
+        }),
+        (
+            any::<(i32, u128, i32, i32)>(),
+            any::<(f64, f64, f64)>(),
+            any::<(u8, u8, u8)>(),
+            any::<(i16, i16, i16)>()
+        )
+            .prop_map(
+                |(
+                    (entity_id, uuid, entity_type, data),
+                    (x, y, z),
+                    (pitch, yaw, head_yaw),
+                    (velocity_x, velocity_y, velocity_z),
+                )| {
+                    ClientBoundPlay::SpawnEntity(SpawnEntity {
+                        entity_id,
+                        uuid: uuid::Uuid::from_u128(uuid),
+                        entity_type,
+                        x,
+                        y,
+                        z,
+                        pitch: Angle(pitch),
+                        yaw: Angle(yaw),
+                        head_yaw: Angle(head_yaw),
+                        data,
+                        velocity: Velocity {
+                            x: velocity_x,
+                            y: velocity_y,
+                            z: velocity_z,
+                        },
+                    })
+                }
+            ),
+        (
+            any::<i32>(),
+            any::<(f64, f64, f64)>(),
+            any::<(u8, u8)>(),
+            any::<bool>()
+        )
+            .prop_map(|(entity_id, (x, y, z), (yaw, pitch), on_ground)| {
+                ClientBoundPlay::TeleportEntity(TeleportEntity {
+                    entity_id,
+                    x,
+                    y,
+                    z,
+                    yaw: Angle(yaw),
+                    pitch: Angle(pitch),
+                    on_ground,
+                })
+            }),
+        prop::collection::vec(any::<i32>(), 0..16).prop_map(|entity_ids| {
+            ClientBoundPlay::RemoveEntities(RemoveEntities { entity_ids })
+        }),
+        (
+            any::<i32>(),
+            prop::collection::vec(
+                (0..255u8, metadata_value())
+                    .prop_map(|(index, value)| MetadataEntry { index, value }),
+                0..8
+            )
+        )
+            .prop_map(|(entity_id, metadata)| {
+                ClientBoundPlay::SetEntityMetadata(SetEntityMetadata {
+                    entity_id,
+                    metadata,
+                })
+            }),
+        (
+            any::<i32>(),
+            prop::collection::vec(
+                (
+                    prop_oneof![
+                        Just(EquipmentSlot::MainHand),
+                        Just(EquipmentSlot::OffHand),
+                        Just(EquipmentSlot::Helmet)
+                    ],
+                    slot()
+                ),
+                1..6
+            )
+        )
+            .prop_map(|(entity_id, equipment)| {
+                ClientBoundPlay::SetEquipment(SetEquipment {
+                    entity_id,
+                    equipment,
+                })
+            }),
+        (any::<i32>(), any::<i32>(), string()).prop_map(|(window_id, window_type, title)| {
+            ClientBoundPlay::OpenScreen(OpenScreen {
+                window_id,
+                window_type,
+                title: Component::text(title),
+            })
+        }),
+        (
+            any::<u8>(),
+            any::<i32>(),
+            prop::collection::vec(slot(), 0..46),
+            slot()
+        )
+            .prop_map(|(window_id, state_id, slots, carried_item)| {
+                ClientBoundPlay::SetContainerContent(SetContainerContent {
+                    window_id,
+                    state_id,
+                    slots,
+                    carried_item,
+                })
+            }),
+        (any::<i8>(), any::<i32>(), any::<i16>(), slot()).prop_map(
+            |(window_id, state_id, slot, item)| {
+                ClientBoundPlay::SetContainerSlot(SetContainerSlot {
+                    window_id,
+                    state_id,
+                    slot,
+                    item,
+                })
+            }
+        ),
+        (0..9i32).prop_map(|slot| ClientBoundPlay::SelectedSlot(SelectedSlotPacket(slot))),
+        prop::collection::vec(player_info_entry(), 0..4).prop_map(|players| {
+            ClientBoundPlay::PlayerInfoUpdate(PlayerInfoUpdate {
+                actions: PlayerInfoActions::all(),
+                players,
+            })
+        }),
+        prop::collection::vec(any::<u128>(), 0..8).prop_map(|players| {
+            ClientBoundPlay::PlayerInfoRemove(PlayerInfoRemove {
+                players: players.into_iter().map(uuid::Uuid::from_u128).collect(),
+            })
+        }),
+        (string(), any::<Option<i32>>(), any::<bool>()).prop_map(|(name, max, executable)| {
+            let mut argument =
+                CommandTreeNode::argument(name, ArgumentParser::Integer { min: Some(0), max })
+                    .suggests(ASK_SERVER);
+            if executable {
+                argument = argument.executes();
+            }
+            let commands = vec![
+                CommandTreeNode::literal("give").then(argument),
+                CommandTreeNode::literal("say").then(
+                    CommandTreeNode::argument(
+                        "message",
+                        ArgumentParser::String(StringBehavior::GreedyPhrase),
+                    )
+                    .executes(),
+                ),
+                CommandTreeNode::literal("run").redirect(Vec::<String>::new()),
+            ];
+            ClientBoundPlay::DeclareCommands(DeclareCommands::from_tree(commands).unwrap())
+        }),
+        (
+            any::<(i32, i32, i32)>(),
+            prop::collection::vec(
+                (string(), prop::option::of(string())).prop_map(|(text, tooltip)| {
+                    CommandSuggestion {
+                        text,
+                        tooltip: tooltip.map(Component::text),
+                    }
+                }),
+                0..8
+            )
+        )
+            .prop_map(|((transaction_id, start, length), matches)| {
+                ClientBoundPlay::CommandSuggestions(CommandSuggestionsResponse {
+                    transaction_id,
+                    start,
+                    length,
+                    matches,
+                })
+            }),
+        (
+            string(),
+            any::<[u8; 20]>(),
+            any::<bool>(),
+            prop::option::of(component())
+        )
+            .prop_map(|(url, sha1, forced, prompt)| {
+                ClientBoundPlay::ResourcePack(ResourcePack {
+                    prompt,
+                    ..ResourcePack::new(url, sha1, forced)
+                })
+            }),
+        (any::<u128>(), boss_bar_action()).prop_map(|(uuid, action)| {
+            ClientBoundPlay::BossBar(BossBar {
+                uuid: uuid::Uuid::from_u128(uuid),
+                action,
+            })
+        }),
+        component().prop_map(|text| ClientBoundPlay::SetTitleText(SetTitleText(text))),
+        component().prop_map(|text| ClientBoundPlay::SetActionBarText(SetActionBarText(text))),
+        any::<(i32, i32, i32)>().prop_map(|(fade_in, stay, fade_out)| {
+            ClientBoundPlay::SetTitleAnimationTimes(SetTitleAnimationTimes {
+                fade_in,
+                stay,
+                fade_out,
+            })
+        }),
+        any::<bool>().prop_map(|reset| ClientBoundPlay::ClearTitles(ClearTitles { reset })),
+        (component(), component()).prop_map(|(header, footer)| {
+            ClientBoundPlay::SetTabListHeaderAndFooter(SetTabListHeaderAndFooter { header, footer })
+        }),
+        (
+            "[a-z_]{1,16}",
+            prop::option::of((component(), any::<bool>()))
+        )
+            .prop_map(|(name, update)| {
+                let action = match update {
+                    Some((display_name, hearts)) => ObjectiveAction::Create {
+                        display_name,
+                        render_type: if hearts {
+                            ObjectiveRenderType::Hearts
+                        } else {
+                            ObjectiveRenderType::Integer
+                        },
+                    },
+                    None => ObjectiveAction::Remove,
+                };
+                ClientBoundPlay::UpdateObjectives(UpdateObjectives { name, action })
+            }),
+        (any::<i8>(), "[a-z_]{0,16}").prop_map(|(position, name)| {
+            ClientBoundPlay::DisplayObjective(DisplayObjective { position, name })
+        }),
+        (
+            "[a-zA-Z0-9_]{1,16}",
+            "[a-z_]{0,16}",
+            prop::option::of(any::<i32>())
+        )
+            .prop_map(|(entity_name, objective, value)| {
+                ClientBoundPlay::UpdateScore(UpdateScore {
+                    entity_name,
+                    objective,
+                    action: value.map_or(ScoreAction::Remove, ScoreAction::Update),
+                })
+            }),
+        ("[a-z_]{1,16}", team_action())
+            .prop_map(|(name, action)| ClientBoundPlay::UpdateTeams(UpdateTeams { name, action })),
+        (
+            any::<(f64, f64, f64, f64)>(),
+            any::<i64>(),
+            any::<(i32, i32, i32)>()
+        )
+            .prop_map(
+                |(
+                    (x, z, old_diameter, new_diameter),
+                    speed,
+                    (portal_teleport_boundary, warning_blocks, warning_time),
+                )| {
+                    ClientBoundPlay::InitializeWorldBorder(InitializeWorldBorder {
+                        x,
+                        z,
+                        old_diameter,
+                        new_diameter,
+                        speed,
+                        portal_teleport_boundary,
+                        warning_blocks,
+                        warning_time,
+                    })
+                }
+            ),
+        (any::<(f64, f64)>(), any::<i64>()).prop_map(|((old_diameter, new_diameter), speed)| {
+            ClientBoundPlay::SetBorderLerpSize(SetBorderLerpSize {
+                old_diameter,
+                new_diameter,
+                speed,
+            })
+        }),
+        any::<(i64, i64)>().prop_map(|(world_age, time_of_day)| {
+            ClientBoundPlay::UpdateTime(UpdateTime {
+                world_age,
+                time_of_day,
+            })
+        }),
+        game_event().prop_map(ClientBoundPlay::GameEvent),
+        (
+            sound_event(),
+            sound_category(),
+            any::<(i32, i32, i32)>(),
+            any::<(f32, f32, i64)>()
+        )
+            .prop_map(|(sound, category, (x, y, z), (volume, pitch, seed))| {
+                ClientBoundPlay::SoundEffect(SoundEffect {
+                    sound,
+                    category,
+                    x,
+                    y,
+                    z,
+                    volume,
+                    pitch,
+                    seed,
+                })
+            }),
+        (
+            sound_event(),
+            sound_category(),
+            any::<i32>(),
+            any::<(f32, f32, i64)>()
+        )
+            .prop_map(|(sound, category, entity_id, (volume, pitch, seed))| {
+                ClientBoundPlay::EntitySoundEffect(EntitySoundEffect {
+                    sound,
+                    category,
+                    entity_id,
+                    volume,
+                    pitch,
+                    seed,
+                })
+            }),
+        (
+            prop::option::of(sound_category()),
+            prop::option::of(string())
+        )
+            .prop_map(|(category, sound)| ClientBoundPlay::StopSound(StopSound {
+                category,
+                sound
+            })),
+        (
+            particle(),
+            any::<bool>(),
+            any::<(f64, f64, f64)>(),
+            any::<(f32, f32, f32, f32)>(),
+            any::<i32>()
+        )
+            .prop_map(
+                |(
+                    particle,
+                    long_distance,
+                    (x, y, z),
+                    (offset_x, offset_y, offset_z, max_speed),
+                    count,
+                )| {
+                    ClientBoundPlay::Particle(ParticlePacket {
+                        particle,
+                        long_distance,
+                        x,
+                        y,
+                        z,
+                        offset_x,
+                        offset_y,
+                        offset_z,
+                        max_speed,
+                        count,
+                    })
+                }
+            ),
+        (
+            (string(), string()),
+            any::<[u8; 8]>(),
+            (0..4u8, -1..4i8),
+            any::<(bool, bool, bool)>(),
+            prop::option::of((string(), any::<i64>()))
+        )
+            .prop_map(
+                |(
+                    (dimension_type, dimension_name),
+                    hashed_seed,
+                    (game_mode, previous_game_mode),
+                    (is_debug, is_flat, keep_data),
+                    death_location,
+                )| {
+                    ClientBoundPlay::Respawn(Respawn {
+                        dimension_type,
+                        dimension_name,
+                        hashed_seed,
+                        game_mode: GameMode::try_from(game_mode).unwrap(),
+                        previous_game_mode,
+                        is_debug,
+                        is_flat,
+                        // 1.19.3 only knows all or nothing
+                        data_kept: if keep_data {
+                            RespawnDataKept::all()
+                        } else {
+                            RespawnDataKept::empty()
+                        },
+                        death_location,
+                    })
+                }
+            ),
+        (position(), any::<f32>()).prop_map(|(position, angle)| {
+            ClientBoundPlay::SetDefaultSpawnPosition(SetDefaultSpawnPosition { position, angle })
+        }),
+        any::<(i32, i32)>().prop_map(|(chunk_x, chunk_z)| {
+            ClientBoundPlay::SetCenterChunk(SetCenterChunk { chunk_x, chunk_z })
+        }),
+        any::<(i32, i32)>().prop_map(|(chunk_x, chunk_z)| {
+            ClientBoundPlay::UnloadChunk(UnloadChunk { chunk_x, chunk_z })
+        }),
+    ]
+}
+
+fn sound_event() -> impl Strategy<Value = SoundEvent> {
+    prop_oneof![
+        (0..2048).prop_map(SoundEvent::Registered),
+        (string(), prop::option::of(any::<f32>()))
+            .prop_map(|(name, fixed_range)| SoundEvent::Named { name, fixed_range }),
+    ]
+}
+
+fn sound_category() -> impl Strategy<Value = SoundCategory> {
+    prop_oneof![
+        Just(SoundCategory::Master),
+        Just(SoundCategory::Record),
+        Just(SoundCategory::Block),
+        Just(SoundCategory::Voice),
+    ]
+}
+
+fn particle() -> impl Strategy<Value = Particle> {
+    let color = any::<(f32, f32, f32)>().prop_map(|(r, g, b)| [r, g, b]);
+    prop_oneof![
+        (0..2).prop_map(Particle::simple),
+        (0..1i32 << 20).prop_map(|block_state| Particle {
+            particle_type: Particle::FALLING_DUST,
+            data: ParticleData::BlockState(block_state),
+        }),
+        (color.clone(), any::<f32>()).prop_map(|(color, scale)| Particle {
+            particle_type: Particle::DUST,
+            data: ParticleData::Dust { color, scale },
+        }),
+        (color.clone(), any::<f32>(), color).prop_map(|(from, scale, to)| Particle {
+            particle_type: Particle::DUST_COLOR_TRANSITION,
+            data: ParticleData::DustColorTransition { from, scale, to },
+        }),
+        slot().prop_map(|slot| Particle {
+            particle_type: Particle::ITEM,
+            data: ParticleData::Item(slot),
+        }),
+        (position(), any::<i32>()).prop_map(|(position, ticks)| Particle {
+            particle_type: Particle::VIBRATION,
+            data: ParticleData::Vibration {
+                source: PositionSource::Block(position),
+                ticks,
+            },
+        }),
+        (any::<i32>(), any::<f32>(), any::<i32>()).prop_map(|(entity_id, eye_height, ticks)| {
+            Particle {
+                particle_type: Particle::VIBRATION,
+                data: ParticleData::Vibration {
+                    source: PositionSource::Entity {
+                        entity_id,
+                        eye_height,
+                    },
+                    ticks,
+                },
+            }
+        }),
+        any::<i32>().prop_map(|delay| Particle {
+            particle_type: Particle::SHRIEK,
+            data: ParticleData::Shriek { delay },
+        }),
+    ]
+}
+
+fn boss_bar_action() -> impl Strategy<Value = BossBarAction> {
+    let color = (0..7).prop_map(|color| BossBarColor::try_from(color).unwrap());
+    let division = (0..5).prop_map(|division| BossBarDivision::try_from(division).unwrap());
+    let flags = (0..8u8).prop_map(|flags| BossBarFlags::from_bits(flags).unwrap());
+    prop_oneof![
+        (
+            component(),
+            0.0..=1.0f32,
+            color.clone(),
+            division.clone(),
+            flags.clone()
+        )
+            .prop_map(
+                |(title, health, color, division, flags)| BossBarAction::Add {
+                    title,
+                    health,
+                    color,
+                    division,
+                    flags,
+                }
+            ),
+        Just(BossBarAction::Remove),
+        (0.0..=1.0f32).prop_map(BossBarAction::UpdateHealth),
+        component().prop_map(BossBarAction::UpdateTitle),
+        (color, division)
+            .prop_map(|(color, division)| BossBarAction::UpdateStyle { color, division }),
+        flags.prop_map(BossBarAction::UpdateFlags),
+    ]
+}
+
+fn game_event() -> impl Strategy<Value = GameEvent> {
+    prop_oneof![
+        Just(GameEvent::NoRespawnBlockAvailable),
+        Just(GameEvent::StartRaining),
+        Just(GameEvent::StopRaining),
+        (0..4u8).prop_map(|mode| GameEvent::ChangeGameMode(GameMode::try_from(mode).unwrap())),
+        any::<bool>().prop_map(|roll_credits| GameEvent::WinGame { roll_credits }),
+        (0..=104).prop_map(GameEvent::Demo),
+        Just(GameEvent::ArrowHitPlayer),
+        (0.0..=1.0f32).prop_map(GameEvent::RainLevelChange),
+        (0.0..=1.0f32).prop_map(GameEvent::ThunderLevelChange),
+        Just(GameEvent::PufferfishSting),
+        Just(GameEvent::ElderGuardianEffect),
+        any::<bool>().prop_map(GameEvent::EnableRespawnScreen),
+    ]
+}
+
+fn team_info() -> impl Strategy<Value = TeamInfo> {
+    (
+        component(),
+        0..4u8,
+        prop_oneof![
+            Just(NameTagVisibility::Always),
+            Just(NameTagVisibility::HideForOtherTeams),
+            Just(NameTagVisibility::HideForOwnTeam),
+            Just(NameTagVisibility::Never),
+        ],
+        prop_oneof![
+            Just(CollisionRule::Always),
+            Just(CollisionRule::PushOtherTeams),
+            Just(CollisionRule::PushOwnTeam),
+            Just(CollisionRule::Never),
+        ],
+        prop::option::of(0..16usize),
+        (component(), component()),
+    )
+        .prop_map(
+            |(
+                display_name,
+                flags,
+                name_tag_visibility,
+                collision_rule,
+                color,
+                (prefix, suffix),
+            )| {
+                TeamInfo {
+                    display_name,
+                    flags: TeamFlags::from_bits(flags).unwrap(),
+                    name_tag_visibility,
+                    collision_rule,
+                    color: color.map(|color| NamedColor::ALL[color]),
+                    prefix,
+                    suffix,
+                }
+            },
+        )
+}
+
+fn team_action() -> impl Strategy<Value = TeamAction> {
+    let entities = prop::collection::vec("[a-zA-Z0-9_]{1,16}", 0..4);
+    prop_oneof![
+        (team_info(), entities.clone())
+            .prop_map(|(info, entities)| TeamAction::Create { info, entities }),
+        Just(TeamAction::Remove),
+        team_info().prop_map(TeamAction::UpdateInfo),
+        entities.clone().prop_map(TeamAction::AddEntities),
+        entities.prop_map(TeamAction::RemoveEntities),
+    ]
+}
+
+fn server_bound() -> impl Strategy<Value = ServerBoundPlay> {
+    prop_oneof![
+        (any::<(f64, f64, f64)>(), any::<bool>()).prop_map(|((x, y, z), on_ground)| {
+            ServerBoundPlay::PlayerMove(ServerBoundMove::PlayerPosition { x, y, z, on_ground })
+        }),
+        (any::<(f64, f64, f64)>(), any::<(f32, f32)>(), any::<bool>()).prop_map(
+            |((x, y, z), (yaw, pitch), on_ground)| {
+                ServerBoundPlay::PlayerMove(ServerBoundMove::PlayerPositionAndRotation {
+                    x,
+                    y,
+                    z,
+                    yaw,
+                    pitch,
+                    on_ground,
+                })
+            }
+        ),
+        (any::<(f32, f32)>(), any::<bool>()).prop_map(|((yaw, pitch), on_ground)| {
+            ServerBoundPlay::PlayerMove(ServerBoundMove::PlayerRotation {
+                yaw,
+                pitch,
+                on_ground,
+            })
+        }),
+        any::<i64>().prop_map(|v| ServerBoundPlay::KeepAlive(KeepAlive(v))),
+        any::<i32>().prop_map(|v| ServerBoundPlay::Ping(PlayPing(v))),
+        (
+            string(),
+            any::<u8>(),
+            prop_oneof![
+                Just(ChatMode::Enabled),
+                Just(ChatMode::CommandsOnly),
+                Just(ChatMode::Hidden)
+            ],
+            any::<u8>(),
+            prop_oneof![Just(MainHand::Left), Just(MainHand::Right)],
+            any::<[bool; 3]>()
+        )
+            .prop_map(
+                |(locale, view_distance, chat_mode, skin_parts, main_hand, flags)| {
+                    ServerBoundPlay::ClientInformation(ClientInformation {
+                        locale,
+                        view_distance,
+                        chat_mode,
+                        chat_colors: flags[0],
+                        displayed_skin_parts: SkinParts::from_bits_truncate(skin_parts),
+                        main_hand,
+                        enable_text_filtering: flags[1],
+                        allow_server_listings: flags[2],
+                    })
+                }
+            ),
+        plugin_message().prop_map(ServerBoundPlay::PluginMessage),
+        any::<i32>().prop_map(|v| ServerBoundPlay::ConfirmTeleport(ConfirmTeleport(VarInt(v)))),
+        (
+            string(),
+            any::<(i64, i64)>(),
+            prop::option::of(signature()),
+            last_seen()
+        )
+            .prop_map(|(message, (timestamp, salt), signature, last_seen)| {
+                ServerBoundPlay::ChatMessage(ChatMessage {
+                    message,
+                    timestamp,
+                    salt,
+                    signature,
+                    last_seen,
+                })
+            }),
+        (
+            string(),
+            any::<(i64, i64)>(),
+            prop::collection::vec(
+                (string(), signature())
+                    .prop_map(|(name, signature)| ArgumentSignature { name, signature }),
+                0..4
+            ),
+            last_seen()
+        )
+            .prop_map(
+                |(command, (timestamp, salt), argument_signatures, last_seen)| {
+                    ServerBoundPlay::ChatCommand(ChatCommand {
+                        command,
+                        timestamp,
+                        salt,
+                        argument_signatures,
+                        last_seen,
+                    })
+                }
+            ),
+        any::<i32>().prop_map(|offset| ServerBoundPlay::MessageAcknowledgment(
+            MessageAcknowledgment { offset }
+        )),
+        (
+            any::<u128>(),
+            any::<i64>(),
+            prop::collection::vec(any::<u8>(), 0..512),
+            prop::collection::vec(any::<u8>(), 0..512)
+        )
+            .prop_map(|(session_id, expires_at, key, key_signature)| {
+                ServerBoundPlay::PlayerSession(PlayerSession {
+                    session_id: uuid::Uuid::from_u128(session_id),
+                    public_key: ProfilePublicKey {
+                        expires_at,
+                        key,
+                        key_signature,
+                    },
+                })
+            }),
+        (
+            any::<(u8, i32, i16, i8)>(),
+            prop_oneof![
+                Just(ClickMode::Pickup),
+                Just(ClickMode::QuickMove),
+                Just(ClickMode::PickupAll)
+            ],
+            prop::collection::vec(
+                (any::<i16>(), slot()).prop_map(|(slot, item)| ChangedSlot { slot, item }),
+                0..8
+            ),
+            slot()
+        )
+            .prop_map(
+                |((window_id, state_id, slot, button), mode, changed_slots, carried_item)| {
+                    ServerBoundPlay::ClickContainer(ClickContainer {
+                        window_id,
+                        state_id,
+                        slot,
+                        button,
+                        mode,
+                        changed_slots,
+                        carried_item,
+                    })
+                }
+            ),
+        (any::<i16>(), slot()).prop_map(|(slot, item)| {
+            ServerBoundPlay::SetCreativeModeSlot(SetCreativeModeSlot { slot, item })
+        }),
+        (any::<i32>(), string()).prop_map(|(transaction_id, text)| {
+            ServerBoundPlay::CommandSuggestionsRequest(CommandSuggestionsRequest {
+                transaction_id,
+                text,
+            })
+        }),
+        prop_oneof![
+            Just(ResourcePackStatus::SuccessfullyLoaded),
+            Just(ResourcePackStatus::Declined),
+            Just(ResourcePackStatus::FailedDownload),
+            Just(ResourcePackStatus::Accepted),
+        ]
+        .prop_map(ServerBoundPlay::ResourcePackStatus),
+        (
+            prop_oneof![
+                Just(PlayerActionStatus::StartedDigging),
+                Just(PlayerActionStatus::FinishedDigging),
+                Just(PlayerActionStatus::SwapItemInHand),
+            ],
+            position(),
+            face(),
+            0..i32::MAX
+        )
+            .prop_map(|(status, position, face, sequence)| {
+                ServerBoundPlay::PlayerAction(PlayerAction {
+                    status,
+                    position,
+                    face,
+                    sequence,
+                })
+            }),
+        (
+            hand(),
+            position(),
+            face(),
+            any::<(f32, f32, f32)>(),
+            any::<bool>(),
+            0..i32::MAX
+        )
+            .prop_map(
+                |(hand, position, face, (cursor_x, cursor_y, cursor_z), inside_block, sequence)| {
+                    ServerBoundPlay::UseItemOn(UseItemOn {
+                        hand,
+                        position,
+                        face,
+                        cursor_x,
+                        cursor_y,
+                        cursor_z,
+                        inside_block,
+                        sequence,
+                    })
+                }
+            ),
+        (hand(), 0..i32::MAX)
+            .prop_map(|(hand, sequence)| ServerBoundPlay::UseItem(UseItem { hand, sequence })),
+        hand().prop_map(|hand| ServerBoundPlay::SwingArm(SwingArm { hand })),
+        (
+            any::<i32>(),
+            prop_oneof![
+                hand().prop_map(InteractAction::Interact),
+                Just(InteractAction::Attack),
+                (any::<(f32, f32, f32)>(), hand())
+                    .prop_map(|((x, y, z), hand)| InteractAction::InteractAt { x, y, z, hand }),
+            ],
+            any::<bool>()
+        )
+            .prop_map(|(entity_id, action, sneaking)| {
+                ServerBoundPlay::Interact(Interact {
+                    entity_id,
+                    action,
+                    sneaking,
+                })
+            }),
+        (
+            any::<i32>(),
+            prop_oneof![
+                Just(PlayerCommandAction::StartSneaking),
+                Just(PlayerCommandAction::LeaveBed),
+                Just(PlayerCommandAction::StartHorseJump),
+                Just(PlayerCommandAction::StartFlyingWithElytra),
+            ],
+            0..=PlayerCommand::MAX_JUMP_BOOST
+        )
+            .prop_map(|(entity_id, action, jump_boost)| {
+                ServerBoundPlay::PlayerCommand(PlayerCommand {
+                    entity_id,
+                    action,
+                    jump_boost,
+                })
+            }),
+    ]
+}
+
+fn hand() -> impl Strategy<Value = Hand> {
+    prop_oneof![Just(Hand::MainHand), Just(Hand::OffHand)]
+}
+
+fn face() -> impl Strategy<Value = Direction> {
+    prop_oneof![
+        Just(Direction::Down),
+        Just(Direction::Up),
+        Just(Direction::North),
+        Just(Direction::East),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn client_bound_play(packet in client_bound(), compression in compression(), split in any::<Index>()) {
+        round_trip_all::<ClientIO>(packet, compression, split)?;
+    }
+
+    #[test]
+    fn server_bound_play(packet in server_bound(), compression in compression(), split in any::<Index>()) {
+        round_trip_all::<ServerIO>(packet, compression, split)?;
+    }
+}
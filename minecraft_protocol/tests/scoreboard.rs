@@ -0,0 +1,66 @@
+use minecraft_protocol::chat::{Component, NamedColor};
+use minecraft_protocol::java::v_761::play::ClientIO;
+use minecraft_protocol::packets::play::client::scoreboard::{
+    ScoreAction, TeamAction, TeamInfo, UpdateScore, UpdateTeams,
+};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::simple_handlers::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
+use minecraft_protocol::{PacketReader, PacketWriter};
+
+fn round_trip(packet: ClientBoundPlay) -> Vec<u8> {
+    let mut writer = NonEncryptedPacketWriter::<ClientIO>::default();
+    let mut out = Vec::new();
+    writer.send_packet(packet.clone(), &mut out).unwrap();
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(&out);
+    assert_eq!(reader.attempt_packet_read().unwrap(), Some(packet));
+    out
+}
+
+#[test]
+pub fn update_score() {
+    let out = round_trip(
+        UpdateScore {
+            entity_name: "a".to_string(),
+            objective: "b".to_string(),
+            action: ScoreAction::Update(300),
+        }
+        .into(),
+    );
+    // Length, id 0x57, "a", update, "b", 300
+    assert_eq!(out, vec![8, 0x57, 1, b'a', 0, 1, b'b', 0xAC, 0x02]);
+
+    let out = round_trip(
+        UpdateScore {
+            entity_name: "a".to_string(),
+            objective: String::new(),
+            action: ScoreAction::Remove,
+        }
+        .into(),
+    );
+    assert_eq!(out, vec![5, 0x57, 1, b'a', 1, 0]);
+}
+
+#[test]
+pub fn team_color() {
+    let mut info = TeamInfo::new(Component::text("Red"));
+    info.color = Some(NamedColor::Red);
+    round_trip(
+        UpdateTeams {
+            name: "red".to_string(),
+            action: TeamAction::Create {
+                info: info.clone(),
+                entities: vec!["Notch".to_string()],
+            },
+        }
+        .into(),
+    );
+    info.color = None;
+    round_trip(
+        UpdateTeams {
+            name: "red".to_string(),
+            action: TeamAction::UpdateInfo(info),
+        }
+        .into(),
+    );
+}
@@ -0,0 +1,95 @@
+use minecraft_protocol::java::v_760;
+use minecraft_protocol::java::v_761::play::ClientIO;
+use minecraft_protocol::packets::play::client::particle::{Particle, ParticleData, ParticlePacket};
+use minecraft_protocol::packets::play::client::sound::{
+    EntitySoundEffect, SoundCategory, SoundEffect, SoundEvent, StopSound,
+};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::simple_handlers::{NonEncryptedPacketReader, NonEncryptedPacketWriter};
+use minecraft_protocol::{PacketReader, PacketWriter};
+
+fn read(data: &[u8]) -> Result<Option<ClientBoundPlay>, minecraft_protocol::PacketReadError> {
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(data);
+    reader.attempt_packet_read()
+}
+
+#[test]
+pub fn sound_ids() {
+    // Length 20, id 0x5D, sound 0 sent as 1, player category, entity 5
+    let mut data = vec![20, 0x5D, 1, 7, 5];
+    data.extend_from_slice(&1.0f32.to_be_bytes());
+    data.extend_from_slice(&0.5f32.to_be_bytes());
+    data.extend_from_slice(&42i64.to_be_bytes());
+    assert_eq!(
+        read(&data).unwrap(),
+        Some(ClientBoundPlay::EntitySoundEffect(EntitySoundEffect {
+            sound: SoundEvent::Registered(0),
+            category: SoundCategory::Player,
+            entity_id: 5,
+            volume: 1.0,
+            pitch: 0.5,
+            seed: 42,
+        }))
+    );
+
+    let sound = SoundEffect::new(
+        SoundEvent::named("custom:horn"),
+        SoundCategory::Master,
+        1.5,
+        64.0,
+        -2.25,
+    );
+    assert_eq!((sound.x, sound.y, sound.z), (12, 512, -18));
+    assert_eq!(sound.position(), (1.5, 64.0, -2.25));
+
+    // 1.19.2 has no inline sound names
+    let mut writer = NonEncryptedPacketWriter::<v_760::play::ClientIO>::default();
+    assert!(writer.write_packet(sound).is_err());
+}
+
+#[test]
+pub fn stop_sound() {
+    // Length 9, id 0x5F, only the sound name
+    let mut data = vec![9, 0x5F, StopSound::HAS_SOUND, 6];
+    data.extend_from_slice(b"a:note");
+    assert_eq!(
+        read(&data).unwrap(),
+        Some(ClientBoundPlay::StopSound(StopSound {
+            category: None,
+            sound: Some("a:note".to_string()),
+        }))
+    );
+    assert_eq!(StopSound::default().flags(), 0);
+}
+
+#[test]
+pub fn particle_data_after_count() {
+    let mut data = vec![0, 0x22, Particle::DUST as u8, 0];
+    for value in [1.0f64, 2.0, 3.0] {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+    for value in [0.0f32, 0.0, 0.0, 0.0] {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+    data.extend_from_slice(&1i32.to_be_bytes());
+    for value in [1.0f32, 0.0, 0.5, 2.0] {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+    data[0] = (data.len() - 1) as u8;
+    assert_eq!(
+        read(&data).unwrap(),
+        Some(ClientBoundPlay::Particle(ParticlePacket::new(
+            Particle {
+                particle_type: Particle::DUST,
+                data: ParticleData::Dust {
+                    color: [1.0, 0.0, 0.5],
+                    scale: 2.0,
+                },
+            },
+            1.0,
+            2.0,
+            3.0,
+        )))
+    );
+}
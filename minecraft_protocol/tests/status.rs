@@ -0,0 +1,114 @@
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use minecraft_protocol::chat::Component;
+use minecraft_protocol::java::status::{
+    ClientBoundStatusPacket, Player, ServerBoundStatusPacket, StatusOrString, Version,
+};
+use minecraft_protocol::java::status_responder::{FaviconError, LegacyPing, StatusResponder};
+
+fn responder() -> StatusResponder {
+    let mut responder = StatusResponder::new(
+        Version {
+            name: "1.19.3".to_string(),
+            protocol: 761,
+        },
+        20,
+    );
+    responder.online_players = 1;
+    responder.sample.push(Player {
+        name: "Notch".to_string(),
+        id: Uuid::parse_str("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap(),
+    });
+    responder.motd = Component::text("Hello ").append(Component::text("World").bold(true));
+    responder.enforces_secure_chat = true;
+    responder
+}
+
+/// The signature and IHDR chunk of a PNG
+fn png_header(width: u32, height: u32) -> Vec<u8> {
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+    png.extend_from_slice(&13u32.to_be_bytes());
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&width.to_be_bytes());
+    png.extend_from_slice(&height.to_be_bytes());
+    png.extend_from_slice(&[8, 6, 0, 0, 0]);
+    png
+}
+
+#[test]
+pub fn status_json() {
+    let mut responder = responder();
+    responder.set_favicon_png(&png_header(64, 64)).unwrap();
+    let ClientBoundStatusPacket::Response(StatusOrString::Status(status)) =
+        responder.handle(ServerBoundStatusPacket::Request)
+    else {
+        panic!("Expected a status response");
+    };
+    let json: Value = serde_json::to_value(status).unwrap();
+    assert_eq!(json["version"], json!({"name": "1.19.3", "protocol": 761}));
+    assert_eq!(json["players"]["max"], 20);
+    assert_eq!(json["players"]["online"], 1);
+    assert_eq!(
+        json["players"]["sample"],
+        json!([{"name": "Notch", "id": "069a79f4-44e9-4726-a5be-fca90e38aaf5"}])
+    );
+    assert_eq!(json["description"]["extra"][0]["text"], "World");
+    assert_eq!(json["enforcesSecureChat"], true);
+    assert_eq!(
+        json["favicon"],
+        "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAEAAAABACAYAAAA="
+    );
+
+    assert_eq!(
+        responder.handle(ServerBoundStatusPacket::Ping(42)),
+        ClientBoundStatusPacket::Ping(42)
+    );
+}
+
+#[test]
+pub fn favicon_must_be_64x64_png() {
+    let mut responder = responder();
+    assert!(matches!(
+        responder.set_favicon_png(&png_header(128, 64)),
+        Err(FaviconError::InvalidSize(128, 64))
+    ));
+    assert!(matches!(
+        responder.set_favicon_png(b"GIF89a"),
+        Err(FaviconError::NotPng)
+    ));
+    assert_eq!(responder.favicon, None);
+}
+
+#[test]
+pub fn legacy_ping() {
+    let responder = responder();
+    // 1.6 sends a MC|PingHost plugin message after 0xFE 0x01
+    assert_eq!(
+        LegacyPing::detect(&[0xFE, 0x01, 0xFA, 0x00, 0x0B]),
+        Some(LegacyPing::V1_4)
+    );
+    assert_eq!(LegacyPing::detect(&[0xFE]), Some(LegacyPing::Beta));
+    // A modern handshake starts with the packet length
+    assert_eq!(LegacyPing::detect(&[0x10, 0x00, 0xF9, 0x05]), None);
+
+    let response = responder.legacy_response(LegacyPing::V1_4);
+    assert_eq!(response[0], 0xFF);
+    let length = u16::from_be_bytes([response[1], response[2]]) as usize;
+    let text: Vec<u16> = response[3..]
+        .chunks(2)
+        .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+        .collect();
+    assert_eq!(text.len(), length);
+    assert_eq!(
+        String::from_utf16(&text).unwrap(),
+        "§1\u{0}127\u{0}1.19.3\u{0}Hello §lWorld\u{0}1\u{0}20"
+    );
+
+    let response = responder.legacy_response(LegacyPing::Beta);
+    let text: Vec<u16> = response[3..]
+        .chunks(2)
+        .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+        .collect();
+    assert_eq!(String::from_utf16(&text).unwrap(), "Hello World§1§20");
+}
@@ -0,0 +1,209 @@
+use std::io::Cursor;
+
+use minecraft_protocol::chat::Component;
+use minecraft_protocol::data::var_int::VarInt;
+use minecraft_protocol::data::PacketDataType;
+use minecraft_protocol::java::handshake::{HandShake, NextState};
+use minecraft_protocol::java::versions::{
+    JavaVersion, SupportedVersion, UnsupportedVersion, VersionVisitor,
+};
+use minecraft_protocol::java::{v_760, v_761, v_762};
+use minecraft_protocol::packets::login::server_bound::ServerBoundLoginStart;
+use minecraft_protocol::packets::login::{ClientBoundLogin, ServerBoundLogin};
+use minecraft_protocol::packets::play::client::player_info::{
+    SyncPlayerPosition, SyncPlayerPositionFlags,
+};
+use minecraft_protocol::packets::play::client::{ClientBoundPlay, ServerData};
+use minecraft_protocol::packets::play::server::container::SetHeldItem;
+use minecraft_protocol::packets::play::server::interaction::{Hand, UseItem};
+use minecraft_protocol::packets::play::server::{ServerBoundMove, ServerBoundPlay};
+use minecraft_protocol::packets::play::{KeepAlive, PlayPing};
+use minecraft_protocol::PacketIO;
+
+fn write<IO: PacketIO>(packet: IO::Type) -> Vec<u8> {
+    let mut buffer = Vec::new();
+    IO::handle_write(packet, &mut buffer).unwrap();
+    buffer
+}
+
+fn read<IO: PacketIO>(buffer: &[u8]) -> IO::Type {
+    let mut reader = Cursor::new(buffer);
+    let id = VarInt::read(&mut reader).unwrap().0;
+    let length = buffer.len() - reader.position() as usize;
+    IO::handle_read(id, length, &mut reader).unwrap()
+}
+
+#[test]
+pub fn protocol_mapping() {
+    for version in SupportedVersion::ALL {
+        assert_eq!(
+            SupportedVersion::from_protocol(version.protocol()),
+            Ok(version)
+        );
+    }
+    let handshake = HandShake {
+        protocol_version: VarInt(762),
+        server_address: "localhost".to_string(),
+        server_port: 25565,
+        next_state: NextState::Login,
+    };
+    let version = SupportedVersion::from_handshake(&handshake).unwrap();
+    assert_eq!(version.name(), "1.19.4");
+}
+
+#[test]
+pub fn unsupported_version_disconnect() {
+    let error = SupportedVersion::from_protocol(759).unwrap_err();
+    assert_eq!(error, UnsupportedVersion(759));
+    let ClientBoundLogin::LoginDisconnect(disconnect) = error.disconnect() else {
+        panic!("Expected a disconnect");
+    };
+    assert_eq!(
+        disconnect.reason.to_plain_text(),
+        "Unsupported client version. This server supports 1.19.2 - 1.19.4"
+    );
+}
+
+/// Writes a keep alive with the play implementations of the version
+struct KeepAliveBytes;
+
+impl VersionVisitor for KeepAliveBytes {
+    type Output = Vec<u8>;
+
+    fn visit<V: JavaVersion>(self) -> Self::Output {
+        write::<V::PlayClientIO>(ClientBoundPlay::KeepAlive(KeepAlive(7)))
+    }
+}
+
+#[test]
+pub fn keep_alive_ids() {
+    let ids: Vec<u8> = SupportedVersion::ALL
+        .iter()
+        .map(|version| version.visit(KeepAliveBytes)[0])
+        .collect();
+    assert_eq!(ids, vec![0x20, 0x1F, 0x23]);
+
+    let bytes = SupportedVersion::V760.visit(KeepAliveBytes);
+    assert_eq!(bytes, [&[0x20][..], &7i64.to_be_bytes()].concat());
+    assert_eq!(
+        read::<v_760::play::ClientIO>(&bytes),
+        ClientBoundPlay::KeepAlive(KeepAlive(7))
+    );
+}
+
+#[test]
+pub fn server_data_icon() {
+    let packet = ClientBoundPlay::ServerData(ServerData {
+        motd: Some(Component::text("Hello")),
+        icon: Some("data:image/png;base64,iVBORw0KGgo=".to_string()),
+        previews_chat: false,
+        enforced_secure_chat: true,
+    });
+    let bytes = write::<v_762::play::ClientIO>(packet.clone());
+    assert_eq!(bytes[0], 0x45);
+    assert_eq!(read::<v_762::play::ClientIO>(&bytes), packet);
+
+    let packet = ClientBoundPlay::ServerData(ServerData {
+        motd: None,
+        icon: None,
+        previews_chat: true,
+        enforced_secure_chat: false,
+    });
+    let bytes = write::<v_760::play::ClientIO>(packet.clone());
+    assert_eq!(bytes, vec![0x42, 0, 0, 1, 0]);
+    assert_eq!(read::<v_760::play::ClientIO>(&bytes), packet);
+}
+
+#[test]
+pub fn sync_position_without_dismount() {
+    let packet = SyncPlayerPosition {
+        x: 1.0,
+        y: 64.0,
+        z: -3.5,
+        yaw: 90.0,
+        pitch: 0.0,
+        flags: SyncPlayerPositionFlags::X | SyncPlayerPositionFlags::Y_ROT,
+        teleport_id: VarInt(12),
+        dismount_vehicle: false,
+    };
+    let v_761 = write::<v_761::play::ClientIO>(ClientBoundPlay::SyncPlayerPosition(packet.clone()));
+    let v_762 = write::<v_762::play::ClientIO>(ClientBoundPlay::SyncPlayerPosition(packet.clone()));
+    assert_eq!(v_762[0], 0x3C);
+    // Same content without the trailing dismount flag
+    assert_eq!(v_762[1..], v_761[1..v_761.len() - 1]);
+    assert_eq!(
+        read::<v_762::play::ClientIO>(&v_762),
+        ClientBoundPlay::SyncPlayerPosition(packet)
+    );
+}
+
+#[test]
+pub fn login_start_with_signature() {
+    let mut bytes = vec![0x00];
+    "Player".to_string().write(&mut bytes).unwrap();
+    true.write(&mut bytes).unwrap();
+    0i64.write(&mut bytes).unwrap();
+    vec![1u8, 2, 3].write(&mut bytes).unwrap();
+    vec![4u8, 5].write(&mut bytes).unwrap();
+    false.write(&mut bytes).unwrap();
+
+    let expected = ServerBoundLogin::LoginStart(ServerBoundLoginStart {
+        name: "Player".to_string(),
+        uuid: None,
+    });
+    assert_eq!(read::<v_760::login::ServerIO>(&bytes), expected);
+    // The signature is never written
+    let written = write::<v_760::login::ServerIO>(expected.clone());
+    assert_eq!(read::<v_760::login::ServerIO>(&written), expected);
+    assert_eq!(written.len(), 1 + 7 + 2);
+}
+
+#[test]
+pub fn move_ids() {
+    let packet = ServerBoundPlay::PlayerMove(ServerBoundMove::PlayerRotation {
+        yaw: 1.0,
+        pitch: 2.0,
+        on_ground: true,
+    });
+    assert_eq!(write::<v_761::play::ServerIO>(packet.clone())[0], 0x15);
+    let bytes = write::<v_762::play::ServerIO>(packet.clone());
+    assert_eq!(bytes[0], 0x16);
+    assert_eq!(read::<v_760::play::ServerIO>(&bytes), packet);
+    assert_eq!(read::<v_762::play::ServerIO>(&bytes), packet);
+}
+
+#[test]
+pub fn server_bound_ids() {
+    let packet = ServerBoundPlay::KeepAlive(KeepAlive(7));
+    let ids: Vec<u8> = [
+        write::<v_760::play::ServerIO>(packet.clone()),
+        write::<v_761::play::ServerIO>(packet.clone()),
+        write::<v_762::play::ServerIO>(packet.clone()),
+    ]
+    .iter()
+    .map(|bytes| bytes[0])
+    .collect();
+    assert_eq!(ids, vec![0x12, 0x11, 0x12]);
+
+    let packet = ServerBoundPlay::Ping(PlayPing(3));
+    let bytes = write::<v_762::play::ServerIO>(packet.clone());
+    assert_eq!(bytes, [&[0x20][..], &3i32.to_be_bytes()].concat());
+    assert_eq!(read::<v_762::play::ServerIO>(&bytes), packet);
+    // The chat packets before Player Session kept their ids
+    assert!(matches!(
+        read::<v_762::play::ServerIO>(&[0x03, 0]),
+        ServerBoundPlay::MessageAcknowledgment(_)
+    ));
+
+    // The packets after the old Player Session id kept their ids
+    let packet = ServerBoundPlay::UseItem(UseItem {
+        hand: Hand::OffHand,
+        sequence: 5,
+    });
+    let bytes = write::<v_762::play::ServerIO>(packet.clone());
+    assert_eq!(bytes, vec![0x32, 1, 5]);
+    assert_eq!(read::<v_762::play::ServerIO>(&bytes), packet);
+    let packet = ServerBoundPlay::SetHeldItem(SetHeldItem { slot: 2 });
+    assert_eq!(write::<v_762::play::ServerIO>(packet.clone())[0], 0x28);
+    assert_eq!(read::<v_762::play::ServerIO>(&[0x28, 0, 2]), packet);
+}
@@ -0,0 +1,47 @@
+use minecraft_protocol::java::v_761::play::ClientIO;
+use minecraft_protocol::packets::play::client::login::GameMode;
+use minecraft_protocol::packets::play::client::world::{GameEvent, UpdateTime};
+use minecraft_protocol::packets::play::client::ClientBoundPlay;
+use minecraft_protocol::simple_handlers::NonEncryptedPacketReader;
+use minecraft_protocol::PacketReader;
+
+fn read(data: &[u8]) -> Result<Option<ClientBoundPlay>, minecraft_protocol::PacketReadError> {
+    let mut reader = NonEncryptedPacketReader::<ClientIO>::default();
+    reader.get_read_buffer().extend_from_slice(data);
+    reader.attempt_packet_read()
+}
+
+#[test]
+pub fn game_event() {
+    // Length 6, id 0x1C, change game mode to creative
+    let mut data = vec![6, 0x1C, 3];
+    data.extend_from_slice(&1.0f32.to_be_bytes());
+    assert_eq!(
+        read(&data).unwrap(),
+        Some(ClientBoundPlay::GameEvent(GameEvent::ChangeGameMode(
+            GameMode::Creative
+        )))
+    );
+
+    // Immediate respawn
+    let mut data = vec![6, 0x1C, 11];
+    data.extend_from_slice(&1.0f32.to_be_bytes());
+    assert_eq!(
+        read(&data).unwrap(),
+        Some(ClientBoundPlay::GameEvent(GameEvent::EnableRespawnScreen(
+            false
+        )))
+    );
+
+    let mut data = vec![6, 0x1C, 12];
+    data.extend_from_slice(&0.0f32.to_be_bytes());
+    assert!(read(&data).is_err());
+}
+
+#[test]
+pub fn daylight_cycle() {
+    let time = UpdateTime::new(100, 6000, false);
+    assert_eq!(time.time_of_day, -6000);
+    assert!(!time.daylight_cycle());
+    assert!(UpdateTime::new(100, 6000, true).daylight_cycle());
+}